@@ -2,8 +2,14 @@
 
 use crate::runtime::handle::Handle;
 #[cfg(tokio_unstable)]
-use crate::runtime::TaskMeta;
-use crate::runtime::{blocking, driver, Callback, HistogramBuilder, Runtime, TaskCallback};
+use crate::runtime::{
+    IdleCallback, MetricsSinkFn, Placement, PlacementFn, StealOrderHook, TaskMeta, WorkerCallback,
+};
+use crate::runtime::{
+    blocking, driver, Callback, DeadlockDetectorConfig, EventIntervalCallback, HistogramBuilder,
+    OverflowPolicy, ParkBackoffConfig, Runtime, StartupDistribution, StealOrder, TaskBudgetFn,
+    TaskCallback, WorkerStackSizeFn,
+};
 use crate::util::rand::{RngSeed, RngSeedGenerator};
 
 use std::fmt;
@@ -103,6 +109,10 @@ pub struct Builder {
     /// How many ticks before yielding to the driver for timer and I/O events?
     pub(super) event_interval: u32,
 
+    /// How many ticks between submitting a worker's accumulated stats to
+    /// `WorkerMetrics`, independent of `event_interval`.
+    pub(super) metrics_submit_interval: Option<u32>,
+
     pub(super) local_queue_capacity: usize,
 
     /// When true, the multi-threade scheduler LIFO slot should not be used.
@@ -110,6 +120,10 @@ pub struct Builder {
     /// This option should only be exposed as unstable.
     pub(super) disable_lifo_slot: bool,
 
+    /// How many tasks a worker will consume from its LIFO slot in a row
+    /// before falling back to its run queue.
+    pub(super) max_lifo_polls: usize,
+
     /// Specify a random number generator seed to provide deterministic results
     pub(super) seed_generator: RngSeedGenerator,
 
@@ -119,8 +133,194 @@ pub struct Builder {
     /// Configures the task poll count histogram
     pub(super) metrics_poll_count_histogram: HistogramBuilder,
 
+    /// When `Some`, invoked on the worker thread each time a worker submits
+    /// its accumulated stats, with the worker's index and the metrics
+    /// accumulated since its previous submit.
+    #[cfg(tokio_unstable)]
+    pub(super) metrics_sink: Option<MetricsSinkFn>,
+
+    /// When true, the last core to shut down offloads dropping the driver to
+    /// a dedicated thread rather than doing so synchronously.
+    pub(super) offload_driver_shutdown_to_dedicated_thread: bool,
+
+    /// Controls whether the driver or the task queues are torn down first
+    /// when the runtime shuts down. See [`ShutdownOrder`] for details.
+    #[cfg(tokio_unstable)]
+    pub(super) shutdown_order: ShutdownOrder,
+
+    /// When `true`, disables the LIFO slot and work-stealing so that
+    /// `next_task` drains strictly FIFO: local queue first, then the
+    /// injection queue, both in the order tasks were scheduled. Test-only.
+    #[cfg(tokio_unstable)]
+    pub(super) strict_fifo: bool,
+
+    /// When `true`, the last searching worker's final recheck for
+    /// materialized work uses a cached bit instead of scanning every
+    /// worker's queue and the injection queue.
+    #[cfg(tokio_unstable)]
+    pub(super) cached_idle_recheck: bool,
+
+    /// When `Some`, a dedicated monitor thread wakes every worker at this
+    /// wall-clock cadence via the unpark path, rather than relying solely on
+    /// `event_interval` ticks.
+    pub(super) maintenance_interval: Option<Duration>,
+
+    /// When `Some`, invoked instead of the default `park`/`park_timeout` any
+    /// time a worker would park on the driver.
+    pub(super) driver_park_strategy: Option<crate::runtime::driver::DriverParkStrategy>,
+
+    /// When `true`, a stolen task that blocks on its first poll causes the
+    /// rest of its stolen batch to be pushed back to the injection queue.
+    pub(super) steal_back: bool,
+
+    /// When `Some`, bounds how long the LIFO polling loop may run
+    /// consecutively by wall-clock time.
+    pub(super) max_lifo_duration: Option<Duration>,
+
+    /// When `Some`, invoked whenever a push to the injection queue causes it
+    /// to transition from empty to non-empty.
+    pub(super) on_inject_nonempty: Option<Callback>,
+
+    /// The multi-threaded scheduler will not let the number of unparked
+    /// workers drop below this many.
+    pub(super) min_active_workers: usize,
+
+    /// Scales the victim-depth threshold work-stealing uses in the
+    /// multi-threaded scheduler.
+    pub(super) locality_bias: f64,
+
+    /// Caps how many tasks a worker moves out of a victim's queue per steal
+    /// attempt.
+    pub(super) steal_batch: Option<usize>,
+
+    /// When `Some`, a worker whose local queue grows past this multiple of
+    /// the average queue depth proactively rebalances to the injection
+    /// queue.
+    pub(super) rebalance_threshold: Option<f64>,
+
+    /// Whether the scheduler asserts (in debug builds) or gracefully
+    /// recovers (in release builds) when it finds deferred tasks waiting
+    /// right before it is about to park.
+    pub(super) strict_defer_assertions: bool,
+
+    /// When `Some`, a dedicated monitor thread periodically checks for a
+    /// lost-wakeup deadlock signature.
+    pub(super) deadlock_detector: Option<DeadlockDetectorConfig>,
+
+    /// Whether the last worker to park re-verifies, inline, that it isn't
+    /// leaving runnable work behind with nothing awake to claim it.
+    pub(super) lost_wakeup_checks: bool,
+
+    /// When `Some`, invoked once per task at spawn time to decide its
+    /// initial placement.
+    #[cfg(tokio_unstable)]
+    pub(super) placement: Option<PlacementFn>,
+
     #[cfg(tokio_unstable)]
     pub(super) unhandled_panic: UnhandledPanic,
+
+    /// When `Some`, overrides the scheduler's choice of steal-scan starting
+    /// index. Test-only.
+    #[cfg(tokio_unstable)]
+    pub(super) test_only_rand_hook: Option<StealOrderHook>,
+
+    /// When `Some`, a parked worker that keeps waking up empty-handed waits
+    /// progressively longer before checking again.
+    pub(super) park_backoff: Option<ParkBackoffConfig>,
+
+    /// Caps the total number of threads that may be running a
+    /// multi-threaded worker at once, including `block_in_place` handoffs.
+    pub(super) max_worker_threads: Option<usize>,
+
+    /// Caps how many `block_in_place` core handoffs may be outstanding at
+    /// once.
+    pub(super) max_concurrent_block_in_place: Option<usize>,
+
+    /// Caps how many spawned tasks may be alive at once.
+    #[cfg(tokio_unstable)]
+    pub(super) max_live_tasks: Option<usize>,
+
+    /// How a worker picks the victim it starts scanning from when
+    /// stealing work from other workers.
+    pub(super) steal_order: StealOrder,
+
+    /// What a worker does with its local run queue's contents when the
+    /// queue is full and a new task needs to be scheduled onto it.
+    pub(super) overflow_policy: OverflowPolicy,
+
+    /// When `Some`, overrides the stack size a worker thread is spawned
+    /// with, given its index. Otherwise, worker threads inherit the
+    /// blocking pool's `thread_stack_size`.
+    pub(super) worker_stack_size: Option<WorkerStackSizeFn>,
+
+    /// When `Some`, invoked with a worker's index every time that worker's
+    /// tick crosses `event_interval`, alongside the scheduler's own
+    /// internal maintenance.
+    pub(super) on_event_interval: Option<EventIntervalCallback>,
+
+    /// When `Some`, invoked with a worker's index each time it acquires a
+    /// core. `multi_thread_alt`-only.
+    #[cfg(tokio_unstable)]
+    pub(super) on_core_acquired: Option<WorkerCallback>,
+
+    /// When `Some`, invoked with a worker's index each time it releases its
+    /// core. `multi_thread_alt`-only.
+    #[cfg(tokio_unstable)]
+    pub(super) on_core_released: Option<WorkerCallback>,
+
+    /// When `true`, each task run out of the LIFO slot loop gets its own
+    /// `start_poll`/`end_poll` measurement instead of inheriting the
+    /// measurement of the task that started the loop.
+    pub(super) measure_lifo_polls_individually: bool,
+
+    /// The minimum `hint_duration` passed to `Handle::block_in_place_for`
+    /// that still triggers a full core handoff.
+    pub(super) block_in_place_threshold: Duration,
+
+    /// Whether a thread returning from a `block_in_place` closure takes
+    /// priority over parked workers when reclaiming its core.
+    pub(super) block_in_place_reacquire_priority: bool,
+
+    /// How the first few tasks spawned after the runtime is created are
+    /// handed to workers.
+    pub(super) startup_distribution: StartupDistribution,
+
+    /// Human-readable label for each worker, indexed by worker index.
+    pub(super) worker_labels: Vec<String>,
+
+    /// Whether a worker checks the injection queue before its own local
+    /// queue on every tick.
+    pub(super) inject_priority_over_local: bool,
+
+    /// Computes a worker's coop budget, given its index.
+    pub(super) task_budget: Option<TaskBudgetFn>,
+
+    /// Whether task allocations are recycled for reuse by a later spawn of
+    /// the same future type, rather than freed immediately.
+    pub(super) task_pooling: bool,
+
+    /// When `Some`, invoked on the worker that currently owns the driver
+    /// each time it polls the driver, right after `park`/`park_timeout`
+    /// returns.
+    pub(super) on_driver_poll: Option<Callback>,
+
+    /// Whether a core handed to a waiting worker is announced with
+    /// `Condvar::notify_all` instead of `Condvar::notify_one`.
+    /// `multi_thread_alt`-only.
+    pub(super) core_notify_broadcast: bool,
+
+    /// Caps how many tasks a worker pulls from the injection queue the
+    /// moment it acquires a core. `multi_thread_alt`-only.
+    pub(super) acquire_core_batch_cap: Option<usize>,
+
+    /// Whether `schedule_task` checks for a task waking itself from within
+    /// its own poll and counts it in `WorkerMetrics::self_wake_count`.
+    pub(super) track_self_wake_count: bool,
+
+    /// When `Some`, invoked with a suggested budget by the last worker to
+    /// park, before it actually parks. `multi_thread`-only.
+    #[cfg(tokio_unstable)]
+    pub(super) on_all_idle: Option<IdleCallback>,
 }
 
 cfg_unstable! {
@@ -204,6 +404,28 @@ cfg_unstable! {
     }
 }
 
+cfg_unstable! {
+    /// Controls the relative order of the two steps the runtime takes while
+    /// shutting down: draining the task queues, and shutting down the
+    /// resource (I/O and timer) driver.
+    ///
+    /// See [`Builder::shutdown_order`] for more details.
+    #[derive(Debug, Clone, Copy)]
+    #[non_exhaustive]
+    pub enum ShutdownOrder {
+        /// Drain every worker's local run queue and the injection queue,
+        /// dropping the tasks they hold, before shutting down the driver.
+        ///
+        /// This is the default behavior.
+        DrainFirst,
+
+        /// Shut down the driver before draining the queues, so that queued
+        /// tasks are only dropped after the I/O and timer resources they may
+        /// reference have already been torn down.
+        DriverFirst,
+    }
+}
+
 pub(crate) type ThreadNameFn = std::sync::Arc<dyn Fn() -> String + Send + Sync + 'static>;
 
 #[derive(Clone, Copy)]
@@ -309,6 +531,7 @@ impl Builder {
             // as parameters.
             global_queue_interval: None,
             event_interval,
+            metrics_submit_interval: None,
 
             #[cfg(not(loom))]
             local_queue_capacity: 256,
@@ -325,7 +548,101 @@ impl Builder {
 
             metrics_poll_count_histogram: HistogramBuilder::default(),
 
+            #[cfg(tokio_unstable)]
+            metrics_sink: None,
+
             disable_lifo_slot: false,
+
+            max_lifo_polls: 3,
+
+            offload_driver_shutdown_to_dedicated_thread: false,
+
+            #[cfg(tokio_unstable)]
+            shutdown_order: ShutdownOrder::DrainFirst,
+
+            #[cfg(tokio_unstable)]
+            strict_fifo: false,
+
+            #[cfg(tokio_unstable)]
+            cached_idle_recheck: false,
+
+            maintenance_interval: None,
+
+            driver_park_strategy: None,
+
+            steal_back: false,
+
+            max_lifo_duration: None,
+
+            on_inject_nonempty: None,
+
+            min_active_workers: 0,
+
+            locality_bias: 0.0,
+
+            steal_batch: None,
+
+            rebalance_threshold: None,
+
+            strict_defer_assertions: cfg!(debug_assertions),
+
+            deadlock_detector: None,
+
+            lost_wakeup_checks: false,
+            #[cfg(tokio_unstable)]
+            placement: None,
+            #[cfg(tokio_unstable)]
+            test_only_rand_hook: None,
+
+            park_backoff: None,
+
+            max_worker_threads: None,
+
+            max_concurrent_block_in_place: None,
+
+            #[cfg(tokio_unstable)]
+            max_live_tasks: None,
+
+            steal_order: StealOrder::Random,
+
+            overflow_policy: OverflowPolicy::SpillOldest,
+
+            worker_stack_size: None,
+
+            on_event_interval: None,
+
+            #[cfg(tokio_unstable)]
+            on_core_acquired: None,
+
+            #[cfg(tokio_unstable)]
+            on_core_released: None,
+
+            measure_lifo_polls_individually: false,
+
+            block_in_place_threshold: Duration::ZERO,
+
+            block_in_place_reacquire_priority: true,
+
+            startup_distribution: StartupDistribution::InjectAndSteal,
+
+            worker_labels: Vec::new(),
+
+            inject_priority_over_local: false,
+
+            task_budget: None,
+
+            task_pooling: false,
+
+            on_driver_poll: None,
+
+            core_notify_broadcast: false,
+
+            acquire_core_batch_cap: None,
+
+            track_self_wake_count: false,
+
+            #[cfg(tokio_unstable)]
+            on_all_idle: None,
         }
     }
 
@@ -1021,6 +1338,1074 @@ impl Builder {
             self
         }
 
+        /// Sets how many tasks a worker will consume from its LIFO slot in a
+        /// row, in a chain like a self-wake or a request/response
+        /// ping-pong, before falling back to its run queue.
+        ///
+        /// The default is 3. In ping-pong style workloads where one task
+        /// notifies another, which notifies it back, continuously
+        /// prioritizing the LIFO slot lets the two tasks repeatedly
+        /// reschedule each other, so the scheduler limits the length of this
+        /// chain to avoid starving every other task. Raising this limit can
+        /// improve latency for workloads with deeper ping-pong chains, at
+        /// the cost of worse fairness and, at extreme values, starvation of
+        /// other tasks on the same worker.
+        ///
+        /// Passing `0` disables the LIFO slot entirely, equivalent to
+        /// calling [`disable_lifo_slot`].
+        ///
+        /// [`disable_lifo_slot`]: Builder::disable_lifo_slot
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .max_lifo_polls(8)
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn max_lifo_polls(&mut self, max_lifo_polls: usize) -> &mut Self {
+            self.max_lifo_polls = max_lifo_polls;
+            self
+        }
+
+        /// Offload dropping the I/O/time driver to a dedicated thread when the
+        /// runtime shuts down, instead of dropping it synchronously on the
+        /// last worker thread to observe shutdown.
+        ///
+        /// By default, the last worker thread to shut down also drops the
+        /// driver before returning. If the application has registered many
+        /// I/O resources, this can take a while and delays the return of
+        /// [`Runtime::shutdown_background`] and similar methods. Enabling
+        /// this option moves that work to a dedicated thread so the worker
+        /// thread returns promptly.
+        ///
+        /// # Ordering guarantees
+        ///
+        /// Regardless of this setting, by the time the runtime finishes
+        /// shutting down, every worker's local run queue and the injection
+        /// queue have already been drained. Enabling this option only affects
+        /// *when* the driver itself (and the I/O resources it owns) is
+        /// dropped relative to the worker thread returning; it does not
+        /// change when tasks are dropped.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .offload_driver_shutdown_to_dedicated_thread()
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn offload_driver_shutdown_to_dedicated_thread(&mut self) -> &mut Self {
+            self.offload_driver_shutdown_to_dedicated_thread = true;
+            self
+        }
+
+        /// Configures whether the runtime drains its task queues or shuts
+        /// down the resource driver first when it shuts down.
+        ///
+        /// By default ([`ShutdownOrder::DrainFirst`]), every worker's local
+        /// run queue and the injection queue are drained — dropping the
+        /// tasks they hold — before the I/O and timer driver is shut down.
+        /// This means a task's [`Drop`] implementation can still rely on its
+        /// I/O handles being registered with a live driver.
+        ///
+        /// Setting this to [`ShutdownOrder::DriverFirst`] reverses the
+        /// order: the driver is shut down before the queues are drained.
+        /// Some applications register I/O resources that must be closed
+        /// before any task referencing them is dropped, to avoid a window
+        /// where the underlying file descriptor could be reused elsewhere in
+        /// the process before the task that thinks it still owns it has
+        /// been torn down. Choosing `DriverFirst` closes that window, at the
+        /// cost of task `Drop` implementations no longer being able to
+        /// assume their I/O handles are still valid: driver-backed types may
+        /// already be deregistered by the time a task holding them is
+        /// dropped, and operations on them from within `Drop` can fail or
+        /// become no-ops.
+        ///
+        /// # Unstable
+        ///
+        /// This option is currently unstable and its implementation is
+        /// incomplete. The API may change or be removed in the future.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::{self, ShutdownOrder};
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .shutdown_order(ShutdownOrder::DriverFirst)
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn shutdown_order(&mut self, order: ShutdownOrder) -> &mut Self {
+            self.shutdown_order = order;
+            self
+        }
+
+        /// Forces the multi-threaded scheduler to drain tasks in strict
+        /// FIFO order: local queue first, then the injection queue, both in
+        /// the order tasks were scheduled.
+        ///
+        /// The scheduler's LIFO slot and work-stealing are both useful for
+        /// throughput, but they make the order in which tasks are polled
+        /// nondeterministic, even on a single worker. Enabling this option
+        /// disables both, so tests that assert exactly which task ran when
+        /// have something reproducible to run against.
+        ///
+        /// # Unstable
+        ///
+        /// This option is intended for testing only and sacrifices the
+        /// scheduler's locality optimizations. Its implementation is
+        /// incomplete. The API may change or be removed in the future.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .worker_threads(1)
+        ///     .strict_fifo()
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn strict_fifo(&mut self) -> &mut Self {
+            self.strict_fifo = true;
+            self
+        }
+
+        /// Replaces the last searching worker's final "did work materialize
+        /// while I was searching?" recheck with a single cached bit instead
+        /// of a scan over every worker's queue plus the injection queue.
+        ///
+        /// That recheck exists to catch a narrow race: a task can land on a
+        /// worker's queue and fail to wake anyone (because every worker still
+        /// looked busy/searching at that moment), right before the last
+        /// searching worker gives up and parks. Left unhandled, the runtime
+        /// would go idle with a task nobody's coming back for. The default
+        /// full scan closes that race exactly, at the cost of O(workers) work
+        /// every time the pool's last searcher parks. On large pools, that
+        /// scan cost adds up.
+        ///
+        /// With this enabled, the push and overflow paths that can make a
+        /// task visible to stealers instead set a shared bit, and the final
+        /// recheck just reads (and clears) it: no per-worker scan, O(1)
+        /// instead of O(workers). The bit can occasionally be set when no
+        /// work is actually left, costing a redundant wakeup, but it is
+        /// never left unset while work is pending, so no task is ever
+        /// stranded.
+        ///
+        /// # Unstable
+        ///
+        /// This option is unstable because scaling the number of worker
+        /// threads on hardware where this optimization matters is itself an
+        /// advanced use case. The API may change or be removed in the
+        /// future.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .cached_idle_recheck()
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn cached_idle_recheck(&mut self) -> &mut Self {
+            self.cached_idle_recheck = true;
+            self
+        }
+
+        /// Caps how many spawned tasks may be alive at once, for admission
+        /// control.
+        ///
+        /// This counts every task from the moment it is spawned until it
+        /// completes and is removed from the runtime's task list, regardless
+        /// of whether it is currently running, scheduled, or blocked waiting
+        /// on something else. Once `max` tasks are alive, further calls to
+        /// [`spawn`] shut the new task down immediately instead of running
+        /// it, so its `JoinHandle` resolves to a cancelled [`JoinError`]
+        /// without ever polling the task; [`Handle::try_spawn`] checks the
+        /// cap up front instead, and returns [`TrySpawnError`] rather than
+        /// handing back a handle that's already doomed.
+        ///
+        /// By default, this is unset and the number of live tasks is
+        /// unbounded.
+        ///
+        /// # Unstable
+        ///
+        /// This option is unstable because the right place to enforce
+        /// admission control (here vs. a wrapper around individual spawns)
+        /// is still being worked out. The API may change or be removed in
+        /// the future.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .max_live_tasks(1024)
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        ///
+        /// [`spawn`]: crate::task::spawn
+        /// [`JoinError`]: crate::task::JoinError
+        /// [`Handle::try_spawn`]: crate::runtime::Handle::try_spawn
+        /// [`TrySpawnError`]: crate::task::TrySpawnError
+        pub fn max_live_tasks(&mut self, max: usize) -> &mut Self {
+            self.max_live_tasks = Some(max);
+            self
+        }
+
+        /// Runs maintenance (checking for shutdown, driving the I/O/time
+        /// driver, etc.) on a wall-clock cadence, in addition to the normal
+        /// tick-based schedule.
+        ///
+        /// By default, each worker only checks whether maintenance is due
+        /// every [`event_interval`] polled tasks. A worker that is stuck
+        /// polling a single long-running, CPU-heavy task without yielding
+        /// will not reach that check, which delays things like observing
+        /// shutdown or driving timers and I/O for other tasks on that
+        /// worker. When this option is set, a dedicated monitor thread wakes
+        /// every worker thread at the given interval via the same path used
+        /// to unpark a worker for new work, which causes it to re-check
+        /// whether maintenance is due the next time it has an opportunity to
+        /// do so.
+        ///
+        /// This does not preempt a currently running task; it only ensures a
+        /// worker that *is* idle or between tasks doesn't wait longer than
+        /// `interval` to reconsider maintenance.
+        ///
+        /// By default, this is disabled.
+        ///
+        /// [`event_interval`]: method@Self::event_interval
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        /// use std::time::Duration;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .maintenance_interval(Duration::from_millis(100))
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn maintenance_interval(&mut self, interval: Duration) -> &mut Self {
+            self.maintenance_interval = Some(interval);
+            self
+        }
+
+        /// Overrides how a worker parks on the driver.
+        ///
+        /// By default, a worker parks on the driver via its ordinary
+        /// `park`/`park_timeout` behavior. When a strategy is set, it is
+        /// invoked instead every time a worker would park, with the driver
+        /// itself, the driver handle, and the timeout that would otherwise
+        /// have been used (`None` for an unbounded park). The strategy is
+        /// still responsible for polling the driver it is given (e.g. by
+        /// calling `park`/`park_timeout` on it) in order to reproduce the
+        /// default behavior; it only gets to decide how that happens. It
+        /// must honor a `Some(timeout)` and not block longer than it, since
+        /// the scheduler relies on regaining control within that window to
+        /// drive timers and maintenance.
+        ///
+        /// This is meant for specialized event loops (e.g. one integrating
+        /// io_uring completion polling with a custom spin-then-block
+        /// strategy) and is not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn driver_park_strategy(
+            &mut self,
+            strategy: crate::runtime::driver::DriverParkStrategy,
+        ) -> &mut Self {
+            self.driver_park_strategy = Some(strategy);
+            self
+        }
+
+        /// Enables steal-back: when a task stolen from another worker
+        /// returns `Pending` on its first poll without immediately waking
+        /// itself, the rest of the batch stolen alongside it is pushed to
+        /// the injection queue instead of being kept on the stealer's local
+        /// queue.
+        ///
+        /// A task that blocks straight away gained nothing from running on
+        /// a colder cache, so the remaining tasks stolen with it are
+        /// unlikely to either; giving them back lets a worker closer to
+        /// where they were scheduled pick them up instead.
+        ///
+        /// By default, this is disabled and stolen batches are always kept
+        /// on the stealer's local queue.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn steal_back(&mut self, enabled: bool) -> &mut Self {
+            self.steal_back = enabled;
+            self
+        }
+
+        /// Bounds how long the multi-threaded scheduler's LIFO polling loop
+        /// may run consecutively by wall-clock time, in addition to the
+        /// existing poll-count and coop-budget caps.
+        ///
+        /// The LIFO loop keeps a worker polling tasks scheduled into its
+        /// LIFO slot (e.g. message-passing ping-pong) without returning to
+        /// the run queue, up to `max_lifo_polls` polls (3 by default) or until
+        /// the coop budget runs out. For tasks whose poll cost varies widely,
+        /// those count-based caps can still let a worker spend a long time
+        /// in the loop before checking other work. Setting this bounds that
+        /// time directly: once exceeded, the loop breaks out and any
+        /// remaining LIFO task is pushed to the back of the run queue.
+        ///
+        /// By default, this is disabled and only the existing caps apply.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn max_lifo_duration(&mut self, duration: Duration) -> &mut Self {
+            self.max_lifo_duration = Some(duration);
+            self
+        }
+
+        /// Measures each task run out of the LIFO slot loop individually,
+        /// instead of letting it inherit the `start_poll`/`end_poll`
+        /// measurement of the task that started the loop.
+        ///
+        /// A worker keeps polling tasks scheduled into its LIFO slot (e.g.
+        /// message-passing ping-pong) without returning to the run queue, up
+        /// to `max_lifo_polls` polls (3 by default) or until the coop budget
+        /// runs out. By default, all of those polls are folded into a single
+        /// poll-time measurement taken before the loop starts, so per-poll
+        /// latency metrics (e.g.
+        /// [`RuntimeMetrics::poll_count_histogram_bucket_count`]) can
+        /// attribute the combined time of several tasks to whichever one
+        /// happened to start the loop.
+        ///
+        /// Enabling this measures each LIFO-loop task on its own, giving
+        /// accurate per-task poll timing at the cost of an extra
+        /// `start_poll`/`end_poll` call per LIFO task.
+        ///
+        /// By default, this is disabled and LIFO-loop tasks inherit the
+        /// starting task's measurement.
+        ///
+        /// This is experimental and not exposed as public API.
+        ///
+        /// [`RuntimeMetrics::poll_count_histogram_bucket_count`]: crate::runtime::RuntimeMetrics::poll_count_histogram_bucket_count
+        #[allow(dead_code)]
+        pub(crate) fn measure_lifo_polls_individually(&mut self, enabled: bool) -> &mut Self {
+            self.measure_lifo_polls_individually = enabled;
+            self
+        }
+
+        /// Sets the minimum `hint_duration` passed to
+        /// `Handle::block_in_place_for` that still triggers a full core
+        /// handoff.
+        ///
+        /// Below this threshold, the blocking closure runs inline on the
+        /// worker's own thread instead of handing the core off to a new
+        /// worker thread, at the cost of pausing the rest of that worker's
+        /// queued tasks for the duration of the call. This trades off worse
+        /// latency for other tasks on a small pool against not growing the
+        /// number of live worker threads for blocking sections too short to
+        /// be worth a handoff.
+        ///
+        /// By default this is `Duration::ZERO`, so every call hands off,
+        /// matching `block_in_place`'s behavior.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn block_in_place_threshold(&mut self, threshold: Duration) -> &mut Self {
+            self.block_in_place_threshold = threshold;
+            self
+        }
+
+        /// Sets whether a thread returning from a `block_in_place` closure
+        /// takes priority over parked workers when reclaiming the core it
+        /// handed off.
+        ///
+        /// When `true` (the default), the returning thread always attempts
+        /// to reclaim its core immediately, racing the freshly spawned
+        /// handoff thread for it. This favors low latency for the code that
+        /// called `block_in_place`, at the cost of sometimes winning the
+        /// race right as the handoff thread starts running, wasting the
+        /// thread spawn.
+        ///
+        /// When `false`, the returning thread skips reclaiming the core if a
+        /// worker is currently parked, leaving it with the handoff thread
+        /// instead. This favors overall throughput: an already-parked
+        /// worker doesn't sit idle while the handoff thread's core gets
+        /// handed straight back, but the code that called `block_in_place`
+        /// resumes without a core and must wait to be rescheduled like any
+        /// other task that lost its core.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn block_in_place_reacquire_priority(&mut self, enabled: bool) -> &mut Self {
+            self.block_in_place_reacquire_priority = enabled;
+            self
+        }
+
+        /// Sets a callback to invoke whenever a push to the injection queue
+        /// causes it to transition from empty to non-empty, i.e. there was
+        /// no work available to steal from the injection queue and now
+        /// there is.
+        ///
+        /// Detection is lock-free, based on the queue's atomic length, so
+        /// under contention the callback may fire even if a concurrent pop
+        /// already drained the queue back to empty by the time it runs.
+        /// Under oscillating load it may fire frequently.
+        ///
+        /// The callback runs inline on the thread that performed the push
+        /// and must not call back into the scheduler (e.g. spawning a task
+        /// or blocking on this runtime).
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn on_inject_nonempty<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            self.on_inject_nonempty = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets the minimum number of workers the multi-threaded scheduler
+        /// keeps unparked at all times.
+        ///
+        /// A worker that would otherwise park and bring the number of
+        /// unparked workers below `k` instead spins, briefly rechecking for
+        /// work before trying to park again. This lets `k` workers absorb a
+        /// sudden burst of work without paying wakeup latency, at the cost
+        /// of the extra CPU those workers spend spinning while idle. That
+        /// extra time shows up in the searching/idle-time metrics like any
+        /// other spin.
+        ///
+        /// By default, this is `0` and workers park as soon as they run out
+        /// of work, same as before this option existed.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn min_active_workers(&mut self, k: usize) -> &mut Self {
+            self.min_active_workers = k;
+            self
+        }
+
+        /// Biases the multi-threaded scheduler's work-stealing toward
+        /// keeping tasks on the worker that scheduled them, for
+        /// cache-sensitive workloads that don't benefit from migrating
+        /// tasks between workers.
+        ///
+        /// A searching worker normally steals from the first victim it
+        /// finds with any tasks at all. `bias` scales how deep a victim's
+        /// local queue must be before it becomes a valid steal target: at
+        /// `0.0` any non-empty queue qualifies (the existing behavior), and
+        /// at `1.0` a victim's queue must be completely full. Values outside
+        /// `0.0..=1.0` are not validated and produce correspondingly
+        /// out-of-range thresholds.
+        ///
+        /// By default, this is `0.0` and stealing is as aggressive as
+        /// before this option existed.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn locality_bias(&mut self, bias: f64) -> &mut Self {
+            self.locality_bias = bias;
+            self
+        }
+
+        /// Caps how many tasks a worker moves out of a victim's run queue in
+        /// a single steal attempt, in the multi-threaded scheduler.
+        ///
+        /// By default, this is unset and a steal attempt moves roughly half
+        /// of the victim's queue, same as before this option existed. On
+        /// workloads with large numbers of very short tasks, stealing in
+        /// large batches can cause thrashing as they bounce back and forth
+        /// between workers; setting a small `max` (down to `1`, for
+        /// single-task stealing) bounds how much migrates per attempt.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn steal_batch(&mut self, max: usize) -> &mut Self {
+            self.steal_batch = Some(max);
+            self
+        }
+
+        /// Enables proactive rebalancing in the multi-threaded scheduler.
+        ///
+        /// Normally, an overloaded worker's excess tasks only move to a
+        /// different worker when an idle peer goes looking for work to
+        /// steal. When `threshold` is set, a worker also checks its own
+        /// queue depth during maintenance and, if it exceeds `threshold`
+        /// times the average depth across all workers, pushes some of its
+        /// tasks to the injection queue and wakes an idle worker itself,
+        /// instead of waiting to be stolen from.
+        ///
+        /// By default, this is disabled and workers rely solely on
+        /// stealing to redistribute load.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn rebalance_threshold(&mut self, threshold: f64) -> &mut Self {
+            self.rebalance_threshold = Some(threshold);
+            self
+        }
+
+        /// Controls whether the scheduler asserts or gracefully recovers
+        /// when it finds deferred tasks waiting right before it is about to
+        /// park.
+        ///
+        /// This should never happen, but has been observed in
+        /// production-adjacent scenarios. By default this matches
+        /// `cfg!(debug_assertions)`, so debug builds keep asserting (to
+        /// catch the underlying logic error) while release builds flush the
+        /// deferred tasks and retry rather than risking a panic or a lost
+        /// wakeup. Tests that intentionally exercise this path can set this
+        /// to `false` even in debug builds.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn strict_defer_assertions(&mut self, strict: bool) -> &mut Self {
+            self.strict_defer_assertions = strict;
+            self
+        }
+
+        /// Enables a diagnostic watchdog that periodically checks for the
+        /// scheduler's classic lost-wakeup signature: every worker parked
+        /// while the injection queue or some worker's local queue still
+        /// holds a runnable task.
+        ///
+        /// This should never happen; if it does, some path that should have
+        /// notified a parked worker (e.g. `notify_parked_local` /
+        /// `notify_parked_remote`) failed to do so. The check is purely
+        /// diagnostic: detecting the signature does not change the
+        /// scheduler's behavior, it only invokes `callback` on the monitor
+        /// thread so that it can be logged, or asserted on in tests.
+        ///
+        /// By default, this is disabled.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn deadlock_detector<F>(&mut self, interval: Duration, callback: F) -> &mut Self
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            self.deadlock_detector = Some(DeadlockDetectorConfig {
+                interval,
+                callback: std::sync::Arc::new(callback),
+            });
+            self
+        }
+
+        /// Enables an inline development-time assertion for the same
+        /// lost-wakeup signature `deadlock_detector` polls for, but checked
+        /// synchronously wherever the scheduler would otherwise be able to
+        /// silently drop it: right as the last searching worker is about to
+        /// park.
+        ///
+        /// The scheduler already rechecks for pending work at that exact
+        /// point and notifies a peer if it finds any (see
+        /// `notify_if_work_pending`); this makes that self-healing path
+        /// verify itself, panicking with the offending queue lengths if a
+        /// worker is about to go to sleep with runnable work and no other
+        /// worker awake to claim it, instead of letting the bug surface
+        /// later as an unexplained hang.
+        ///
+        /// By default, this is disabled, since the check is not free: it
+        /// scans every remote's queue and the injection queue each time the
+        /// pool goes fully idle.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn lost_wakeup_checks(&mut self, enabled: bool) -> &mut Self {
+            self.lost_wakeup_checks = enabled;
+            self
+        }
+
+        /// Configures a parked worker to wait progressively longer instead
+        /// of indefinitely when it keeps waking up to find no work.
+        ///
+        /// The first time a worker parks with an empty queue, it waits at
+        /// most `initial` before checking again. Each further wakeup that
+        /// still finds nothing to do doubles the previous wait, up to
+        /// `max`. As soon as a worker is unparked with real work, its wait
+        /// resets back to `initial` for the next time it parks.
+        ///
+        /// By default, this is disabled and a parked worker waits until it
+        /// is explicitly notified.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn park_backoff(&mut self, initial: Duration, max: Duration) -> &mut Self {
+            self.park_backoff = Some(ParkBackoffConfig { initial, max });
+            self
+        }
+
+        /// Caps the total number of threads that may be running a
+        /// multi-threaded worker at once.
+        ///
+        /// `block_in_place` normally hands its worker's core off to a
+        /// freshly spawned thread so other tasks keep making progress while
+        /// the blocking call runs. Repeated `block_in_place` calls can spawn
+        /// an unbounded number of these threads. Once `max` worker-running
+        /// threads (counting the original core threads and any handoff
+        /// clones) are already live, a further `block_in_place` call instead
+        /// runs its closure inline, keeping the core: no new thread is
+        /// spawned, at the cost of blocking that worker's other tasks for
+        /// the duration of the call.
+        ///
+        /// By default, this is unset and worker threads may multiply
+        /// without bound.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn max_worker_threads(&mut self, max: usize) -> &mut Self {
+            self.max_worker_threads = Some(max);
+            self
+        }
+
+        /// Caps how many `block_in_place` core handoffs may be outstanding
+        /// at once.
+        ///
+        /// Unlike [`Builder::max_worker_threads`], which bounds the total
+        /// number of live worker threads regardless of cause, this only
+        /// counts threads that are currently blocked because they handed
+        /// their core off via `block_in_place`, so it can bound handoff
+        /// churn specifically without also capping other sources of worker
+        /// thread growth. Once `max` handoffs are already outstanding, a
+        /// further `block_in_place` call runs its closure inline, keeping
+        /// the core, instead of spawning another thread to take it over.
+        ///
+        /// By default, this is unset and handoffs may multiply without
+        /// bound (subject to `max_worker_threads`, if that is also set).
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn max_concurrent_block_in_place(&mut self, max: usize) -> &mut Self {
+            self.max_concurrent_block_in_place = Some(max);
+            self
+        }
+
+        /// Chooses how a worker picks the victim it starts scanning from
+        /// when it goes looking for work to steal.
+        ///
+        /// By default, a worker starts from a random victim on every steal
+        /// attempt. Selecting [`StealOrder::RoundRobin`] instead makes it
+        /// start from just after wherever the previous attempt left off,
+        /// spreading steal attempts evenly across victims over time rather
+        /// than leaving it up to chance. Selecting [`StealOrder::LastParked`]
+        /// instead biases toward whichever worker most recently woke from
+        /// parking with a backlog already in its run queue. Selecting
+        /// [`StealOrder::LeastLoaded`] instead probes whichever worker
+        /// currently holds the most tasks first, avoiding wasted scans over
+        /// victims that turn out to be empty.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn steal_order(&mut self, order: StealOrder) -> &mut Self {
+            self.steal_order = order;
+            self
+        }
+
+        /// Chooses what a worker does with its local run queue's contents
+        /// when the queue is full and a new task needs to be scheduled onto
+        /// it.
+        ///
+        /// By default, a worker spills the oldest half of its local queue to
+        /// the injection queue, alongside the task that triggered the
+        /// overflow ([`OverflowPolicy::SpillOldest`]). Selecting
+        /// [`OverflowPolicy::SpillNewest`] instead keeps the just-scheduled
+        /// task local, so only the older half moves. Selecting
+        /// [`OverflowPolicy::Reject`] leaves the local queue untouched
+        /// entirely and sends the just-scheduled task straight to the
+        /// injection queue, trading locality for a cheaper overflow and an
+        /// explicit backpressure signal.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn overflow_policy(&mut self, policy: OverflowPolicy) -> &mut Self {
+            self.overflow_policy = policy;
+            self
+        }
+
+        /// Chooses how the first few tasks spawned after the runtime is
+        /// created are handed to workers.
+        ///
+        /// By default ([`StartupDistribution::InjectAndSteal`]), a freshly
+        /// spawned task goes to the injection queue like any other remote
+        /// spawn, and workers pull their share from there once they wake up.
+        /// Since no worker has started stealing yet, the first one to
+        /// acquire a core can end up draining a large portion of a startup
+        /// burst in one go. Selecting [`StartupDistribution::RoundRobinLocal`]
+        /// instead round-robins each of the first few tasks to a specific
+        /// worker and wakes it directly, so a burst spawned right after
+        /// `build()` fans out across workers immediately instead of piling
+        /// up behind whichever worker wakes first.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn startup_distribution(&mut self, distribution: StartupDistribution) -> &mut Self {
+            self.startup_distribution = distribution;
+            self
+        }
+
+        /// Sets a human-readable label for each worker, indexed by worker
+        /// index.
+        ///
+        /// This is purely diagnostic: it has no effect on scheduling. It
+        /// exists so a caller can tag workers by role (e.g. "io", "cpu") when
+        /// exporting metrics, making dashboards readable without having to
+        /// remember what each bare worker index corresponds to.
+        ///
+        /// A worker whose index has no corresponding entry, including every
+        /// worker when this is never called, falls back to the default label
+        /// `"worker-{index}"`.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn worker_labels(&mut self, labels: Vec<String>) -> &mut Self {
+            self.worker_labels = labels;
+            self
+        }
+
+        /// Makes a worker check the injection queue before its own local
+        /// queue on every tick, instead of only on `global_queue_interval`
+        /// ticks.
+        ///
+        /// This favors externally submitted work over work the worker
+        /// generated for itself, at the cost of locality: a worker that
+        /// keeps finding inject work ahead of its local queue polls fewer
+        /// tasks out of its own LIFO slot, which reduces the LIFO
+        /// optimization's effectiveness and increases how often tasks get
+        /// bounced between workers via the injection queue instead of
+        /// staying put.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn inject_priority_over_local(&mut self, enable: bool) -> &mut Self {
+            self.inject_priority_over_local = enable;
+            self
+        }
+
+        /// Overrides the stack size a worker thread is spawned with, given
+        /// its index.
+        ///
+        /// Worker threads are, by default, spawned onto the blocking pool
+        /// and so inherit its [`thread_stack_size`]. Setting this gives
+        /// worker threads their own stack size independent of the blocking
+        /// pool's, which matters for workers expected to run deeply
+        /// recursive futures. It has no effect on threads spawned by
+        /// [`spawn_blocking`], which continue to use [`thread_stack_size`].
+        ///
+        /// [`thread_stack_size`]: Builder::thread_stack_size
+        /// [`spawn_blocking`]: crate::task::spawn_blocking
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn worker_stack_size<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(usize) -> usize + Send + Sync + 'static,
+        {
+            self.worker_stack_size = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets a callback invoked with a worker's index every time that
+        /// worker's tick crosses `event_interval`, right alongside the
+        /// scheduler's own internal maintenance.
+        ///
+        /// This lets periodic per-worker housekeeping (e.g. flushing a
+        /// batch) piggyback on the scheduler's existing maintenance cadence
+        /// instead of requiring a separate timer. The callback runs inline
+        /// on the hot-ish maintenance path, so it should be cheap.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn on_event_interval<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(usize) + Send + Sync + 'static,
+        {
+            self.on_event_interval = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets a function computing a worker's coop budget from its index,
+        /// in place of the scheduler's uniform default.
+        ///
+        /// The budget bounds how many tasks a worker polls before it yields
+        /// to check for driver events and other maintenance, letting
+        /// different worker roles (e.g. an "io" affinity group) trade
+        /// throughput for snappier yielding without changing the budget for
+        /// every other worker. Values are clamped to `u8::MAX`; see
+        /// [`Budget`](crate::runtime::coop::Budget) for what the budget
+        /// governs.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn task_budget<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(usize) -> u32 + Send + Sync + 'static,
+        {
+            self.task_budget = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Enables recycling a task's backing allocation for reuse by a
+        /// later spawn of the same future type on the same worker thread,
+        /// instead of freeing and reallocating it from the global allocator.
+        ///
+        /// This trades memory for allocator throughput: each worker thread
+        /// may hold onto a bounded number of freed allocations indefinitely,
+        /// on the chance a matching spawn comes along to reuse them.
+        /// Spawn-heavy workloads that repeatedly spawn the same future type
+        /// benefit most; a wide variety of one-off task types sees little
+        /// benefit and simply pays the extra memory.
+        ///
+        /// By default, this is disabled and task allocations are always
+        /// freed immediately.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn task_pooling(&mut self, enabled: bool) -> &mut Self {
+            self.task_pooling = enabled;
+            self
+        }
+
+        /// Sets a callback invoked on whichever worker currently owns the
+        /// driver each time it polls the driver for I/O and timer events.
+        ///
+        /// This lets a custom event source that must be polled alongside
+        /// the I/O driver (e.g. a completion queue for another kind of
+        /// event loop) piggyback on the driver's existing polling cadence
+        /// instead of running its own thread. The callback runs on the
+        /// driver-owning worker, inline on the park path, so it must be
+        /// cheap and non-blocking.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn on_driver_poll<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn() + Send + Sync + 'static,
+        {
+            self.on_driver_poll = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Chooses whether a core handed to a waiting worker is announced
+        /// with `Condvar::notify_all` instead of `Condvar::notify_one`.
+        ///
+        /// Only meaningful for the `multi_thread_alt` scheduler. Each worker
+        /// waits on its own dedicated condvar, so at most one thread is ever
+        /// parked on it and the two calls are equivalent in practice; this
+        /// exists to make that choice explicit and available for A/B testing
+        /// rather than implicit in whichever call happened to be used at
+        /// each site.
+        ///
+        /// By default, this is disabled and cores are announced with
+        /// `notify_one`.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn core_notify_broadcast(&mut self, enabled: bool) -> &mut Self {
+            self.core_notify_broadcast = enabled;
+            self
+        }
+
+        /// Caps how many tasks a worker pulls from the injection queue the
+        /// moment it acquires a core, rather than up to half of the core's
+        /// run queue capacity.
+        ///
+        /// A worker that just woke up from parking pulls a batch of tasks
+        /// from the injection queue to fill its otherwise-empty local queue.
+        /// On a cold wake with several workers waking at once, letting the
+        /// first one take half the injection queue starves the others;
+        /// capping the batch leaves more behind for them to grab.
+        ///
+        /// Only meaningful for the `multi_thread_alt` scheduler.
+        ///
+        /// By default, this is unset and a worker pulls up to half its run
+        /// queue's capacity, matching the scheduler's previous behavior.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn acquire_core_batch_cap(&mut self, cap: usize) -> &mut Self {
+            self.acquire_core_batch_cap = Some(cap);
+            self
+        }
+
+        /// Enables tracking how often a task wakes itself from within its
+        /// own poll, as opposed to being woken by an external event.
+        ///
+        /// A task's schedule path checks, on every reschedule, whether the
+        /// task being scheduled is the one currently being polled on this
+        /// worker. Doing that check has a small but nonzero cost, so it's
+        /// off by default. Use [`Handle::metrics()`] and
+        /// [`RuntimeMetrics::worker_self_wake_count`] to read the counts.
+        ///
+        /// A consistently high self-wake rate usually means a future is
+        /// busy-spinning (immediately re-waking itself) rather than
+        /// registering a waker and returning `Pending`.
+        ///
+        /// By default, this is disabled.
+        ///
+        /// This is experimental and not exposed as public API.
+        ///
+        /// [`Handle::metrics()`]: crate::runtime::Handle::metrics
+        /// [`RuntimeMetrics::worker_self_wake_count`]: crate::runtime::RuntimeMetrics::worker_self_wake_count
+        #[allow(dead_code)]
+        pub(crate) fn track_self_wake_count(&mut self, enabled: bool) -> &mut Self {
+            self.track_self_wake_count = enabled;
+            self
+        }
+
+        /// Sets a callback invoked, with a suggested budget, when every
+        /// worker in the pool would otherwise block on the park condvar.
+        ///
+        /// Intended for embedding the runtime inside an external event loop
+        /// (e.g. a GUI main loop) that also needs a turn to run: rather than
+        /// the last worker parking and giving up the thread entirely, it
+        /// calls this hook first so the embedder can pump its own loop for
+        /// roughly the suggested duration. The worker parks normally
+        /// afterward; this is advisory only and does not change how or for
+        /// how long the worker actually parks.
+        ///
+        /// This is meant for single-threaded-embedding scenarios, i.e. a
+        /// `multi_thread` runtime configured with one worker sharing a
+        /// thread with the embedder's own loop. It interacts carefully with
+        /// driver ownership: whichever worker owns the I/O/time driver is
+        /// not necessarily the one that parks last, so the embedder should
+        /// not assume this hook fires on any particular thread.
+        ///
+        /// Only meaningful for the `multi_thread` scheduler, whose workers
+        /// track how many of their peers are still awake. A single-worker
+        /// `current_thread` runtime has no equivalent "every other worker is
+        /// already parked" moment to detect.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn on_all_idle<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(Duration) + Send + Sync + 'static,
+        {
+            self.on_all_idle = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets a callback invoked with a worker's index each time it
+        /// acquires a core from the pool of available cores.
+        ///
+        /// Only meaningful for the `multi_thread_alt` scheduler, whose
+        /// workers do not hold a core for their entire lifetime the way the
+        /// default multi-threaded scheduler's do. Paired with
+        /// [`on_core_released`], this brackets exactly how long a worker
+        /// holds a core, which is more precise than [`on_thread_park`] /
+        /// [`on_thread_unpark`] since parking does not always mean a worker
+        /// gave up its core.
+        ///
+        /// This is experimental and not exposed as public API.
+        ///
+        /// [`on_core_released`]: Builder::on_core_released
+        /// [`on_thread_park`]: Builder::on_thread_park
+        /// [`on_thread_unpark`]: Builder::on_thread_unpark
+        #[allow(dead_code)]
+        pub(crate) fn on_core_acquired<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(usize) + Send + Sync + 'static,
+        {
+            self.on_core_acquired = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets a callback invoked with a worker's index each time it
+        /// releases its core back to the pool of available cores.
+        ///
+        /// See [`on_core_acquired`] for details.
+        ///
+        /// This is experimental and not exposed as public API.
+        ///
+        /// [`on_core_acquired`]: Builder::on_core_acquired
+        #[allow(dead_code)]
+        pub(crate) fn on_core_released<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(usize) + Send + Sync + 'static,
+        {
+            self.on_core_released = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Sets how many ticks a multi-threaded worker waits between
+        /// submitting its accumulated stats to `WorkerMetrics`, independent
+        /// of `event_interval`.
+        ///
+        /// Lowering this makes metrics more up to date at the cost of more
+        /// frequent atomic stores; raising it (or leaving it unset) trades
+        /// freshness for less overhead.
+        ///
+        /// By default, this is unset and stats are submitted whenever
+        /// `event_interval`-gated maintenance runs, matching the scheduler's
+        /// previous behavior.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn metrics_submit_interval(&mut self, interval: u32) -> &mut Self {
+            self.metrics_submit_interval = Some(interval);
+            self
+        }
+
+        /// Sets a hook that is invoked once per task, at spawn time, to
+        /// decide where the task's initial `Notified` handle should land.
+        /// See [`Placement`] for the available choices and what each one
+        /// guarantees.
+        ///
+        /// The hook runs once at spawn; it has no bearing on where the task
+        /// runs after it first wakes up; work stealing and yielding proceed
+        /// as usual from there.
+        ///
+        /// By default, no hook is set and the scheduler behaves as if
+        /// [`Placement::Auto`] were always returned.
+        ///
+        /// This is experimental and not exposed as public API.
+        ///
+        /// [`Placement`]: crate::runtime::Placement
+        /// [`Placement::Auto`]: crate::runtime::Placement::Auto
+        #[allow(dead_code)]
+        pub(crate) fn task_placement<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(&TaskMeta<'_>) -> Placement + Send + Sync + 'static,
+        {
+            self.placement = Some(std::sync::Arc::new(f));
+            self
+        }
+
+        /// Overrides the RNG the multi-threaded scheduler uses to pick a
+        /// starting index when scanning for a worker to steal from.
+        ///
+        /// **This is test-only and must never be used outside of tokio's own
+        /// test suite.** The real RNG spreads steal attempts across workers
+        /// so that no single worker is repeatedly targeted; replacing it
+        /// with a fixed or adversarial closure breaks that guarantee and is
+        /// only useful for deterministically reproducing a specific steal
+        /// order in a test.
+        ///
+        /// By default, no hook is set and the scheduler uses its own
+        /// pseudorandom generator.
+        ///
+        /// This is experimental and not exposed as public API.
+        #[allow(dead_code)]
+        pub(crate) fn test_only_rand_hook<F>(&mut self, f: F) -> &mut Self
+        where
+            F: Fn(u32) -> u32 + Send + Sync + 'static,
+        {
+            self.test_only_rand_hook = Some(std::sync::Arc::new(f));
+            self
+        }
+
         /// Specifies the random number generation seed to use within all
         /// threads associated with the runtime being built.
         ///
@@ -1180,6 +2565,42 @@ impl Builder {
             self.metrics_poll_count_histogram.num_buckets = buckets;
             self
         }
+
+        /// Sets a sink invoked on the worker thread each time a worker
+        /// submits its accumulated stats (see [`metrics_submit_interval`]),
+        /// with the worker's index and the metrics accumulated since that
+        /// worker's previous submit.
+        ///
+        /// This is a push counterpart to [`Handle::metrics()`]: rather than
+        /// polling `RuntimeMetrics`'s cumulative counters on your own
+        /// schedule, register a sink here to receive each worker's delta as
+        /// soon as it's available, e.g. to forward into a time-series
+        /// system. The sink runs on the worker thread inline with
+        /// maintenance, so it should be quick and non-blocking; anything
+        /// heavier should hand the delta off to another thread.
+        ///
+        /// [`metrics_submit_interval`]: Builder::metrics_submit_interval
+        /// [`Handle::metrics()`]: crate::runtime::Handle::metrics
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime;
+        ///
+        /// let rt = runtime::Builder::new_multi_thread()
+        ///     .metrics_sink(|worker, delta| {
+        ///         println!("worker {worker} polled {} tasks", delta.poll_count);
+        ///     })
+        ///     .build()
+        ///     .unwrap();
+        /// ```
+        pub fn metrics_sink<F>(&mut self, sink: F) -> &mut Self
+        where
+            F: Fn(usize, &crate::runtime::WorkerMetricsDelta) + Send + Sync + 'static,
+        {
+            self.metrics_sink = Some(std::sync::Arc::new(sink));
+            self
+        }
     }
 
     cfg_loom! {
@@ -1220,12 +2641,66 @@ impl Builder {
                 after_termination: self.after_termination.clone(),
                 global_queue_interval: self.global_queue_interval,
                 event_interval: self.event_interval,
+                metrics_submit_interval: self.metrics_submit_interval,
                 local_queue_capacity: self.local_queue_capacity,
                 #[cfg(tokio_unstable)]
                 unhandled_panic: self.unhandled_panic.clone(),
                 disable_lifo_slot: self.disable_lifo_slot,
+                max_lifo_polls: self.max_lifo_polls,
                 seed_generator: seed_generator_1,
                 metrics_poll_count_histogram: self.metrics_poll_count_histogram_builder(),
+                offload_driver_shutdown_to_dedicated_thread: self.offload_driver_shutdown_to_dedicated_thread,
+                #[cfg(tokio_unstable)]
+                shutdown_order: self.shutdown_order,
+                #[cfg(tokio_unstable)]
+                strict_fifo: self.strict_fifo,
+                #[cfg(tokio_unstable)]
+                cached_idle_recheck: self.cached_idle_recheck,
+                #[cfg(tokio_unstable)]
+                metrics_sink: self.metrics_sink.clone(),
+                maintenance_interval: self.maintenance_interval,
+                driver_park_strategy: self.driver_park_strategy.clone(),
+                steal_back: self.steal_back,
+                max_lifo_duration: self.max_lifo_duration,
+                on_inject_nonempty: self.on_inject_nonempty.clone(),
+                min_active_workers: self.min_active_workers,
+                locality_bias: self.locality_bias,
+                steal_batch: self.steal_batch,
+                rebalance_threshold: self.rebalance_threshold,
+                strict_defer_assertions: self.strict_defer_assertions,
+                deadlock_detector: self.deadlock_detector.clone(),
+                lost_wakeup_checks: self.lost_wakeup_checks,
+                park_backoff: self.park_backoff,
+                max_worker_threads: self.max_worker_threads,
+                max_concurrent_block_in_place: self.max_concurrent_block_in_place,
+                #[cfg(tokio_unstable)]
+                max_live_tasks: self.max_live_tasks,
+                steal_order: self.steal_order,
+                overflow_policy: self.overflow_policy,
+                worker_stack_size: self.worker_stack_size.clone(),
+                on_event_interval: self.on_event_interval.clone(),
+                #[cfg(tokio_unstable)]
+                on_core_acquired: self.on_core_acquired.clone(),
+                #[cfg(tokio_unstable)]
+                on_core_released: self.on_core_released.clone(),
+                #[cfg(tokio_unstable)]
+                on_all_idle: self.on_all_idle.clone(),
+                #[cfg(tokio_unstable)]
+                placement: self.placement.clone(),
+                #[cfg(tokio_unstable)]
+                test_only_rand_hook: self.test_only_rand_hook.clone(),
+                measure_lifo_polls_individually: self.measure_lifo_polls_individually,
+                block_in_place_threshold: self.block_in_place_threshold,
+                block_in_place_reacquire_priority: self.block_in_place_reacquire_priority,
+                startup_distribution: self.startup_distribution,
+                worker_labels: self.worker_labels.clone(),
+                inject_priority_over_local: self.inject_priority_over_local,
+                task_budget: self.task_budget.clone(),
+                task_pooling: self.task_pooling,
+                on_driver_poll: self.on_driver_poll.clone(),
+                core_notify_broadcast: self.core_notify_broadcast,
+                acquire_core_batch_cap: self.acquire_core_batch_cap,
+                track_self_wake_count: self.track_self_wake_count,
             },
         );
 
@@ -1373,12 +2848,66 @@ cfg_rt_multi_thread! {
                     after_termination: self.after_termination.clone(),
                     global_queue_interval: self.global_queue_interval,
                     event_interval: self.event_interval,
+                    metrics_submit_interval: self.metrics_submit_interval,
                     local_queue_capacity: self.local_queue_capacity,
                     #[cfg(tokio_unstable)]
                     unhandled_panic: self.unhandled_panic.clone(),
                     disable_lifo_slot: self.disable_lifo_slot,
+                    max_lifo_polls: self.max_lifo_polls,
                     seed_generator: seed_generator_1,
                     metrics_poll_count_histogram: self.metrics_poll_count_histogram_builder(),
+                    offload_driver_shutdown_to_dedicated_thread: self.offload_driver_shutdown_to_dedicated_thread,
+                    #[cfg(tokio_unstable)]
+                    shutdown_order: self.shutdown_order,
+                    #[cfg(tokio_unstable)]
+                    strict_fifo: self.strict_fifo,
+                    #[cfg(tokio_unstable)]
+                    cached_idle_recheck: self.cached_idle_recheck,
+                    #[cfg(tokio_unstable)]
+                    metrics_sink: self.metrics_sink.clone(),
+                    maintenance_interval: self.maintenance_interval,
+                    driver_park_strategy: self.driver_park_strategy.clone(),
+                steal_back: self.steal_back,
+                max_lifo_duration: self.max_lifo_duration,
+                on_inject_nonempty: self.on_inject_nonempty.clone(),
+                min_active_workers: self.min_active_workers,
+                locality_bias: self.locality_bias,
+                steal_batch: self.steal_batch,
+                rebalance_threshold: self.rebalance_threshold,
+                strict_defer_assertions: self.strict_defer_assertions,
+                deadlock_detector: self.deadlock_detector.clone(),
+                lost_wakeup_checks: self.lost_wakeup_checks,
+                park_backoff: self.park_backoff,
+                max_worker_threads: self.max_worker_threads,
+                max_concurrent_block_in_place: self.max_concurrent_block_in_place,
+                #[cfg(tokio_unstable)]
+                max_live_tasks: self.max_live_tasks,
+                steal_order: self.steal_order,
+                overflow_policy: self.overflow_policy,
+                worker_stack_size: self.worker_stack_size.clone(),
+                on_event_interval: self.on_event_interval.clone(),
+                #[cfg(tokio_unstable)]
+                on_core_acquired: self.on_core_acquired.clone(),
+                #[cfg(tokio_unstable)]
+                on_core_released: self.on_core_released.clone(),
+                #[cfg(tokio_unstable)]
+                on_all_idle: self.on_all_idle.clone(),
+                #[cfg(tokio_unstable)]
+                placement: self.placement.clone(),
+                #[cfg(tokio_unstable)]
+                test_only_rand_hook: self.test_only_rand_hook.clone(),
+                measure_lifo_polls_individually: self.measure_lifo_polls_individually,
+                block_in_place_threshold: self.block_in_place_threshold,
+                block_in_place_reacquire_priority: self.block_in_place_reacquire_priority,
+                startup_distribution: self.startup_distribution,
+                worker_labels: self.worker_labels.clone(),
+                inject_priority_over_local: self.inject_priority_over_local,
+                task_budget: self.task_budget.clone(),
+                task_pooling: self.task_pooling,
+                on_driver_poll: self.on_driver_poll.clone(),
+                core_notify_broadcast: self.core_notify_broadcast,
+                acquire_core_batch_cap: self.acquire_core_batch_cap,
+                track_self_wake_count: self.track_self_wake_count,
                 },
             );
 
@@ -1422,12 +2951,66 @@ cfg_rt_multi_thread! {
                         after_termination: self.after_termination.clone(),
                         global_queue_interval: self.global_queue_interval,
                         event_interval: self.event_interval,
+                        metrics_submit_interval: self.metrics_submit_interval,
                         local_queue_capacity: self.local_queue_capacity,
                         #[cfg(tokio_unstable)]
                         unhandled_panic: self.unhandled_panic.clone(),
                         disable_lifo_slot: self.disable_lifo_slot,
+                        max_lifo_polls: self.max_lifo_polls,
                         seed_generator: seed_generator_1,
                         metrics_poll_count_histogram: self.metrics_poll_count_histogram_builder(),
+                        offload_driver_shutdown_to_dedicated_thread: self.offload_driver_shutdown_to_dedicated_thread,
+                        #[cfg(tokio_unstable)]
+                        shutdown_order: self.shutdown_order,
+                        #[cfg(tokio_unstable)]
+                        strict_fifo: self.strict_fifo,
+                        #[cfg(tokio_unstable)]
+                        cached_idle_recheck: self.cached_idle_recheck,
+                        #[cfg(tokio_unstable)]
+                        metrics_sink: self.metrics_sink.clone(),
+                        maintenance_interval: self.maintenance_interval,
+                        driver_park_strategy: self.driver_park_strategy.clone(),
+                steal_back: self.steal_back,
+                max_lifo_duration: self.max_lifo_duration,
+                on_inject_nonempty: self.on_inject_nonempty.clone(),
+                min_active_workers: self.min_active_workers,
+                locality_bias: self.locality_bias,
+                steal_batch: self.steal_batch,
+                rebalance_threshold: self.rebalance_threshold,
+                strict_defer_assertions: self.strict_defer_assertions,
+                deadlock_detector: self.deadlock_detector.clone(),
+                lost_wakeup_checks: self.lost_wakeup_checks,
+                park_backoff: self.park_backoff,
+                max_worker_threads: self.max_worker_threads,
+                max_concurrent_block_in_place: self.max_concurrent_block_in_place,
+                #[cfg(tokio_unstable)]
+                max_live_tasks: self.max_live_tasks,
+                steal_order: self.steal_order,
+                overflow_policy: self.overflow_policy,
+                worker_stack_size: self.worker_stack_size.clone(),
+                on_event_interval: self.on_event_interval.clone(),
+                #[cfg(tokio_unstable)]
+                on_core_acquired: self.on_core_acquired.clone(),
+                #[cfg(tokio_unstable)]
+                on_core_released: self.on_core_released.clone(),
+                #[cfg(tokio_unstable)]
+                on_all_idle: self.on_all_idle.clone(),
+                #[cfg(tokio_unstable)]
+                placement: self.placement.clone(),
+                #[cfg(tokio_unstable)]
+                test_only_rand_hook: self.test_only_rand_hook.clone(),
+                measure_lifo_polls_individually: self.measure_lifo_polls_individually,
+                block_in_place_threshold: self.block_in_place_threshold,
+                block_in_place_reacquire_priority: self.block_in_place_reacquire_priority,
+                    startup_distribution: self.startup_distribution,
+                    worker_labels: self.worker_labels.clone(),
+                    inject_priority_over_local: self.inject_priority_over_local,
+                    task_budget: self.task_budget.clone(),
+                    task_pooling: self.task_pooling,
+                    on_driver_poll: self.on_driver_poll.clone(),
+                    core_notify_broadcast: self.core_notify_broadcast,
+                    acquire_core_batch_cap: self.acquire_core_batch_cap,
+                    track_self_wake_count: self.track_self_wake_count,
                     },
                 );
 
@@ -2,7 +2,19 @@
     any(not(all(tokio_unstable, feature = "full")), target_family = "wasm"),
     allow(dead_code)
 )]
-use crate::runtime::{Callback, TaskCallback};
+#[cfg(tokio_unstable)]
+use crate::runtime::PlacementFn;
+#[cfg(tokio_unstable)]
+use crate::runtime::StealOrderHook;
+#[cfg(tokio_unstable)]
+use crate::runtime::IdleCallback;
+#[cfg(tokio_unstable)]
+use crate::runtime::MetricsSinkFn;
+#[cfg(tokio_unstable)]
+use crate::runtime::WorkerCallback;
+use crate::runtime::{
+    Callback, EventIntervalCallback, TaskBudgetFn, TaskCallback, WorkerStackSizeFn,
+};
 use crate::util::RngSeedGenerator;
 
 pub(crate) struct Config {
@@ -12,6 +24,13 @@ pub(crate) struct Config {
     /// How many ticks before yielding to the driver for timer and I/O events?
     pub(crate) event_interval: u32,
 
+    /// How many ticks between submitting a worker's accumulated stats to
+    /// `WorkerMetrics`, independent of `event_interval`.
+    ///
+    /// Default is `None`, which submits stats whenever `event_interval`-gated
+    /// maintenance runs, matching the scheduler's previous behavior.
+    pub(crate) metrics_submit_interval: Option<u32>,
+
     /// How big to make each worker's local queue
     pub(crate) local_queue_capacity: usize,
 
@@ -36,6 +55,14 @@ pub(crate) struct Config {
     /// stop-gap, this unstable option lets users disable the LIFO task.
     pub(crate) disable_lifo_slot: bool,
 
+    /// The maximum number of tasks a worker will consume from its LIFO slot
+    /// in a row, in a chain like a self-wake or a request/response
+    /// ping-pong, before falling back to its run queue. `0` disables the
+    /// LIFO slot entirely, same as `disable_lifo_slot`.
+    ///
+    /// Default is `3`.
+    pub(crate) max_lifo_polls: usize,
+
     /// Random number generator seed to configure runtimes to act in a
     /// deterministic way.
     pub(crate) seed_generator: RngSeedGenerator,
@@ -43,7 +70,577 @@ pub(crate) struct Config {
     /// How to build poll time histograms
     pub(crate) metrics_poll_count_histogram: Option<crate::runtime::HistogramBuilder>,
 
+    /// When `Some`, invoked on the worker thread each time a worker submits
+    /// its accumulated stats (see `metrics_submit_interval`), with the
+    /// worker's index and the metrics accumulated since its previous submit.
+    /// Lets a caller stream metrics into a time-series system without
+    /// polling `Handle::metrics()`.
+    #[cfg(tokio_unstable)]
+    pub(crate) metrics_sink: Option<MetricsSinkFn>,
+
+    /// When `Some`, a dedicated monitor thread wakes every worker at this
+    /// wall-clock cadence via the unpark path, rather than relying solely on
+    /// `event_interval` ticks. This helps bound maintenance latency for
+    /// workers stuck running long-lived tasks that rarely yield.
+    pub(crate) maintenance_interval: Option<std::time::Duration>,
+
+    /// When `true`, the last core to shut down offloads dropping the driver
+    /// to a dedicated thread instead of doing so synchronously. This allows
+    /// the worker thread to return promptly even if the driver has many
+    /// registered I/O resources that are slow to tear down.
+    pub(crate) offload_driver_shutdown_to_dedicated_thread: bool,
+
+    /// Controls whether the driver or the task queues are torn down first
+    /// during shutdown. See [`ShutdownOrder`] for details.
+    ///
+    /// [`ShutdownOrder`]: crate::runtime::ShutdownOrder
+    #[cfg(tokio_unstable)]
+    pub(crate) shutdown_order: crate::runtime::ShutdownOrder,
+
+    /// When `true`, disables the LIFO slot and work-stealing, forcing
+    /// `next_task` to drain strictly FIFO: local queue first, then the
+    /// injection queue, both in scheduling order. `multi_thread`-only.
+    ///
+    /// Exists so tests asserting exact task execution order have a
+    /// deterministic scheduler to run against, typically paired with
+    /// `worker_threads(1)` since ordering across multiple workers still
+    /// depends on which worker a task happens to land on.
+    ///
+    /// Default is `false`, preserving the existing LIFO-slot and
+    /// work-stealing behavior.
+    #[cfg(tokio_unstable)]
+    pub(crate) strict_fifo: bool,
+
+    /// When `true`, the final searching worker's last-chance recheck for
+    /// materialized work (see the "last searcher" comment in
+    /// `Core::transition_to_parked`) consults a single cached bit instead of
+    /// scanning every worker's queue plus the injection queue.
+    ///
+    /// The bit is set by the push/overflow paths that can make a task
+    /// visible to stealers and cleared each time the recheck consumes it,
+    /// so it trades a small false-positive rate (an occasional redundant
+    /// wakeup) for an O(1) check instead of the O(workers) scan.
+    ///
+    /// Default is `false`, preserving the full scan.
+    #[cfg(tokio_unstable)]
+    pub(crate) cached_idle_recheck: bool,
+
+    /// When `Some`, invoked instead of the default `park`/`park_timeout` any
+    /// time a worker would park on the driver. This allows specialized event
+    /// loops (e.g. one that integrates io_uring completion polling) to take
+    /// over how the worker waits. See [`driver::DriverParkStrategy`] for the
+    /// contract implementations must uphold.
+    ///
+    /// [`driver::DriverParkStrategy`]: crate::runtime::driver::DriverParkStrategy
+    pub(crate) driver_park_strategy: Option<crate::runtime::driver::DriverParkStrategy>,
+
+    /// When `true`, if a task that was just stolen from another worker
+    /// returns `Pending` on its first poll without immediately waking
+    /// itself, the rest of the batch stolen alongside it is pushed to the
+    /// injection queue instead of being kept in the stealer's local queue.
+    /// The idea is that a task that blocks straight away didn't benefit
+    /// from being moved to a colder cache, so the remaining tasks in that
+    /// batch probably won't either.
+    pub(crate) steal_back: bool,
+
+    /// When `Some`, bounds how long the LIFO polling loop may run
+    /// consecutively by wall-clock time, in addition to the existing
+    /// poll-count and coop-budget caps. Once exceeded, the loop breaks out
+    /// and the remaining LIFO task (if any) is pushed to the run queue.
+    pub(crate) max_lifo_duration: Option<std::time::Duration>,
+
+    /// When `Some`, invoked whenever a push to the injection queue causes it
+    /// to transition from empty to non-empty. Detected lock-free via the
+    /// queue's atomic length, so it may race with a concurrent pop and
+    /// report a transition that a reader never actually observes. The
+    /// callback runs inline on the pushing thread and must not call back
+    /// into the scheduler.
+    pub(crate) on_inject_nonempty: Option<Callback>,
+
+    /// The multi-threaded scheduler will not let the number of unparked
+    /// workers drop below this many. A worker that would otherwise park and
+    /// bring the count under the minimum instead spins, briefly rechecking
+    /// for work, so that at least this many workers are always ready to run
+    /// newly submitted tasks without paying wakeup latency.
+    ///
+    /// Default is `0`, which preserves the existing parking behavior.
+    pub(crate) min_active_workers: usize,
+
+    /// Scales the queue-depth a victim worker must have before the
+    /// multi-threaded scheduler's work-stealing will migrate tasks off of
+    /// it, in the range `0.0..=1.0`.
+    ///
+    /// Default is `0.0`, which preserves the existing always-steal-half
+    /// behavior: any victim with at least one task is a valid steal target.
+    /// At `1.0`, a victim's local queue must be completely full before it
+    /// will be stolen from, minimizing task migration at the cost of load
+    /// balancing responsiveness.
+    pub(crate) locality_bias: f64,
+
+    /// Caps how many tasks a worker will move out of a victim's run queue in
+    /// a single steal attempt.
+    ///
+    /// Default is `None`, which preserves the existing behavior of moving
+    /// roughly half of the victim's queue per attempt. Lowering this can
+    /// reduce thrashing on workloads with many very short tasks, where large
+    /// batches bounce back and forth between workers; a value of `1`
+    /// migrates a single task at a time.
+    pub(crate) steal_batch: Option<usize>,
+
+    /// When `Some`, a worker whose local queue depth exceeds the average
+    /// across all workers by more than this multiple proactively pushes some
+    /// of its tasks to the injection queue during maintenance, rather than
+    /// waiting for an idle peer to steal them.
+    ///
+    /// Default is `None`, which preserves the existing steal-only rebalancing
+    /// behavior.
+    pub(crate) rebalance_threshold: Option<f64>,
+
+    /// Controls how the scheduler reacts if it finds deferred tasks waiting
+    /// right before it is about to park, which should never happen but has
+    /// been observed in production-adjacent scenarios.
+    ///
+    /// When `true`, the scheduler asserts (via `debug_assert!`, so only in
+    /// debug builds) that no deferred tasks are present, to catch the
+    /// underlying logic error during development and testing. When `false`,
+    /// a release-hardened build instead flushes the deferred tasks and
+    /// retries its search for work rather than risking either a panic or an
+    /// unnoticed lost wakeup.
+    ///
+    /// Default is `cfg!(debug_assertions)`, matching the scheduler's
+    /// previous unconditional `debug_assert!`-based behavior.
+    pub(crate) strict_defer_assertions: bool,
+
+    /// When `Some`, a dedicated monitor thread periodically checks for the
+    /// scheduler's classic lost-wakeup signature: every worker parked while
+    /// the injection queue or some worker's local queue still holds
+    /// runnable tasks. This should never happen; if it does, it means a
+    /// worker was not notified when it should have been. Detecting it is
+    /// purely diagnostic: the callback is invoked and nothing about the
+    /// scheduler's state is touched.
+    pub(crate) deadlock_detector: Option<DeadlockDetectorConfig>,
+
+    /// When `true`, every last-searching worker re-verifies, right where it
+    /// is about to park, that it isn't leaving runnable work behind with no
+    /// other worker awake to claim it. `notify_if_work_pending` already
+    /// rechecks and notifies a peer in this situation; this is a
+    /// development-time assertion that the recheck actually worked, and
+    /// panics with the offending queue lengths if it didn't, rather than
+    /// letting the bug manifest as a silent hang.
+    ///
+    /// Unlike `deadlock_detector`, this is a synchronous check performed
+    /// inline on the parking path itself, not a periodic external poll, so
+    /// it can only ever be a false negative (missing a lost wakeup that a
+    /// later notification papers over) rather than a false positive.
+    ///
+    /// Off by default, since it's not free: it scans every remote's queue
+    /// and the injection queue each time the pool goes fully idle.
+    pub(crate) lost_wakeup_checks: bool,
+
+    /// When `Some`, invoked once per task at spawn time to decide where its
+    /// initial `Notified` handle should land. See [`Placement`] for the
+    /// available choices and their guarantees.
+    ///
+    /// [`Placement`]: crate::runtime::Placement
+    #[cfg(tokio_unstable)]
+    pub(crate) placement: Option<PlacementFn>,
+
     #[cfg(tokio_unstable)]
     /// How to respond to unhandled task panics.
     pub(crate) unhandled_panic: crate::runtime::UnhandledPanic,
+
+    /// When `Some`, replaces the scheduler's `FastRand`-driven choice of
+    /// starting index when scanning for a steal victim. Exists so tests can
+    /// force a specific, reproducible steal order instead of depending on
+    /// whichever index the real RNG happens to produce.
+    ///
+    /// This is test-only and must never be set outside of tokio's own test
+    /// suite: it bypasses the RNG that keeps steal attempts from repeatedly
+    /// targeting the same victim, which is load-bearing for scheduler
+    /// fairness in production.
+    #[cfg(tokio_unstable)]
+    pub(crate) test_only_rand_hook: Option<StealOrderHook>,
+
+    /// When `Some`, a parked worker that keeps waking up to find no work
+    /// waits progressively longer before checking again, instead of waiting
+    /// on its condition variable indefinitely.
+    ///
+    /// Default is `None`, which preserves the existing behavior of parking
+    /// until explicitly notified.
+    pub(crate) park_backoff: Option<ParkBackoffConfig>,
+
+    /// Caps the total number of threads that may be running a
+    /// multi-threaded worker at once, counting both the original core
+    /// threads and any clones spawned to hand off a core during
+    /// `block_in_place`. Once the cap is reached, a `block_in_place` call
+    /// runs its closure inline, keeping the core, instead of spawning
+    /// another thread to take it over.
+    ///
+    /// Default is `None`, which preserves the existing unbounded behavior.
+    pub(crate) max_worker_threads: Option<usize>,
+
+    /// Caps how many `block_in_place` core handoffs may be outstanding at
+    /// once. Once the cap is reached, a `block_in_place` call runs its
+    /// closure inline, keeping the core, instead of handing it off to
+    /// another thread.
+    ///
+    /// Unlike `max_worker_threads`, which bounds the total number of live
+    /// worker threads regardless of cause, this only counts threads that
+    /// are currently blocked *because* they handed their core to another
+    /// thread via `block_in_place`, so it can be used to bound handoff
+    /// churn specifically without also limiting other sources of worker
+    /// thread growth.
+    ///
+    /// Default is `None`, which preserves the existing unbounded behavior.
+    pub(crate) max_concurrent_block_in_place: Option<usize>,
+
+    /// Caps how many spawned tasks may be alive at once, i.e. `OwnedTasks`'
+    /// length. This counts every task from spawn until it completes and is
+    /// removed from `OwnedTasks`, regardless of whether it is currently
+    /// running, queued, or blocked on something else; it does not count
+    /// tasks waiting in a run queue that haven't been spawned yet, since
+    /// there's no such thing here — spawning is what adds a task to
+    /// `OwnedTasks` in the first place.
+    ///
+    /// Once the cap is reached, [`OwnedTasks::bind`] rejects the new task
+    /// the same way it does when the collection is closed: the task is
+    /// shut down immediately instead of being scheduled, and callers using
+    /// [`Handle::try_spawn`] get back a [`TrySpawnError`] instead of a
+    /// doomed [`JoinHandle`].
+    ///
+    /// Default is `None`, which preserves the existing unbounded behavior.
+    ///
+    /// [`OwnedTasks::bind`]: crate::runtime::task::OwnedTasks::bind
+    /// [`Handle::try_spawn`]: crate::runtime::Handle::try_spawn
+    /// [`TrySpawnError`]: crate::task::TrySpawnError
+    /// [`JoinHandle`]: crate::task::JoinHandle
+    #[cfg(tokio_unstable)]
+    pub(crate) max_live_tasks: Option<usize>,
+
+    /// Chooses how a worker picks the victim it starts scanning from when
+    /// it goes looking for work to steal.
+    ///
+    /// Default is [`StealOrder::Random`], preserving the existing
+    /// RNG-driven behavior.
+    pub(crate) steal_order: StealOrder,
+
+    /// Chooses what a worker does with its local run queue's contents when
+    /// the queue is full and a new task needs to be scheduled onto it. See
+    /// [`OverflowPolicy`] for the available choices.
+    ///
+    /// Default is [`OverflowPolicy::SpillOldest`], preserving the existing
+    /// behavior.
+    pub(crate) overflow_policy: OverflowPolicy,
+
+    /// When `Some`, invoked with a worker's index to compute the stack size
+    /// its OS thread is spawned with, overriding the blocking pool's
+    /// `thread_stack_size` for worker threads specifically.
+    ///
+    /// Default is `None`, which spawns worker threads with the same stack
+    /// size as any other blocking-pool thread.
+    pub(crate) worker_stack_size: Option<WorkerStackSizeFn>,
+
+    /// When `Some`, invoked with a worker's index every time that worker's
+    /// tick crosses `event_interval`, right alongside the scheduler's own
+    /// internal maintenance. This lets a caller piggyback periodic
+    /// per-worker housekeeping on the scheduler's existing cadence instead
+    /// of running a separate timer.
+    ///
+    /// Runs inline on the hot-ish maintenance path, so the callback should
+    /// be cheap.
+    ///
+    /// Default is `None`.
+    pub(crate) on_event_interval: Option<EventIntervalCallback>,
+
+    /// When `Some`, invoked with a worker's index each time it acquires a
+    /// core from the pool of available cores. Only meaningful for the
+    /// `multi_thread_alt` scheduler, whose workers do not hold a core for
+    /// their entire lifetime the way the default multi-threaded scheduler's
+    /// do.
+    #[cfg(tokio_unstable)]
+    pub(crate) on_core_acquired: Option<WorkerCallback>,
+
+    /// When `Some`, invoked with a worker's index each time it releases its
+    /// core back to the pool of available cores. Paired with
+    /// `on_core_acquired`, these bracket exactly how long a worker holds a
+    /// core, which is more precise than the per-park callbacks since a
+    /// parked worker may or may not have given up its core.
+    #[cfg(tokio_unstable)]
+    pub(crate) on_core_released: Option<WorkerCallback>,
+
+    /// When `true`, each task run out of the LIFO slot loop in `run_task`
+    /// gets its own `start_poll`/`end_poll` measurement instead of
+    /// inheriting the measurement of the task that started the loop.
+    ///
+    /// Default is `false`, preserving the existing inherited measurement,
+    /// where the loop's entire run is folded into a single poll-time
+    /// measurement taken before it starts.
+    pub(crate) measure_lifo_polls_individually: bool,
+
+    /// The minimum `hint_duration` passed to `Handle::block_in_place_for`
+    /// that still triggers a full core handoff. Below this, the blocking
+    /// closure runs inline on the worker's own thread instead.
+    ///
+    /// Default is `Duration::ZERO`, so every call hands off, matching
+    /// `block_in_place`'s behavior.
+    pub(crate) block_in_place_threshold: std::time::Duration,
+
+    /// Whether a thread returning from a `block_in_place` closure takes
+    /// priority over parked workers when reclaiming the core it handed off.
+    ///
+    /// Default is `true`, matching the historical behavior of always racing
+    /// to reclaim the core immediately.
+    pub(crate) block_in_place_reacquire_priority: bool,
+
+    /// Chooses how the first few tasks spawned after the runtime is created
+    /// are handed to workers. See [`StartupDistribution`] for the available
+    /// choices.
+    ///
+    /// Default is [`StartupDistribution::InjectAndSteal`], preserving the
+    /// existing behavior.
+    pub(crate) startup_distribution: StartupDistribution,
+
+    /// Human-readable label for each worker, indexed by worker index. Purely
+    /// diagnostic: it has no effect on scheduling and exists so a caller can
+    /// tag workers by role (e.g. "io", "cpu") when exporting metrics.
+    ///
+    /// A worker whose index has no corresponding entry here (including every
+    /// worker, when this is left empty) falls back to the default label
+    /// `"worker-{index}"`.
+    pub(crate) worker_labels: Vec<String>,
+
+    /// When `true`, a worker checks the injection queue before its own local
+    /// queue on every tick, instead of only on `global_queue_interval` ticks.
+    ///
+    /// This favors externally submitted (remote-spawned) work over work the
+    /// worker generated for itself, at the cost of locality: a worker that
+    /// keeps finding inject work ahead of its local queue polls fewer tasks
+    /// out of its own LIFO slot, which reduces the LIFO optimization's
+    /// effectiveness and increases how often tasks get bounced between
+    /// workers via the injection queue instead of staying put.
+    ///
+    /// Default is `false`, preserving the existing tick-gated preference for
+    /// local tasks.
+    pub(crate) inject_priority_over_local: bool,
+
+    /// When `Some`, invoked with a worker's index to compute the coop budget
+    /// each task on that worker is polled with, in place of the scheduler's
+    /// default. See [`coop::Budget`](crate::runtime::coop::Budget) for what
+    /// the budget governs.
+    ///
+    /// Lets different worker roles (e.g. an "io" affinity group) trade
+    /// throughput for snappier yielding without changing the budget for
+    /// every other worker.
+    ///
+    /// Default is `None`, which gives every worker the scheduler's uniform
+    /// default budget.
+    pub(crate) task_budget: Option<TaskBudgetFn>,
+
+    /// When `true`, a task's backing allocation is recycled for reuse by a
+    /// later spawn of the same future type on the same worker thread,
+    /// instead of freed and reallocated from the global allocator.
+    ///
+    /// This trades memory for allocator throughput: each worker thread may
+    /// hold onto a bounded number of freed allocations indefinitely, on the
+    /// chance a matching spawn comes along to reuse them. Workloads that
+    /// repeatedly spawn the same future type in a tight loop benefit most;
+    /// workloads with a wide variety of one-off task types see little
+    /// benefit and simply pay the extra memory.
+    ///
+    /// Default is `false`, which frees task allocations immediately,
+    /// matching the scheduler's previous behavior.
+    pub(crate) task_pooling: bool,
+
+    /// When `Some`, invoked on whichever worker currently owns the driver
+    /// each time it polls the driver, right after the park call returns and
+    /// before deferred wakers are flushed. Lets a custom event source that
+    /// must be polled alongside the I/O driver (e.g. a completion queue for
+    /// another kind of event loop) piggyback on the driver's existing
+    /// polling cadence.
+    ///
+    /// Runs inline on the driver-owning worker's park path, so the callback
+    /// must be cheap and non-blocking.
+    ///
+    /// Default is `None`.
+    pub(crate) on_driver_poll: Option<Callback>,
+
+    /// When `true`, a core handed to a waiting worker (e.g. by
+    /// `block_in_place` returning it, or the scheduler assigning one to a
+    /// newly-unparked worker) is announced with `Condvar::notify_all`
+    /// instead of `Condvar::notify_one`.
+    ///
+    /// Each worker waits on its own dedicated condvar, so at most one thread
+    /// is ever parked on it and the two calls are equivalent in practice;
+    /// this exists to make that choice explicit and available for A/B
+    /// testing rather than implicit in whichever call happened to be used at
+    /// each site.
+    ///
+    /// Default is `false`, preserving the existing `notify_one` behavior.
+    pub(crate) core_notify_broadcast: bool,
+
+    /// Caps how many tasks a worker pulls from the injection queue the
+    /// moment it acquires a core, in `wait_for_core`. That path normally
+    /// pulls up to half of the core's run queue capacity in one go, which
+    /// front-loads whichever worker wakes first at the expense of the
+    /// injection queue's remaining tasks. `multi_thread_alt`-only.
+    ///
+    /// Default is `None`, which preserves the existing half-capacity pull.
+    pub(crate) acquire_core_batch_cap: Option<usize>,
+
+    /// When `true`, counts each time a task wakes itself from within its own
+    /// poll (rather than being woken by an external event) in
+    /// `WorkerMetrics::self_wake_count`. A high self-wake rate can indicate a
+    /// future that busy-spins instead of registering a waker and returning
+    /// `Pending`.
+    ///
+    /// Off by default.
+    pub(crate) track_self_wake_count: bool,
+
+    /// Invoked, with a suggested budget, by the last worker to park when
+    /// every worker in the pool would otherwise block on the park condvar.
+    /// Lets an embedder (e.g. a GUI main loop) pump its own event loop for
+    /// roughly that long instead of the runtime going fully idle. Only the
+    /// `multi_thread` scheduler currently fires this; single-worker
+    /// runtimes have no "last worker" transition distinct from any other
+    /// park.
+    #[cfg(tokio_unstable)]
+    pub(crate) on_all_idle: Option<IdleCallback>,
+}
+
+impl Config {
+    /// The configured cap on live tasks, or `None` if unset. Builds without
+    /// `tokio_unstable` don't expose this option at all, so this always
+    /// returns `None` for them; callers that need an unconditional value
+    /// (e.g. `OwnedTasks::new`) can go through this instead of `#[cfg]`-ing
+    /// themselves.
+    pub(crate) fn max_live_tasks(&self) -> Option<usize> {
+        #[cfg(tokio_unstable)]
+        {
+            self.max_live_tasks
+        }
+        #[cfg(not(tokio_unstable))]
+        {
+            None
+        }
+    }
+}
+
+/// How a worker picks the victim it starts scanning from when stealing
+/// work from other workers.
+///
+/// Only `Random` is ever selected by default; the other variants are
+/// reachable solely through the experimental, test-only
+/// [`Builder::steal_order`](crate::runtime::Builder::steal_order) setter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub(crate) enum StealOrder {
+    /// Start from a random worker, chosen freshly on every steal attempt.
+    #[default]
+    Random,
+
+    /// Start from the worker just after wherever the previous steal
+    /// attempt left off, advancing by one each time. This spreads steal
+    /// attempts evenly across victims over time instead of leaving it up
+    /// to chance, at the cost of being more predictable.
+    RoundRobin,
+
+    /// Start from whichever worker most recently woke from parking already
+    /// holding tasks in its run queue (e.g. another thread scheduled
+    /// directly to it while it was asleep). Falls back to the same random
+    /// choice as `Random` if no such worker has been observed yet.
+    ///
+    /// The idea is that a worker that hasn't yet caught up on tasks it
+    /// picked up while parked is a good steal target.
+    LastParked,
+
+    /// Start from whichever worker currently holds the most tasks in its
+    /// run queue, on the theory that a fuller queue is more likely to still
+    /// have work left by the time the scan gets to it, and probing it first
+    /// avoids wasting a scan on victims that turn out to be empty.
+    LeastLoaded,
+}
+
+/// What a worker does with its local run queue's contents when the queue is
+/// full and a new task needs to be scheduled onto it.
+///
+/// Only `SpillOldest` is ever selected by default; the other variants are
+/// reachable solely through the experimental, test-only
+/// [`Builder::overflow_policy`](crate::runtime::Builder::overflow_policy)
+/// setter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub(crate) enum OverflowPolicy {
+    /// Move the oldest half of the local queue, plus the task that triggered
+    /// the overflow, to the injection queue. This is the original behavior:
+    /// it favors whichever tasks the worker queued most recently.
+    #[default]
+    SpillOldest,
+
+    /// Move the oldest half of the local queue to the injection queue, but
+    /// keep the task that triggered the overflow local instead of sending it
+    /// along too. Older queued work still spills, but the just-scheduled
+    /// task avoids the extra hop through the injection queue and a
+    /// potential steal by another worker.
+    SpillNewest,
+
+    /// Refuse to touch the local queue at all: the task that triggered the
+    /// overflow is sent directly to the injection queue instead. Trades the
+    /// locality of `SpillOldest`/`SpillNewest` for a cheaper, non-disruptive
+    /// overflow and an explicit backpressure signal (see
+    /// [`RuntimeMetrics::worker_overflow_reject_count`](crate::runtime::RuntimeMetrics::worker_overflow_reject_count)).
+    Reject,
+}
+
+/// How the first few tasks spawned after the runtime is created are handed
+/// to workers.
+///
+/// Only `InjectAndSteal` is ever selected by default; the other variant is
+/// reachable solely through the experimental, test-only
+/// [`Builder::startup_distribution`](crate::runtime::Builder::startup_distribution)
+/// setter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[allow(dead_code)]
+pub(crate) enum StartupDistribution {
+    /// Send every newly spawned task to the injection queue, same as the
+    /// scheduler's normal remote-spawn path. Idle workers wake up and pull
+    /// their share from there, same as when a burst of tasks lands after
+    /// the runtime has been running for a while.
+    ///
+    /// Since no worker has had a chance to start stealing yet, the first
+    /// worker to acquire a core ends up draining a large portion of the
+    /// injection queue in one go (see `next_task`), rather than the burst
+    /// spreading evenly across workers right away.
+    #[default]
+    InjectAndSteal,
+
+    /// Round-robin each of the first tasks to a specific worker instead:
+    /// the task still goes through the injection queue (workers only ever
+    /// pull from their own local queue or the injection queue, never push
+    /// into another worker's local queue directly), but the target worker
+    /// is also woken directly so it gets first chance at its assigned
+    /// share of the burst rather than whichever worker happens to wake up
+    /// first.
+    RoundRobinLocal,
+}
+
+/// Configuration for the optional deadlock-detecting watchdog.
+#[derive(Clone)]
+pub(crate) struct DeadlockDetectorConfig {
+    /// How often the monitor thread checks for the lost-wakeup signature.
+    pub(crate) interval: std::time::Duration,
+
+    /// Invoked (on the monitor thread) each time the signature is detected.
+    pub(crate) callback: Callback,
+}
+
+/// Configuration for escalating park timeouts.
+#[derive(Clone, Copy)]
+pub(crate) struct ParkBackoffConfig {
+    /// How long a worker waits the first time it parks with nothing to do.
+    pub(crate) initial: std::time::Duration,
+
+    /// The most a worker's park timeout is allowed to escalate to. Every
+    /// subsequent wakeup that still finds no work doubles the previous
+    /// timeout, capped at this value.
+    pub(crate) max: std::time::Duration,
 }
@@ -176,6 +176,35 @@ cfg_rt! {
         });
     }
 
+    cfg_unstable! {
+        /// A hint that the calling worker's queued backlog should be
+        /// offered to idle peers. Does nothing if called from outside of a
+        /// worker context.
+        #[track_caller]
+        pub(crate) fn yield_core_hint() {
+            with_scheduler(|maybe_scheduler| {
+                if let Some(scheduler) = maybe_scheduler {
+                    scheduler.yield_core_hint();
+                }
+            });
+        }
+
+        /// Returns `true` if the runtime driving the currently running task
+        /// has begun shutting down.
+        ///
+        /// This is advisory only: it does not cancel the calling task or
+        /// force it to yield. A cooperative task can check this between
+        /// units of work and return early once shutdown has started, but
+        /// nothing enforces that it does so. Returns `false` if called from
+        /// outside of a worker context.
+        #[track_caller]
+        pub fn shutdown_requested() -> bool {
+            with_scheduler(|maybe_scheduler| {
+                maybe_scheduler.is_some_and(|scheduler| scheduler.shutdown_requested())
+            })
+        }
+    }
+
     pub(super) fn set_scheduler<R>(v: &scheduler::Context, f: impl FnOnce() -> R) -> R {
         CONTEXT.with(|c| c.scheduler.set(v, f))
     }
@@ -56,6 +56,11 @@ impl Budget {
         Budget(Some(128))
     }
 
+    /// Returns a budget of `initial`, clamped to `u8::MAX`.
+    fn new(initial: u32) -> Budget {
+        Budget(Some(initial.min(u8::MAX as u32) as u8))
+    }
+
     /// Returns an unconstrained budget. Operations will not be limited.
     pub(super) const fn unconstrained() -> Budget {
         Budget(None)
@@ -64,6 +69,18 @@ impl Budget {
     fn has_remaining(self) -> bool {
         self.0.map_or(true, |budget| budget > 0)
     }
+
+    /// Adds `extra` to the budget, saturating at `u8::MAX`. A no-op on an
+    /// unconstrained budget.
+    fn add(self, extra: u8) -> Budget {
+        Budget(self.0.map(|budget| budget.saturating_add(extra)))
+    }
+
+    /// Subtracts `extra` from the budget, saturating at `0`. A no-op on an
+    /// unconstrained budget.
+    fn sub(self, extra: u8) -> Budget {
+        Budget(self.0.map(|budget| budget.saturating_sub(extra)))
+    }
 }
 
 /// Runs the given closure with a cooperative task budget. When the function
@@ -73,6 +90,18 @@ pub(crate) fn budget<R>(f: impl FnOnce() -> R) -> R {
     with_budget(Budget::initial(), f)
 }
 
+/// Runs the given closure with a cooperative task budget of `initial`
+/// (clamped to `u8::MAX`) if given, or the scheduler's uniform default
+/// otherwise. When the function returns, the budget is reset to the value
+/// prior to calling the function.
+#[inline(always)]
+pub(crate) fn budget_with<R>(initial: Option<u32>, f: impl FnOnce() -> R) -> R {
+    match initial {
+        Some(initial) => with_budget(Budget::new(initial), f),
+        None => with_budget(Budget::initial(), f),
+    }
+}
+
 /// Runs the given closure with an unconstrained task budget. When the function returns, the budget
 /// is reset to the value prior to calling the function.
 #[inline(always)]
@@ -107,6 +136,35 @@ fn with_budget<R>(budget: Budget, f: impl FnOnce() -> R) -> R {
     f()
 }
 
+/// Runs the given closure with the current task's budget temporarily raised
+/// by `extra`. When the function returns, the budget is reduced back by the
+/// same amount it was raised by, so that whatever the closure consumed is
+/// still reflected in the task's remaining budget afterward.
+///
+/// This is a no-op if the current budget is unconstrained.
+#[inline(always)]
+pub(crate) fn with_extended_budget<R>(extra: u8, f: impl FnOnce() -> R) -> R {
+    struct ResetGuard {
+        extra: u8,
+    }
+
+    impl Drop for ResetGuard {
+        fn drop(&mut self) {
+            let _ = context::budget(|cell| {
+                cell.set(cell.get().sub(self.extra));
+            });
+        }
+    }
+
+    #[allow(unused_variables)]
+    let maybe_guard = context::budget(|cell| {
+        cell.set(cell.get().add(extra));
+        ResetGuard { extra }
+    });
+
+    f()
+}
+
 #[inline(always)]
 pub(crate) fn has_budget_remaining() -> bool {
     // If the current budget cannot be accessed due to the thread-local being
@@ -114,6 +172,29 @@ pub(crate) fn has_budget_remaining() -> bool {
     context::budget(|cell| cell.get().has_remaining()).unwrap_or(true)
 }
 
+cfg_unstable! {
+    /// Returns the remaining cooperative scheduling budget of whatever is
+    /// currently being polled on this worker, whether that's a spawned task
+    /// or the future passed to [`Runtime::block_on`].
+    ///
+    /// Returns `None` if there is currently no budget limit in effect,
+    /// which happens when this is called from outside of any future being
+    /// polled by a runtime, or from within a scope that is running with an
+    /// unconstrained budget (for example, inside [`task::block_in_place`]).
+    ///
+    /// This is meant for debugging: code that keeps receiving `Pending`
+    /// from a `coop`-aware yield point like [`task::consume_budget`] can
+    /// check this to see how close its budget is to exhausted, and tune
+    /// how many yield points it batches between checks accordingly.
+    ///
+    /// [`Runtime::block_on`]: crate::runtime::Runtime::block_on
+    /// [`task::block_in_place`]: crate::task::block_in_place
+    /// [`task::consume_budget`]: crate::task::consume_budget
+    pub fn current_task_budget() -> Option<u32> {
+        context::budget(|cell| cell.get().0.map(u32::from)).unwrap_or(None)
+    }
+}
+
 cfg_rt_multi_thread! {
     /// Sets the current task's budget.
     pub(crate) fn set(budget: Budget) {
@@ -361,4 +442,48 @@ mod test {
             assert_pending!(task.poll());
         });
     }
+
+    #[test]
+    fn extending_budget() {
+        use tokio_test::*;
+
+        assert!(get().0.is_none());
+
+        budget(|| {
+            let initial = get().0.unwrap();
+
+            with_extended_budget(10, || {
+                assert_eq!(get().0.unwrap(), initial + 10);
+            });
+
+            // The extension is fully backed out once `f` returns, since `f`
+            // did not itself consume any budget.
+            assert_eq!(get().0.unwrap(), initial);
+
+            let coop = assert_ready!(task::spawn(()).enter(|cx, _| poll_proceed(cx)));
+            coop.made_progress();
+            drop(coop);
+            let after_decrement = get().0.unwrap();
+            assert_eq!(after_decrement, initial - 1);
+
+            with_extended_budget(10, || {
+                assert_eq!(get().0.unwrap(), after_decrement + 10);
+
+                let coop = assert_ready!(task::spawn(()).enter(|cx, _| poll_proceed(cx)));
+                coop.made_progress();
+                drop(coop);
+            });
+
+            // Whatever was consumed inside the extended section is still
+            // reflected once the extension is backed out.
+            assert_eq!(get().0.unwrap(), after_decrement - 1);
+        });
+
+        assert!(get().0.is_none());
+
+        // A no-op on an unconstrained budget.
+        with_extended_budget(10, || {
+            assert!(get().0.is_none());
+        });
+    }
 }
@@ -10,8 +10,26 @@
 use crate::runtime::park::{ParkThread, UnparkThread};
 
 use std::io;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// A hook that, when set, is invoked instead of the default `park`/
+/// `park_timeout` behavior when a worker parks on the driver.
+///
+/// The strategy is handed the driver itself, so it remains responsible for
+/// actually polling it (e.g. by calling [`Driver::park`] or
+/// [`Driver::park_timeout`]) in order to drive timers and I/O; the hook only
+/// gets to decide *how* that happens (for example, spinning for a while
+/// before falling back to blocking).
+///
+/// `timeout` is `None` for an unbounded park (the worker is relying entirely
+/// on being unparked) and `Some(d)` for a bounded one. Implementations that
+/// receive `Some(d)` must not block for longer than `d`, since the scheduler
+/// relies on regaining control within that window to drive timers and
+/// maintenance.
+pub(crate) type DriverParkStrategy =
+    Arc<dyn Fn(&mut Driver, &Handle, Option<Duration>) + Send + Sync>;
+
 #[derive(Debug)]
 pub(crate) struct Driver {
     inner: TimeDriver,
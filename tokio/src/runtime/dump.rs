@@ -27,6 +27,7 @@ pub struct Tasks {
 #[derive(Debug)]
 pub struct Task {
     id: Id,
+    migration_count: u32,
     trace: Trace,
 }
 
@@ -59,9 +60,10 @@ impl Tasks {
 }
 
 impl Task {
-    pub(crate) fn new(id: Id, trace: super::task::trace::Trace) -> Self {
+    pub(crate) fn new(id: Id, migration_count: u32, trace: super::task::trace::Trace) -> Self {
         Self {
             id,
+            migration_count,
             trace: Trace { inner: trace },
         }
     }
@@ -81,6 +83,24 @@ impl Task {
         self.id
     }
 
+    /// Returns the number of times this task has been moved from one
+    /// worker's queue to another's by the work-stealing queue, as of the
+    /// time this snapshot was taken.
+    ///
+    /// Tasks with a persistently high migration count are candidates for
+    /// pinning to a single worker.
+    ///
+    /// **Note**: This is an [unstable API][unstable]. The public API of this type
+    /// may break in 1.x releases. See [the documentation on unstable
+    /// features][unstable] for details.
+    ///
+    /// [unstable]: crate#unstable-features
+    #[cfg(tokio_unstable)]
+    #[cfg_attr(docsrs, doc(cfg(tokio_unstable)))]
+    pub fn migration_count(&self) -> u32 {
+        self.migration_count
+    }
+
     /// A trace of this task's state.
     pub fn trace(&self) -> &Trace {
         &self.trace
@@ -22,6 +22,10 @@ use crate::util::trace::SpawnMeta;
 
 use std::future::Future;
 use std::marker::PhantomData;
+#[cfg(tokio_unstable)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(tokio_unstable)]
+use std::sync::Arc;
 use std::{error, fmt, mem};
 
 /// Runtime context guard.
@@ -348,6 +352,63 @@ impl Handle {
         self.inner.spawn(future, id)
     }
 
+    cfg_unstable! {
+        /// Spawns a future onto the runtime, rejecting it up front if
+        /// [`Builder::max_live_tasks`] has been reached.
+        ///
+        /// This is [`Handle::spawn`], but admission-control-aware: instead of
+        /// letting an over-the-cap task get scheduled, immediately shut down,
+        /// and hand back a `JoinHandle` that only resolves to a cancelled
+        /// [`JoinError`] once polled, `try_spawn` checks the cap up front and
+        /// returns [`TrySpawnError`] without spawning anything. Since the
+        /// check and the actual spawn aren't atomic, a task can still be
+        /// rejected at the cap even if this call observed room for it (or
+        /// vice versa); [`Builder::max_live_tasks`] is enforced exactly at
+        /// the spawn itself and remains the source of truth.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Builder;
+        ///
+        /// # fn dox() {
+        /// let rt = Builder::new_multi_thread()
+        ///     .max_live_tasks(1)
+        ///     .build()
+        ///     .unwrap();
+        /// let handle = rt.handle();
+        ///
+        /// let _first = handle.try_spawn(async {}).unwrap();
+        /// # }
+        /// ```
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this type
+        /// may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`Builder::max_live_tasks`]: crate::runtime::Builder::max_live_tasks
+        /// [`JoinError`]: crate::task::JoinError
+        /// [`TrySpawnError`]: crate::task::TrySpawnError
+        #[track_caller]
+        pub fn try_spawn<F>(
+            &self,
+            future: F,
+        ) -> Result<JoinHandle<F::Output>, crate::task::TrySpawnError>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            if let Some(max) = self.inner.max_live_tasks() {
+                if self.inner.num_alive_tasks() >= max {
+                    return Err(crate::task::TrySpawnError::at_capacity());
+                }
+            }
+
+            Ok(self.spawn(future))
+        }
+    }
+
     /// Returns the flavor of the current `Runtime`.
     ///
     /// # Examples
@@ -409,6 +470,643 @@ impl Handle {
             };
             owned_id.into()
         }
+
+        /// Returns the seed used to initialize each worker thread's random
+        /// number generator, in worker index order.
+        ///
+        /// These are the seeds actually generated by [`Builder::rng_seed`]'s
+        /// generator (or, if unset, from the OS's source of entropy) at
+        /// runtime creation time, before any scheduling has happened. Log
+        /// them on failure and replay a specific worker's steal-order-dependent
+        /// bug by feeding the corresponding seed back into `Builder::rng_seed`.
+        ///
+        /// Returns an empty `Vec` for the current-thread scheduler, which has
+        /// no steal order to reproduce.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`Builder::rng_seed`]: crate::runtime::Builder::rng_seed
+        pub fn worker_rng_seeds(&self) -> Vec<u64> {
+            match &self.inner {
+                scheduler::Handle::CurrentThread(handle) => handle.worker_rng_seeds(),
+                #[cfg(feature = "rt-multi-thread")]
+                scheduler::Handle::MultiThread(handle) => handle.worker_rng_seeds(),
+                #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                scheduler::Handle::MultiThreadAlt(handle) => handle.worker_rng_seeds(),
+            }
+        }
+
+        /// Dynamically enables or disables the LIFO slot optimization for
+        /// every worker thread on this runtime.
+        ///
+        /// This is meant for A/B experiments that want to measure the LIFO
+        /// slot's impact on a live workload without rebuilding the runtime.
+        /// For statically disabling the LIFO slot at build time, see
+        /// [`Builder::disable_lifo_slot`] instead.
+        ///
+        /// Workers only pick up the new setting the next time they reset
+        /// their own local `lifo_enabled` flag (e.g. between tasks), so
+        /// there is a brief window after this call where some workers are
+        /// still running with the previous setting.
+        ///
+        /// Does nothing for the current-thread scheduler, which has no LIFO
+        /// slot to disable.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`Builder::disable_lifo_slot`]: crate::runtime::Builder::disable_lifo_slot
+        pub fn set_lifo_enabled_all(&self, enabled: bool) {
+            match &self.inner {
+                scheduler::Handle::CurrentThread(handle) => handle.set_lifo_enabled_all(enabled),
+                #[cfg(feature = "rt-multi-thread")]
+                scheduler::Handle::MultiThread(handle) => handle.set_lifo_enabled_all(enabled),
+                #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                scheduler::Handle::MultiThreadAlt(handle) => handle.set_lifo_enabled_all(enabled),
+            }
+        }
+
+        /// Temporarily stops workers from pulling new tasks out of the
+        /// injection queue, without affecting local queues.
+        ///
+        /// Tasks submitted remotely (e.g. via [`Handle::spawn`] from outside
+        /// a worker thread, or a task migrating between workers) simply
+        /// accumulate in the injection queue while paused instead of being
+        /// dropped; call [`Handle::resume_inject`] to let workers start
+        /// draining it again. Workers keep polling their own local queues as
+        /// normal throughout, so already-running work is unaffected.
+        ///
+        /// This is meant for coordinating a config reload: pause injection,
+        /// wait for in-flight local work to settle, apply the change, then
+        /// resume.
+        ///
+        /// Does nothing for the current-thread scheduler, which has no other
+        /// workers to keep running while injection is paused.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`Handle::resume_inject`]: crate::runtime::Handle::resume_inject
+        pub fn pause_inject(&self) {
+            match &self.inner {
+                scheduler::Handle::CurrentThread(handle) => handle.pause_inject(),
+                #[cfg(feature = "rt-multi-thread")]
+                scheduler::Handle::MultiThread(handle) => handle.pause_inject(),
+                #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                scheduler::Handle::MultiThreadAlt(handle) => handle.pause_inject(),
+            }
+        }
+
+        /// Resumes workers pulling new tasks out of the injection queue
+        /// after a call to [`Handle::pause_inject`].
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`Handle::pause_inject`]: crate::runtime::Handle::pause_inject
+        pub fn resume_inject(&self) {
+            match &self.inner {
+                scheduler::Handle::CurrentThread(handle) => handle.resume_inject(),
+                #[cfg(feature = "rt-multi-thread")]
+                scheduler::Handle::MultiThread(handle) => handle.resume_inject(),
+                #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                scheduler::Handle::MultiThreadAlt(handle) => handle.resume_inject(),
+            }
+        }
+
+        /// Applies a batch of dynamic scheduler setting changes.
+        ///
+        /// This exists so that turning on several dynamic knobs at once (for
+        /// example, as part of one A/B experiment) doesn't require a
+        /// separate `Handle` call per knob. Only settings that are safe to
+        /// change on a live runtime are
+        /// exposed on [`RuntimeConfigMut`]; structural settings that are
+        /// fixed for the lifetime of the runtime, such as the worker count,
+        /// have no setter there and so cannot be changed by this call.
+        ///
+        /// Each individual setting still takes effect independently and as
+        /// soon as it is applied: workers are not paused, and there is no
+        /// guarantee that every worker observes every change in this batch
+        /// at the same instant. This is the same "eventually consistent"
+        /// behavior as calling the equivalent single-setting methods (such
+        /// as [`Handle::set_lifo_enabled_all`]) one after another.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// # #[tokio::main]
+        /// # async fn main() {
+        /// Handle::current().update_config(|config| {
+        ///     config.set_lifo_enabled(false).set_steal_back(true);
+        /// });
+        /// # }
+        /// ```
+        pub fn update_config(&self, f: impl FnOnce(&mut RuntimeConfigMut<'_>)) {
+            let mut config = RuntimeConfigMut { handle: &self.inner };
+            f(&mut config);
+        }
+
+        /// Spawns a future onto this `Handle`'s associated `Runtime`, and
+        /// cancels it once it has accumulated more than `limit` of poll
+        /// time.
+        ///
+        /// The accumulated time is *CPU time*, i.e. wall-clock time spent
+        /// actually executing inside the future's `poll` method, not
+        /// wall-clock time since the task was spawned; time the task spends
+        /// waiting to be woken doesn't count against `limit`. The check
+        /// happens at poll boundaries, so it is only as precise as the
+        /// granularity of the task's own polls: a task whose single poll
+        /// call runs for longer than `limit` overruns it by however long
+        /// that poll takes before being canceled on the next one.
+        ///
+        /// The returned `JoinHandle` resolves to `Ok(output)` if the future
+        /// completes before exceeding `limit`, or `Err(CpuLimitExceeded)`
+        /// once it's been canceled for exceeding it.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        /// use std::time::Duration;
+        ///
+        /// # #[tokio::main]
+        /// # async fn main() {
+        /// let result = Handle::current()
+        ///     .spawn_with_cpu_limit(Duration::from_secs(1), async {
+        ///         "done"
+        ///     })
+        ///     .await
+        ///     .unwrap();
+        ///
+        /// assert_eq!(result, Ok("done"));
+        /// # }
+        /// ```
+        pub fn spawn_with_cpu_limit<F>(
+            &self,
+            limit: std::time::Duration,
+            future: F,
+        ) -> JoinHandle<Result<F::Output, crate::task::CpuLimitExceeded>>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            self.spawn(crate::task::WithCpuLimit::new(future, limit))
+        }
+
+        /// Hints to the runtime, from within a running task, that any tasks
+        /// queued up behind it on the current worker should be offered to
+        /// idle peers.
+        ///
+        /// This is lighter than [`block_in_place`], which fully hands the
+        /// core off to another thread: the calling task keeps running on
+        /// this worker, and only its worker's queued backlog is pushed to
+        /// the injection queue and idle peers are woken. It's meant for a
+        /// task that knows it's about to run a long CPU burst and wants its
+        /// queued siblings to get a chance to make progress elsewhere in
+        /// the meantime.
+        ///
+        /// This is purely a hint: there is no guarantee that any tasks are
+        /// migrated, or that an idle worker is available to pick them up.
+        /// It does nothing when called outside of a task running on this
+        /// runtime, and is a no-op on the current-thread scheduler, which
+        /// has no idle peers to offer a backlog to.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`block_in_place`]: crate::task::block_in_place
+        pub fn yield_core_hint(&self) {
+            context::yield_core_hint();
+        }
+
+        /// Spawns a future, trying to poll it once immediately on the
+        /// calling worker instead of always going through the scheduler's
+        /// normal enqueue-then-pick-up path.
+        ///
+        /// For a future that's likely to finish (or hit its first await
+        /// point) in a handful of instructions, the round trip through the
+        /// run queue is pure overhead compared to just running it. When
+        /// `spawn_inline` is called from a worker thread that currently
+        /// holds its core, the new task is polled once right there: if it
+        /// completes, it never touches a queue at all; if it returns
+        /// `Pending`, it's left owned by the runtime like any other spawned
+        /// task, relying on its waker to be scheduled from here on. Outside
+        /// of that situation — called from `block_on`'s thread, from a
+        /// worker that doesn't hold its core (e.g. mid-steal), or on the
+        /// `current_thread` or [alternate multi-threaded][unstable]
+        /// scheduler — it falls back to behaving exactly like [`spawn`].
+        ///
+        /// **Reentrancy**: it's fine for the polled future to itself call
+        /// `spawn_inline` (directly, or by spawning a task that does).
+        /// Nothing prevents nested inline polling other than the budget
+        /// accounting below, so a chain of futures that each complete by
+        /// immediately inline-spawning the next will keep running inline
+        /// until that chain either bottoms out or exhausts its budget.
+        ///
+        /// **Budget accounting**: the inline poll spends from whatever
+        /// cooperative budget is already active on the calling worker
+        /// rather than starting a fresh one, and `spawn_inline` declines to
+        /// poll at all once that budget is exhausted. This is what bounds
+        /// reentrant inline spawning: a task that inline-spawns another,
+        /// which inline-spawns another, and so on, is charged against the
+        /// same per-poll budget as any other tight loop, and eventually
+        /// falls back to normal scheduling instead of starving the worker.
+        ///
+        /// [`spawn`]: Handle::spawn
+        /// [unstable]: crate#unstable-features
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        #[track_caller]
+        pub fn spawn_inline<F>(&self, future: F) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let fut_size = mem::size_of::<F>();
+            if fut_size > BOX_FUTURE_THRESHOLD {
+                self.spawn_inline_named(Box::pin(future), SpawnMeta::new_unnamed(fut_size))
+            } else {
+                self.spawn_inline_named(future, SpawnMeta::new_unnamed(fut_size))
+            }
+        }
+
+        #[track_caller]
+        fn spawn_inline_named<F>(&self, future: F, _meta: SpawnMeta<'_>) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let id = crate::runtime::task::Id::next();
+            #[cfg(all(
+                tokio_unstable,
+                tokio_taskdump,
+                feature = "rt",
+                target_os = "linux",
+                any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+            ))]
+            let future = super::task::trace::Trace::root(future);
+            #[cfg(all(tokio_unstable, feature = "tracing"))]
+            let future = crate::util::trace::task(future, "task", _meta, id.as_u64());
+            self.inner.spawn_inline(future, id)
+        }
+
+        /// Spawns a future, delivering it directly to `worker`'s low-latency
+        /// fast-path mailbox instead of going through the usual placement
+        /// logic.
+        ///
+        /// The mailbox is a single-slot, lock-free bypass of the mutex-guarded
+        /// injection queue, meant for latency-sensitive remote wakeups (e.g.
+        /// from an I/O completion thread) that don't want to pay for a lock
+        /// on the hot path. It's a best-effort optimization, not a guarantee:
+        /// if the mailbox already holds an undelivered task when this is
+        /// called, or the runtime is shutting down, the new task falls back
+        /// to the regular injection queue instead.
+        ///
+        /// `worker` is taken modulo the number of workers, so any value is
+        /// accepted.
+        ///
+        /// Only `multi_thread` has per-worker mailboxes to deliver into; the
+        /// other flavors just spawn normally, ignoring `worker`.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        #[track_caller]
+        pub fn notify_fast<F>(&self, worker: usize, future: F) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let fut_size = mem::size_of::<F>();
+            if fut_size > BOX_FUTURE_THRESHOLD {
+                self.notify_fast_named(worker, Box::pin(future), SpawnMeta::new_unnamed(fut_size))
+            } else {
+                self.notify_fast_named(worker, future, SpawnMeta::new_unnamed(fut_size))
+            }
+        }
+
+        #[track_caller]
+        fn notify_fast_named<F>(
+            &self,
+            worker: usize,
+            future: F,
+            _meta: SpawnMeta<'_>,
+        ) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let id = crate::runtime::task::Id::next();
+            #[cfg(all(
+                tokio_unstable,
+                tokio_taskdump,
+                feature = "rt",
+                target_os = "linux",
+                any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+            ))]
+            let future = super::task::trace::Trace::root(future);
+            #[cfg(all(tokio_unstable, feature = "tracing"))]
+            let future = crate::util::trace::task(future, "task", _meta, id.as_u64());
+            self.inner.notify_fast(worker, future, id)
+        }
+
+        /// Spawns a future, delivering it to `worker`'s per-worker inject
+        /// queue instead of going through the usual placement logic.
+        ///
+        /// Unlike [`notify_fast`], this queue is unbounded, so there's no
+        /// single slot to contend over: the task always makes it into
+        /// `worker`'s queue rather than falling back to the shared
+        /// injection queue. `worker` drains its own queue in preference to
+        /// the shared one, but an idle peer may also steal from it if
+        /// `worker` doesn't get to it first.
+        ///
+        /// `worker` is taken modulo the number of workers, so any value is
+        /// accepted.
+        ///
+        /// Only `multi_thread` has per-worker inject queues to deliver
+        /// into; the other flavors just spawn normally, ignoring `worker`.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [`notify_fast`]: Handle::notify_fast
+        /// [unstable]: crate#unstable-features
+        #[track_caller]
+        pub fn inject_to_worker<F>(&self, worker: usize, future: F) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let fut_size = mem::size_of::<F>();
+            if fut_size > BOX_FUTURE_THRESHOLD {
+                self.inject_to_worker_named(worker, Box::pin(future), SpawnMeta::new_unnamed(fut_size))
+            } else {
+                self.inject_to_worker_named(worker, future, SpawnMeta::new_unnamed(fut_size))
+            }
+        }
+
+        #[track_caller]
+        fn inject_to_worker_named<F>(
+            &self,
+            worker: usize,
+            future: F,
+            _meta: SpawnMeta<'_>,
+        ) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let id = crate::runtime::task::Id::next();
+            #[cfg(all(
+                tokio_unstable,
+                tokio_taskdump,
+                feature = "rt",
+                target_os = "linux",
+                any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+            ))]
+            let future = super::task::trace::Trace::root(future);
+            #[cfg(all(tokio_unstable, feature = "tracing"))]
+            let future = crate::util::trace::task(future, "task", _meta, id.as_u64());
+            self.inner.inject_to_worker(worker, future, id)
+        }
+
+        /// Spawns a future that only runs once every worker has run out of
+        /// other work to do.
+        ///
+        /// The task is never placed on a worker's local queue or on the
+        /// injection queue: it's set aside in a dedicated low-priority slot
+        /// that a worker only checks once it has nothing left to steal, and
+        /// it stays out of those queues for its entire lifetime, not just
+        /// its initial spawn. This is meant for background maintenance work
+        /// (e.g. periodic cleanup, cache eviction) that should make
+        /// progress eventually but must never compete with real work for a
+        /// worker's attention.
+        ///
+        /// Because of that, a task spawned this way can starve indefinitely
+        /// under constant load: if the runtime is always busy, an idle task
+        /// may simply never get a turn. Don't use this for anything that
+        /// needs to run promptly or within a bounded amount of time.
+        ///
+        /// Only `multi_thread` distinguishes idle tasks from normal ones;
+        /// the other flavors just spawn normally, since a `current_thread`
+        /// runtime has no other workers to prioritize over this task in the
+        /// first place.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        #[track_caller]
+        pub fn spawn_when_idle<F>(&self, future: F) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let fut_size = mem::size_of::<F>();
+            if fut_size > BOX_FUTURE_THRESHOLD {
+                self.spawn_when_idle_named(Box::pin(future), SpawnMeta::new_unnamed(fut_size))
+            } else {
+                self.spawn_when_idle_named(future, SpawnMeta::new_unnamed(fut_size))
+            }
+        }
+
+        #[track_caller]
+        fn spawn_when_idle_named<F>(&self, future: F, _meta: SpawnMeta<'_>) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let id = crate::runtime::task::Id::next();
+            #[cfg(all(
+                tokio_unstable,
+                tokio_taskdump,
+                feature = "rt",
+                target_os = "linux",
+                any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+            ))]
+            let future = super::task::trace::Trace::root(future);
+            #[cfg(all(tokio_unstable, feature = "tracing"))]
+            let future = crate::util::trace::task(future, "task", _meta, id.as_u64());
+            self.inner.spawn_when_idle(future, id)
+        }
+
+        /// Spawns a future onto this handle with the given coarse
+        /// execution-order priority hint.
+        ///
+        /// A `High` task is placed at the front of a worker's local queue
+        /// (or its LIFO slot) instead of the back, and preferred by the
+        /// work-stealing queue's steal order; a `Low` task is always placed
+        /// at the back, and stolen only after other tasks. `Normal` behaves
+        /// exactly like [`spawn`], which is what every task not spawned
+        /// through this method gets. This is a hint rather than a
+        /// guarantee, and is meant for the common "some tasks matter more"
+        /// case rather than exact ordering.
+        ///
+        /// Only `multi_thread` runtimes act on the hint; the other flavors
+        /// spawn the future normally.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [`spawn`]: Handle::spawn
+        /// [unstable]: crate#unstable-features
+        #[track_caller]
+        pub fn spawn_with_priority<F>(
+            &self,
+            future: F,
+            priority: crate::runtime::task::TaskPriority,
+        ) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let fut_size = mem::size_of::<F>();
+            if fut_size > BOX_FUTURE_THRESHOLD {
+                self.spawn_with_priority_named(Box::pin(future), SpawnMeta::new_unnamed(fut_size), priority)
+            } else {
+                self.spawn_with_priority_named(future, SpawnMeta::new_unnamed(fut_size), priority)
+            }
+        }
+
+        #[track_caller]
+        fn spawn_with_priority_named<F>(
+            &self,
+            future: F,
+            _meta: SpawnMeta<'_>,
+            priority: crate::runtime::task::TaskPriority,
+        ) -> JoinHandle<F::Output>
+        where
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let id = crate::runtime::task::Id::next();
+            #[cfg(all(
+                tokio_unstable,
+                tokio_taskdump,
+                feature = "rt",
+                target_os = "linux",
+                any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")
+            ))]
+            let future = super::task::trace::Trace::root(future);
+            #[cfg(all(tokio_unstable, feature = "tracing"))]
+            let future = crate::util::trace::task(future, "task", _meta, id.as_u64());
+            self.inner.spawn_with_priority(future, id, priority)
+        }
+
+        /// Tries to drive the runtime's resource (I/O, timer, ...) driver
+        /// once, with a zero timeout, without blocking the calling thread.
+        ///
+        /// This is meant for tests that want to advance I/O and timer state
+        /// deterministically instead of sleeping and hoping the driver gets
+        /// polled in the meantime. It's a best-effort, single poll: any
+        /// events the driver picks up are dispatched to their wakers, which
+        /// schedules the tasks waiting on them, but this does not itself
+        /// poll those tasks or wait for them to run.
+        ///
+        /// Only has an effect when called from a `multi_thread` runtime's
+        /// worker thread while it's between tasks or in the middle of
+        /// running one; returns `false` without doing anything otherwise
+        /// (including on the `current_thread` runtime, whose single worker
+        /// already drives I/O and timers as part of its normal loop). The
+        /// driver is shared across all workers, so if another worker has
+        /// already claimed it, this still returns `true` but doesn't
+        /// actually block waiting for it — the poll is skipped for this
+        /// call.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        pub fn drive_once(&self) -> bool {
+            self.inner.drive_once()
+        }
+
+        /// Spawns every future produced by `iter` onto this handle and waits
+        /// for all of them to complete, without holding onto a per-task
+        /// [`JoinHandle`].
+        ///
+        /// This is meant for load tests and benchmarks that want to submit a
+        /// large number of tasks and cheaply wait for all of them to finish,
+        /// not as a replacement for [`JoinSet`]: there is no way to retrieve
+        /// individual outputs, and a panicking task aborts the process like
+        /// any other detached spawn rather than being reported here.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of this
+        /// function may break in 1.x releases. See [the documentation on unstable
+        /// features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`JoinHandle`]: crate::task::JoinHandle
+        /// [`JoinSet`]: crate::task::JoinSet
+        pub async fn spawn_and_await_n<I, F>(&self, iter: I)
+        where
+            I: IntoIterator<Item = F>,
+            F: Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let tasks: Vec<F> = iter.into_iter().collect();
+            let remaining = Arc::new(AtomicUsize::new(tasks.len()));
+            let notify = Arc::new(crate::sync::notify::Notify::new());
+
+            for task in tasks {
+                let remaining = remaining.clone();
+                let notify = notify.clone();
+
+                self.spawn(async move {
+                    task.await;
+
+                    // The last task to finish wakes anyone waiting below.
+                    if remaining.fetch_sub(1, Ordering::AcqRel) == 1 {
+                        notify.notify_waiters();
+                    }
+                });
+            }
+
+            while remaining.load(Ordering::Acquire) != 0 {
+                let notified = notify.notified();
+
+                // Re-check after constructing the `Notified` future so a
+                // completion racing with the check above isn't missed.
+                if remaining.load(Ordering::Acquire) == 0 {
+                    break;
+                }
+
+                notified.await;
+            }
+        }
     }
 
     /// Returns a view that lets you get information about how the runtime
@@ -418,6 +1116,130 @@ impl Handle {
     }
 }
 
+cfg_unstable_metrics! {
+    impl Handle {
+        /// Captures a point-in-time snapshot of the full scheduler state:
+        /// per-worker status, queue depths, LIFO slot occupancy, and global
+        /// queue intervals, plus the injection queue depth and (where
+        /// available) the steal matrix.
+        ///
+        /// This consolidates the individual accessors on
+        /// [`RuntimeMetrics`] into a single report, convenient for a
+        /// diagnostics endpoint or an operator dump. Each field is still
+        /// read independently off the same lock-free counters those
+        /// accessors use, so, like [`RuntimeMetrics::steal_matrix`], the
+        /// result is a best-effort snapshot rather than one taken under a
+        /// single consistent pause of the scheduler.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of
+        /// this method may break in 1.x releases. See [the documentation on
+        /// unstable features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let dump = Handle::current().scheduler_dump();
+        ///     for worker in dump.workers() {
+        ///         println!("worker {}: {:?}", worker.index(), worker.status());
+        ///     }
+        /// }
+        /// ```
+        pub fn scheduler_dump(&self) -> crate::runtime::SchedulerDump {
+            crate::runtime::SchedulerDump::capture(&self.metrics())
+        }
+
+        /// Returns the wall-clock time the given worker last started polling
+        /// a task, or the time this method (or [`scheduler_dump`]) was first
+        /// called anywhere in the process if that worker has not yet polled
+        /// one.
+        ///
+        /// Unlike the accessors on [`RuntimeMetrics`], this is updated the
+        /// moment a task poll begins rather than only when metrics are next
+        /// submitted, so it stays accurate for a worker currently stuck
+        /// inside a long-running, non-yielding poll. A watchdog can poll
+        /// this periodically and flag any worker whose last activity is far
+        /// enough in the past to indicate it is stuck, complementing a
+        /// push-based poll-duration watchdog with a pull-based check that
+        /// works even while the offending poll is still running.
+        ///
+        /// # Panics
+        ///
+        /// This method panics if `worker` is greater than or equal to the
+        /// number of workers in the runtime.
+        ///
+        /// **Note**: This is an [unstable API][unstable]. The public API of
+        /// this method may break in 1.x releases. See [the documentation on
+        /// unstable features][unstable] for details.
+        ///
+        /// [unstable]: crate#unstable-features
+        /// [`scheduler_dump`]: Handle::scheduler_dump
+        /// [`RuntimeMetrics`]: crate::runtime::RuntimeMetrics
+        pub fn worker_last_activity(&self, worker: usize) -> std::time::Instant {
+            self.inner.worker_metrics(worker).last_activity()
+        }
+    }
+}
+
+cfg_unstable! {
+    /// A batch of dynamically adjustable scheduler settings, passed to a
+    /// closure by [`Handle::update_config`].
+    ///
+    /// Only settings that are safe to change on a live runtime have a
+    /// setter here. Settings that are fixed for the lifetime of the
+    /// runtime, such as the worker count, have no setter and so cannot be
+    /// changed through this type.
+    ///
+    /// **Note**: This is an [unstable API][unstable]. The public API of this
+    /// type may break in 1.x releases. See [the documentation on unstable
+    /// features][unstable] for details.
+    ///
+    /// [unstable]: crate#unstable-features
+    #[derive(Debug)]
+    pub struct RuntimeConfigMut<'a> {
+        handle: &'a scheduler::Handle,
+    }
+
+    impl RuntimeConfigMut<'_> {
+        /// Dynamically enables or disables the LIFO slot optimization for
+        /// every worker thread on this runtime. See
+        /// [`Handle::set_lifo_enabled_all`].
+        pub fn set_lifo_enabled(&mut self, enabled: bool) -> &mut Self {
+            match self.handle {
+                scheduler::Handle::CurrentThread(handle) => handle.set_lifo_enabled_all(enabled),
+                #[cfg(feature = "rt-multi-thread")]
+                scheduler::Handle::MultiThread(handle) => handle.set_lifo_enabled_all(enabled),
+                #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                scheduler::Handle::MultiThreadAlt(handle) => handle.set_lifo_enabled_all(enabled),
+            }
+            self
+        }
+
+        /// Dynamically enables or disables steal-back for every worker
+        /// thread on this runtime. See [`Builder::steal_back`].
+        ///
+        /// Does nothing for the current-thread scheduler, which has no
+        /// work-stealing to steal back from.
+        ///
+        /// [`Builder::steal_back`]: crate::runtime::Builder::steal_back
+        pub fn set_steal_back(&mut self, enabled: bool) -> &mut Self {
+            match self.handle {
+                scheduler::Handle::CurrentThread(handle) => handle.set_steal_back_enabled(enabled),
+                #[cfg(feature = "rt-multi-thread")]
+                scheduler::Handle::MultiThread(handle) => handle.set_steal_back_enabled(enabled),
+                #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                scheduler::Handle::MultiThreadAlt(handle) => handle.set_steal_back_enabled(enabled),
+            }
+            self
+        }
+    }
+}
+
 cfg_taskdump! {
     impl Handle {
         /// Captures a snapshot of the runtime's state.
@@ -583,6 +1405,115 @@ cfg_taskdump! {
     }
 }
 
+cfg_unstable! {
+    cfg_rt_multi_thread! {
+        impl Handle {
+            /// Runs the provided closure like [`task::block_in_place`], but
+            /// treats the core handoff as optional: if `hint_duration` is
+            /// shorter than the runtime's configured threshold, `f` runs
+            /// inline on the current worker thread without handing its core
+            /// off, at the cost of pausing that worker's other queued tasks
+            /// for the duration of the call. Otherwise, this behaves exactly
+            /// like `block_in_place`, handing the core off to a new worker
+            /// thread so other tasks keep making progress.
+            ///
+            /// `hint_duration` is purely advisory: it is not measured against
+            /// how long `f` actually takes, so an inaccurate hint has no
+            /// effect beyond running inline (or handing off) for longer than
+            /// would have been ideal. It exists to let a caller that already
+            /// knows roughly how long its blocking section takes avoid paying
+            /// for a handoff it doesn't need.
+            ///
+            /// Just like `block_in_place`, this panics if called from a
+            /// [`current_thread`] runtime.
+            ///
+            /// [`task::block_in_place`]: crate::task::block_in_place
+            /// [`current_thread`]: fn@crate::runtime::Builder::new_current_thread
+            ///
+            /// **Note**: This is an [unstable API][unstable]. The public API of this
+            /// function may break in 1.x releases. See [the documentation on unstable
+            /// features][unstable] for details.
+            ///
+            /// [unstable]: crate#unstable-features
+            #[track_caller]
+            pub fn block_in_place_for<F, R>(hint_duration: std::time::Duration, f: F) -> R
+            where
+                F: FnOnce() -> R,
+            {
+                scheduler::block_in_place_for(hint_duration, f)
+            }
+
+            /// Runs `f` once on every worker, at a safe point between tasks,
+            /// and resolves once every worker has done so.
+            ///
+            /// This is intended for consistent global operations across the
+            /// whole pool, e.g. swapping a shared config or flushing
+            /// per-worker caches, without racing an individual worker
+            /// mid-poll. Concurrent `barrier` calls are serialized: a second
+            /// caller blocks until the first one's callback has finished
+            /// running on every worker.
+            ///
+            /// Similar to task dumps, the returned future may never resolve
+            /// if a worker is blocked for more than 250ms, e.g. by a
+            /// long-running blocking task.
+            ///
+            /// **Note**: This is an [unstable API][unstable]. The public API of this
+            /// function may break in 1.x releases. See [the documentation on unstable
+            /// features][unstable] for details.
+            ///
+            /// [unstable]: crate#unstable-features
+            pub async fn barrier<F>(&self, f: F)
+            where
+                F: Fn() + Send + Sync + 'static,
+            {
+                match &self.inner {
+                    scheduler::Handle::MultiThread(handle) => handle.barrier(f).await,
+                    #[cfg(tokio_unstable)]
+                    scheduler::Handle::MultiThreadAlt(_) => {
+                        panic!("barrier not implemented for this runtime flavor")
+                    }
+                    scheduler::Handle::CurrentThread(_) => {
+                        panic!("barrier is only supported by the multi_thread runtime")
+                    }
+                }
+            }
+        }
+    }
+}
+
+cfg_rt! {
+    impl Handle {
+        /// Runs `f`, temporarily raising the current task's remaining
+        /// cooperative scheduling budget by `extra`.
+        ///
+        /// Tokio periodically forces a task to yield back to the scheduler
+        /// once it has done "enough" work, so that other tasks get a chance
+        /// to run. `with_extended_budget` lets a bounded critical section —
+        /// e.g. a batch of quick channel sends that should complete as a
+        /// unit — spend more of that budget than it normally would be
+        /// allowed to, without being interrupted partway through.
+        ///
+        /// The extra budget is only borrowed for the duration of `f`: once
+        /// `f` returns, the budget is reduced back by `extra`, so whatever
+        /// `f` actually consumed still counts against the task's budget for
+        /// the rest of the current poll. This only affects the poll that is
+        /// currently running; it does not persist across `.await` points
+        /// that suspend the task, since a fresh budget is assigned the next
+        /// time the task is polled.
+        ///
+        /// # Starvation risk
+        ///
+        /// Raising the budget delays the point at which this task yields to
+        /// its peers. Using a large `extra`, or calling this from a task
+        /// that already does a lot of work per poll, can starve other tasks
+        /// on the same worker thread. Keep the extended section short and
+        /// bounded.
+        pub fn with_extended_budget<R>(extra: u8, f: impl FnOnce() -> R) -> R {
+            crate::runtime::coop::with_extended_budget(extra, f)
+        }
+    }
+}
+
 /// Error returned by `try_current` when no Runtime has been started
 #[derive(Debug)]
 pub struct TryCurrentError {
@@ -1,4 +1,4 @@
-use crate::runtime::metrics::{HistogramBatch, WorkerMetrics};
+use crate::runtime::metrics::{HistogramBatch, WorkerMetrics, WorkerMetricsDelta};
 
 use std::sync::atomic::Ordering::Relaxed;
 use std::time::{Duration, Instant};
@@ -19,9 +19,41 @@ pub(crate) struct MetricsBatch {
     /// Number of times tasks where stolen.
     steal_operations: u64,
 
+    /// Number of times a steal succeeded against the very first victim
+    /// examined, i.e. without having to scan past any empty/shallow
+    /// candidates first.
+    steal_first_try_success_count: u64,
+
+    /// Number of times a stolen batch was pushed back to the injection queue.
+    steal_back_count: u64,
+
+    /// Number of times `steal_work` found no task to steal from any peer
+    /// worker and fell back to the global queue, where it did find a task.
+    steal_global_fallback_count: u64,
+
+    /// Number of times `steal_work` was denied permission to search for
+    /// tasks to steal by the idle subsystem's half-searcher guard.
+    steal_search_denied_count: u64,
+
+    /// Number of times the worker acquired a core after fully parking.
+    core_acquisitions_count: u64,
+
     /// Number of tasks that were polled by the worker.
     poll_count: u64,
 
+    /// Number of individual `task.run()` calls that completed the task on
+    /// that poll. Only incremented by the multi-threaded scheduler.
+    completed_poll_count: u64,
+
+    /// Number of individual `task.run()` calls that left the task pending
+    /// (i.e. it returned `Pending` and did not wake itself). Only
+    /// incremented by the multi-threaded scheduler.
+    pending_poll_count: u64,
+
+    /// Number of tasks this worker has driven to completion. Tracked by
+    /// every scheduler flavor.
+    completed_tasks: u64,
+
     /// Number of tasks polled when the worker entered park. This is used to
     /// track the noop count.
     poll_count_on_last_park: u64,
@@ -33,14 +65,62 @@ pub(crate) struct MetricsBatch {
     /// queue
     overflow_count: u64,
 
+    /// Number of times a task was pushed straight to the global queue instead
+    /// of the local queue because `Config::overflow_policy` is
+    /// `OverflowPolicy::Reject` and the local queue was full.
+    overflow_reject_count: u64,
+
+    /// Number of times the worker was woken (not from a timeout) but found
+    /// no task waiting for it, meaning the notification that woke it was
+    /// wasted.
+    notify_no_work_count: u64,
+
+    /// Number of times a task already sitting in the LIFO slot was evicted
+    /// to the run queue by a newer task claiming the slot.
+    lifo_eviction_count: u64,
+
+    /// Number of times a task in the LIFO slot was pushed to the back of the
+    /// run queue instead of being polled immediately because the worker had
+    /// exhausted its coop budget.
+    lifo_budget_demotion_count: u64,
+
+    /// Number of times `next_task` returned a task pulled from the worker's
+    /// local queue or LIFO slot.
+    local_queue_pull_count: u64,
+
+    /// Number of times `next_task` returned a task pulled from the global
+    /// (injection) queue.
+    global_queue_pull_count: u64,
+
     /// The total busy duration in nanoseconds.
     busy_duration_total: u64,
 
+    /// The worker thread's total CPU time in nanoseconds, as of the last
+    /// call to `submit`. `None` on platforms that don't expose this.
+    cpu_time_total: Option<u64>,
+
     /// Instant at which work last resumed (continued after park).
     processing_scheduled_tasks_started_at: Instant,
 
     /// If `Some`, tracks poll times in nanoseconds
     poll_timer: Option<PollTimer>,
+
+    /// Histogram of `run_task`'s LIFO polling-chain lengths.
+    lifo_chain_length_histogram: HistogramBatch,
+
+    /// Snapshot of the cumulative counters as of the previous `submit`, used
+    /// to compute the `WorkerMetricsDelta` handed to `Builder::metrics_sink`.
+    last_submitted: MetricsBaseline,
+}
+
+#[derive(Default)]
+struct MetricsBaseline {
+    poll_count: u64,
+    local_schedule_count: u64,
+    overflow_count: u64,
+    completed_tasks: u64,
+    busy_duration_total: u64,
+    park_count: u64,
 }
 
 struct PollTimer {
@@ -61,11 +141,26 @@ impl MetricsBatch {
             noop_count: 0,
             steal_count: 0,
             steal_operations: 0,
+            steal_first_try_success_count: 0,
+            steal_back_count: 0,
+            steal_global_fallback_count: 0,
+            steal_search_denied_count: 0,
+            core_acquisitions_count: 0,
             poll_count: 0,
+            completed_poll_count: 0,
+            pending_poll_count: 0,
+            completed_tasks: 0,
             poll_count_on_last_park: 0,
             local_schedule_count: 0,
             overflow_count: 0,
+            overflow_reject_count: 0,
+            notify_no_work_count: 0,
+            lifo_eviction_count: 0,
+            lifo_budget_demotion_count: 0,
+            local_queue_pull_count: 0,
+            global_queue_pull_count: 0,
             busy_duration_total: 0,
+            cpu_time_total: None,
             processing_scheduled_tasks_started_at: now,
             poll_timer: worker_metrics
                 .poll_count_histogram
@@ -74,10 +169,21 @@ impl MetricsBatch {
                     poll_counts: HistogramBatch::from_histogram(worker_poll_counts),
                     poll_started_at: now,
                 }),
+            lifo_chain_length_histogram: HistogramBatch::from_histogram(
+                worker_metrics
+                    .lifo_chain_length_histogram
+                    .as_ref()
+                    .unwrap(),
+            ),
+            last_submitted: MetricsBaseline::default(),
         }
     }
 
-    pub(crate) fn submit(&mut self, worker: &WorkerMetrics, mean_poll_time: u64) {
+    pub(crate) fn submit(
+        &mut self,
+        worker: &WorkerMetrics,
+        mean_poll_time: u64,
+    ) -> WorkerMetricsDelta {
         worker.mean_poll_time.store(mean_poll_time, Relaxed);
         worker.park_count.store(self.park_count, Relaxed);
         worker
@@ -88,21 +194,98 @@ impl MetricsBatch {
         worker
             .steal_operations
             .store(self.steal_operations, Relaxed);
+        worker
+            .steal_first_try_success_count
+            .store(self.steal_first_try_success_count, Relaxed);
+        worker
+            .steal_back_count
+            .store(self.steal_back_count, Relaxed);
+        worker
+            .steal_global_fallback_count
+            .store(self.steal_global_fallback_count, Relaxed);
+        worker
+            .steal_search_denied_count
+            .store(self.steal_search_denied_count, Relaxed);
+        worker
+            .core_acquisitions_count
+            .store(self.core_acquisitions_count, Relaxed);
         worker.poll_count.store(self.poll_count, Relaxed);
+        worker
+            .completed_poll_count
+            .store(self.completed_poll_count, Relaxed);
+        worker
+            .pending_poll_count
+            .store(self.pending_poll_count, Relaxed);
+        worker
+            .completed_tasks
+            .store(self.completed_tasks, Relaxed);
 
         worker
             .busy_duration_total
             .store(self.busy_duration_total, Relaxed);
 
+        // Sampled here, rather than accumulated like `busy_duration_total`,
+        // since `clock_gettime(CLOCK_THREAD_CPUTIME_ID)` already reports the
+        // thread's lifetime total.
+        self.cpu_time_total = crate::util::thread_cpu_time().map(duration_as_u64);
+        if let Some(cpu_time_total) = self.cpu_time_total {
+            worker.cpu_time_total.store(cpu_time_total, Relaxed);
+        }
+
         worker
             .local_schedule_count
             .store(self.local_schedule_count, Relaxed);
         worker.overflow_count.store(self.overflow_count, Relaxed);
+        worker
+            .overflow_reject_count
+            .store(self.overflow_reject_count, Relaxed);
+        worker
+            .notify_no_work_count
+            .store(self.notify_no_work_count, Relaxed);
+        worker
+            .lifo_eviction_count
+            .store(self.lifo_eviction_count, Relaxed);
+        worker
+            .lifo_budget_demotion_count
+            .store(self.lifo_budget_demotion_count, Relaxed);
+
+        worker
+            .local_queue_pull_count
+            .store(self.local_queue_pull_count, Relaxed);
+        worker
+            .global_queue_pull_count
+            .store(self.global_queue_pull_count, Relaxed);
 
         if let Some(poll_timer) = &self.poll_timer {
             let dst = worker.poll_count_histogram.as_ref().unwrap();
             poll_timer.poll_counts.submit(dst);
         }
+
+        self.lifo_chain_length_histogram
+            .submit(worker.lifo_chain_length_histogram.as_ref().unwrap());
+
+        let delta = WorkerMetricsDelta {
+            poll_count: self.poll_count - self.last_submitted.poll_count,
+            local_schedule_count: self.local_schedule_count
+                - self.last_submitted.local_schedule_count,
+            overflow_count: self.overflow_count - self.last_submitted.overflow_count,
+            completed_tasks: self.completed_tasks - self.last_submitted.completed_tasks,
+            park_count: self.park_count - self.last_submitted.park_count,
+            busy_duration: Duration::from_nanos(
+                self.busy_duration_total - self.last_submitted.busy_duration_total,
+            ),
+        };
+
+        self.last_submitted = MetricsBaseline {
+            poll_count: self.poll_count,
+            local_schedule_count: self.local_schedule_count,
+            overflow_count: self.overflow_count,
+            completed_tasks: self.completed_tasks,
+            busy_duration_total: self.busy_duration_total,
+            park_count: self.park_count,
+        };
+
+        delta
     }
 
     /// The worker is about to park.
@@ -153,6 +336,23 @@ impl MetricsBatch {
     pub(crate) fn inc_local_schedule_count(&mut self) {
         self.local_schedule_count += 1;
     }
+
+    /// Increment the number of times `next_task` returned a task pulled from
+    /// the local queue or LIFO slot.
+    pub(crate) fn incr_local_queue_pull_count(&mut self) {
+        self.local_queue_pull_count += 1;
+    }
+
+    /// Increment the number of times `next_task` returned a task pulled from
+    /// the global (injection) queue.
+    pub(crate) fn incr_global_queue_pull_count(&mut self) {
+        self.global_queue_pull_count += 1;
+    }
+
+    /// Records that this worker just drove a task to completion.
+    pub(crate) fn incr_completed_tasks(&mut self) {
+        self.completed_tasks += 1;
+    }
 }
 
 cfg_rt_multi_thread! {
@@ -165,9 +365,60 @@ cfg_rt_multi_thread! {
             self.steal_operations += 1;
         }
 
+        pub(crate) fn incr_steal_first_try_success(&mut self) {
+            self.steal_first_try_success_count += 1;
+        }
+
+        pub(crate) fn incr_steal_back_count(&mut self, by: u16) {
+            self.steal_back_count += by as u64;
+        }
+
+        pub(crate) fn incr_steal_global_fallback_count(&mut self) {
+            self.steal_global_fallback_count += 1;
+        }
+
+        pub(crate) fn incr_steal_search_denied_count(&mut self) {
+            self.steal_search_denied_count += 1;
+        }
+
         pub(crate) fn incr_overflow_count(&mut self) {
             self.overflow_count += 1;
         }
+
+        pub(crate) fn incr_overflow_reject_count(&mut self) {
+            self.overflow_reject_count += 1;
+        }
+
+        pub(crate) fn incr_notify_no_work_count(&mut self) {
+            self.notify_no_work_count += 1;
+        }
+
+        pub(crate) fn incr_lifo_eviction_count(&mut self) {
+            self.lifo_eviction_count += 1;
+        }
+
+        pub(crate) fn incr_lifo_budget_demotion_count(&mut self) {
+            self.lifo_budget_demotion_count += 1;
+        }
+
+        pub(crate) fn incr_completed_poll_count(&mut self) {
+            self.completed_poll_count += 1;
+        }
+
+        pub(crate) fn incr_pending_poll_count(&mut self) {
+            self.pending_poll_count += 1;
+        }
+
+        pub(crate) fn incr_core_acquisitions_count(&mut self) {
+            self.core_acquisitions_count += 1;
+        }
+
+        /// Records that `run_task`'s LIFO polling loop polled `length` tasks
+        /// in a row straight from the LIFO slot before falling back to the
+        /// run queue (`0` if the loop never ran).
+        pub(crate) fn record_lifo_chain_length(&mut self, length: usize) {
+            self.lifo_chain_length_histogram.measure(length as u64, 1);
+        }
     }
 }
 
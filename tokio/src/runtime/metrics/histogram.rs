@@ -60,6 +60,12 @@ impl Histogram {
         }
     }
 
+    pub(crate) fn reset(&self) {
+        for bucket in self.buckets.iter() {
+            bucket.store(0, Relaxed);
+        }
+    }
+
     pub(crate) fn bucket_range(&self, bucket: usize) -> Range<u64> {
         match self.scale {
             HistogramScale::Log => Range {
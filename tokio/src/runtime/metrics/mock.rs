@@ -18,6 +18,18 @@ impl SchedulerMetrics {
 
     /// Increment the number of tasks scheduled externally
     pub(crate) fn inc_remote_schedule_count(&self) {}
+
+    pub(crate) fn inc_live_worker_thread_count(&self) {}
+    pub(crate) fn dec_live_worker_thread_count(&self) {}
+    pub(crate) fn live_worker_thread_count(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn inc_outstanding_block_in_place_count(&self) {}
+    pub(crate) fn dec_outstanding_block_in_place_count(&self) {}
+    pub(crate) fn outstanding_block_in_place_count(&self) -> usize {
+        0
+    }
 }
 
 impl WorkerMetrics {
@@ -32,7 +44,23 @@ impl WorkerMetrics {
     }
 
     pub(crate) fn set_queue_depth(&self, _len: usize) {}
+    pub(crate) fn set_run_queue_remaining(&self, _remaining: usize) {}
+    pub(crate) fn set_lifo_slot_occupied(&self, _occupied: bool) {}
+    pub(crate) fn set_consecutive_idle(&self, _value: u64) {}
+    #[allow(dead_code)]
+    pub(crate) fn global_queue_interval(&self) -> u32 {
+        0
+    }
+    pub(crate) fn set_global_queue_interval(&self, _value: u32) {}
     pub(crate) fn set_thread_id(&self, _thread_id: ThreadId) {}
+    pub(crate) fn set_worker_status_running(&self) {}
+    pub(crate) fn set_worker_status_searching(&self) {}
+    pub(crate) fn set_worker_status_parked(&self) {}
+    pub(crate) fn record_deferred_wake_count(&self, _count: u64) {}
+    #[allow(dead_code)]
+    pub(crate) fn incr_self_wake_count(&self) {}
+    pub(crate) fn incr_driver_scheduled_task_count(&self) {}
+    pub(crate) fn record_poll_start(&self) {}
 }
 
 impl MetricsBatch {
@@ -44,16 +72,30 @@ impl MetricsBatch {
     pub(crate) fn about_to_park(&mut self) {}
     pub(crate) fn unparked(&mut self) {}
     pub(crate) fn inc_local_schedule_count(&mut self) {}
+    pub(crate) fn incr_local_queue_pull_count(&mut self) {}
+    pub(crate) fn incr_global_queue_pull_count(&mut self) {}
     pub(crate) fn start_processing_scheduled_tasks(&mut self) {}
     pub(crate) fn end_processing_scheduled_tasks(&mut self) {}
     pub(crate) fn start_poll(&mut self) {}
     pub(crate) fn end_poll(&mut self) {}
+    pub(crate) fn incr_completed_tasks(&mut self) {}
 }
 
 cfg_rt_multi_thread! {
     impl MetricsBatch {
         pub(crate) fn incr_steal_count(&mut self, _by: u16) {}
         pub(crate) fn incr_steal_operations(&mut self) {}
+        pub(crate) fn incr_steal_first_try_success(&mut self) {}
+        pub(crate) fn incr_steal_back_count(&mut self, _by: u16) {}
+        pub(crate) fn incr_steal_global_fallback_count(&mut self) {}
+        pub(crate) fn incr_steal_search_denied_count(&mut self) {}
         pub(crate) fn incr_overflow_count(&mut self) {}
+        pub(crate) fn incr_overflow_reject_count(&mut self) {}
+        pub(crate) fn incr_notify_no_work_count(&mut self) {}
+        pub(crate) fn incr_lifo_eviction_count(&mut self) {}
+        pub(crate) fn incr_lifo_budget_demotion_count(&mut self) {}
+        pub(crate) fn incr_completed_poll_count(&mut self) {}
+        pub(crate) fn incr_pending_poll_count(&mut self) {}
+        pub(crate) fn record_lifo_chain_length(&mut self, _length: usize) {}
     }
 }
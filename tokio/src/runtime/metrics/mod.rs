@@ -26,6 +26,12 @@ cfg_unstable_metrics! {
 
     mod worker;
     pub(crate) use worker::WorkerMetrics;
+    #[allow(unreachable_pub)] // rust-lang/rust#57411
+    pub use worker::{WorkerMetricsDelta, WorkerStatus};
+
+    mod scheduler_dump;
+    #[allow(unreachable_pub)] // rust-lang/rust#57411
+    pub use scheduler_dump::{SchedulerDump, WorkerDump};
 
     cfg_net! {
         mod io;
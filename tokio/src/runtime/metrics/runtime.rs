@@ -154,6 +154,185 @@ impl RuntimeMetrics {
             self.handle.inner.num_idle_blocking_threads()
         }
 
+        /// Returns the number of worker threads currently executing.
+        ///
+        /// On the multi-threaded scheduler, `block_in_place` hands a worker's
+        /// core off to a newly spawned thread so the originating thread can
+        /// block. While the handoff is in progress, more threads may be
+        /// executing worker logic than `num_workers()` configured workers.
+        /// This metric tracks that live count, which can help diagnose thread
+        /// growth caused by heavy `block_in_place` usage.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let n = metrics.live_worker_thread_count();
+        ///     println!("Runtime has {} live worker threads", n);
+        /// }
+        /// ```
+        pub fn live_worker_thread_count(&self) -> usize {
+            self.handle.inner.live_worker_thread_count()
+        }
+
+        /// Returns the number of `block_in_place` core handoffs currently
+        /// outstanding.
+        ///
+        /// A handoff is outstanding from the moment `block_in_place` sends a
+        /// worker's core to a newly spawned thread until the original
+        /// thread reacquires it (or gives up trying to). This is a subset
+        /// of [`live_worker_thread_count`]: it excludes the runtime's
+        /// original worker threads and counts only extra threads spawned to
+        /// take over a core for the duration of a blocking call. Combine
+        /// with [`Builder::max_concurrent_block_in_place`] to bound handoff
+        /// churn specifically.
+        ///
+        /// [`live_worker_thread_count`]: Self::live_worker_thread_count
+        /// [`Builder::max_concurrent_block_in_place`]: crate::runtime::Builder::max_concurrent_block_in_place
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let n = metrics.outstanding_block_in_place_count();
+        ///     println!("Runtime has {} outstanding block_in_place handoffs", n);
+        /// }
+        /// ```
+        pub fn outstanding_block_in_place_count(&self) -> usize {
+            self.handle.inner.outstanding_block_in_place_count()
+        }
+
+        /// Returns the approximate total number of tasks currently pending
+        /// across the runtime.
+        ///
+        /// This is the sum of every worker's local queue depth, the LIFO
+        /// slot occupancy (multi-threaded scheduler only), and the
+        /// injection queue depth. It is a **best-effort** snapshot: it reads
+        /// several independent counters without a global lock, so the
+        /// returned value may be momentarily inconsistent, especially under
+        /// concurrent scheduling activity.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let n = metrics.total_pending_tasks();
+        ///     println!("{} tasks are currently pending", n);
+        /// }
+        /// ```
+        pub fn total_pending_tasks(&self) -> usize {
+            self.handle.inner.total_pending_tasks()
+        }
+
+        /// Returns the highest number of workers observed searching for work
+        /// at the same time since the runtime was created, or since the last
+        /// call to [`reset_peak_searching_workers`].
+        ///
+        /// A value close to [`num_workers`] indicates that the scheduler's
+        /// guard against too many workers searching simultaneously isn't
+        /// keeping the number of concurrent searchers down as much as
+        /// intended, which is worth investigating as a tuning or scheduler
+        /// bug.
+        ///
+        /// [`reset_peak_searching_workers`]: Self::reset_peak_searching_workers
+        /// [`num_workers`]: Self::num_workers
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let n = metrics.peak_searching_workers();
+        ///     println!("At most {} workers have searched for work at once", n);
+        /// }
+        /// ```
+        pub fn peak_searching_workers(&self) -> usize {
+            self.handle.inner.peak_searching_workers()
+        }
+
+        /// Resets the high-water mark returned by [`peak_searching_workers`]
+        /// back down to however many workers are searching for work right
+        /// now.
+        ///
+        /// [`peak_searching_workers`]: Self::peak_searching_workers
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     metrics.reset_peak_searching_workers();
+        /// }
+        /// ```
+        pub fn reset_peak_searching_workers(&self) {
+            self.handle.inner.reset_peak_searching_workers()
+        }
+
+        /// Resets all runtime metrics to zero.
+        ///
+        /// This zeroes the scheduler-wide counters as well as every worker's
+        /// counters, including high-water-mark style values and EWMAs (such
+        /// as [`worker_mean_poll_time`]). Gauges that reflect current state
+        /// rather than accumulated history, such as
+        /// [`live_worker_thread_count`] and [`worker_local_queue_depth`],
+        /// are left untouched, since they aren't meaningful to "reset" to
+        /// zero without misreporting the runtime's actual state.
+        ///
+        /// This is intended for services that want metrics scoped to a
+        /// reporting interval rather than the runtime's entire lifetime.
+        /// Resetting is not atomic across counters: a metric being submitted
+        /// concurrently with the reset may still land its update
+        /// immediately afterward, so a reset is only precise up to a small
+        /// amount of in-flight data.
+        ///
+        /// [`worker_mean_poll_time`]: Self::worker_mean_poll_time
+        /// [`live_worker_thread_count`]: Self::live_worker_thread_count
+        /// [`worker_local_queue_depth`]: Self::worker_local_queue_depth
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     metrics.reset_metrics();
+        /// }
+        /// ```
+        pub fn reset_metrics(&self) {
+            self.handle.inner.scheduler_metrics().reset();
+            self.handle.inner.reset_peak_searching_workers();
+
+            for worker in 0..self.num_workers() {
+                self.handle.inner.worker_metrics(worker).reset();
+            }
+        }
+
         /// Returns the thread id of the given worker thread.
         ///
         /// The returned value is `None` if the worker thread has not yet finished
@@ -269,6 +448,165 @@ impl RuntimeMetrics {
                     .load(Relaxed)
             }
 
+            /// Returns the moving average of how long a task spends in the
+            /// injection queue before being picked up by a worker.
+            ///
+            /// Tasks land on the injection queue when they are scheduled
+            /// from outside the runtime, or as overflow when a worker's
+            /// local run queue is full. A growing average can indicate that
+            /// workers aren't keeping up with the rate at which tasks are
+            /// being scheduled remotely.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.mean_inject_queue_wait();
+            ///     println!("Tasks wait {:?} in the injection queue on average", n);
+            /// }
+            /// ```
+            pub fn mean_inject_queue_wait(&self) -> Duration {
+                self.handle.inner.scheduler_metrics().mean_inject_queue_wait()
+            }
+
+            /// Returns the aggregate time spent waiting to acquire the
+            /// scheduler's internal `synced` lock, which guards cores, the
+            /// injection queue, idle state, and the driver handoff.
+            ///
+            /// Only a sample of lock acquisitions are timed, with each
+            /// sample scaled up to keep the total an unbiased estimate of
+            /// the true aggregate. A large or fast-growing value indicates
+            /// this lock is a scalability bottleneck for the workload.
+            ///
+            /// Currently only measured by the `multi_thread_alt` scheduler;
+            /// always zero otherwise.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.synced_lock_contention_time();
+            ///     println!("Workers waited {:?} total to acquire the synced lock", n);
+            /// }
+            /// ```
+            pub fn synced_lock_contention_time(&self) -> Duration {
+                self.handle
+                    .inner
+                    .scheduler_metrics()
+                    .synced_lock_contention_time()
+            }
+
+            /// Returns how long the most recent shutdown's single-threaded
+            /// final phase spent draining workers' local run queues.
+            ///
+            /// This phase begins once every worker has observed the
+            /// shutdown signal and pushed its core back to the runtime, and
+            /// this is the first step it performs. It runs on a single
+            /// thread, so it can dominate shutdown latency for large pools;
+            /// this metric, together with [`shutdown_driver_time`] and
+            /// [`shutdown_inject_drain_time`], tells you which part.
+            ///
+            /// Zero if no shutdown has reached this phase yet.
+            ///
+            /// [`shutdown_driver_time`]: RuntimeMetrics::shutdown_driver_time
+            /// [`shutdown_inject_drain_time`]: RuntimeMetrics::shutdown_inject_drain_time
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.shutdown_task_drain_time();
+            ///     println!("Shutdown spent {:?} draining local run queues", n);
+            /// }
+            /// ```
+            pub fn shutdown_task_drain_time(&self) -> Duration {
+                self.handle
+                    .inner
+                    .scheduler_metrics()
+                    .shutdown_task_drain_duration()
+            }
+
+            /// Returns how long the most recent shutdown's single-threaded
+            /// final phase spent shutting down the resource driver.
+            ///
+            /// See [`shutdown_task_drain_time`] for where this phase fits
+            /// into shutdown. When
+            /// [`Builder::offload_driver_shutdown_to_dedicated_thread`] is
+            /// set, driver shutdown runs on its own thread rather than the
+            /// phase's thread, so it no longer contributes to the time
+            /// between the last worker parking and the runtime returning
+            /// from shutdown; this metric still reports how long it took.
+            ///
+            /// Zero if no shutdown has reached this phase yet.
+            ///
+            /// [`shutdown_task_drain_time`]: RuntimeMetrics::shutdown_task_drain_time
+            /// [`Builder::offload_driver_shutdown_to_dedicated_thread`]: crate::runtime::Builder::offload_driver_shutdown_to_dedicated_thread
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.shutdown_driver_time();
+            ///     println!("Shutdown spent {:?} shutting down the driver", n);
+            /// }
+            /// ```
+            pub fn shutdown_driver_time(&self) -> Duration {
+                self.handle
+                    .inner
+                    .scheduler_metrics()
+                    .shutdown_driver_duration()
+            }
+
+            /// Returns how long the most recent shutdown's single-threaded
+            /// final phase spent draining the injection queue.
+            ///
+            /// See [`shutdown_task_drain_time`] for where this phase fits
+            /// into shutdown.
+            ///
+            /// Zero if no shutdown has reached this phase yet.
+            ///
+            /// [`shutdown_task_drain_time`]: RuntimeMetrics::shutdown_task_drain_time
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.shutdown_inject_drain_time();
+            ///     println!("Shutdown spent {:?} draining the injection queue", n);
+            /// }
+            /// ```
+            pub fn shutdown_inject_drain_time(&self) -> Duration {
+                self.handle
+                    .inner
+                    .scheduler_metrics()
+                    .shutdown_inject_drain_duration()
+            }
+
             /// Returns the total number of times the given worker thread has parked.
             ///
             /// The worker park count starts at zero when the runtime is created and
@@ -502,10 +840,16 @@ impl RuntimeMetrics {
                     .load(Relaxed)
             }
 
-            /// Returns the number of tasks the given worker thread has polled.
+            /// Returns the number of times the given worker thread stole tasks from
+            /// the very first victim it examined, without having to scan past any
+            /// empty or too-shallow candidates first.
             ///
-            /// The worker poll count starts at zero when the runtime is created and
-            /// increases by one each time the worker polls a scheduled task.
+            /// Compare this against [`worker_steal_operations`] to see how often the
+            /// worker's chosen starting victim (see the `steal_order` scheduler
+            /// option) actually pays off.
+            ///
+            /// This metric only applies to the **multi-threaded** runtime and will
+            /// always return `0` when using the current thread runtime.
             ///
             /// The counter is monotonically increasing. It is never decremented or
             /// reset to zero.
@@ -531,28 +875,36 @@ impl RuntimeMetrics {
             /// async fn main() {
             ///     let metrics = Handle::current().metrics();
             ///
-            ///     let n = metrics.worker_poll_count(0);
-            ///     println!("worker 0 has polled {} tasks", n);
+            ///     let n = metrics.worker_steal_first_try_success_count(0);
+            ///     println!("worker 0 succeeded on the first try {} times", n);
             /// }
             /// ```
-            pub fn worker_poll_count(&self, worker: usize) -> u64 {
+            ///
+            /// [`worker_steal_operations`]: RuntimeMetrics::worker_steal_operations
+            pub fn worker_steal_first_try_success_count(&self, worker: usize) -> u64 {
                 self.handle
                     .inner
                     .worker_metrics(worker)
-                    .poll_count
+                    .steal_first_try_success_count
                     .load(Relaxed)
             }
 
-            /// Returns the amount of time the given worker thread has been busy.
+            /// Returns the number of times the given worker thread found no task to
+            /// steal from any peer worker and instead found one on the global
+            /// (injection) queue.
             ///
-            /// The worker busy duration starts at zero when the runtime is created and
-            /// increases whenever the worker is spending time processing work. Using
-            /// this value can indicate the load of the given worker. If a lot of time
-            /// is spent busy, then the worker is under load and will check for inbound
-            /// events less often.
+            /// Compare this against [`worker_steal_operations`] to see how much of
+            /// the worker's balancing is actually happening via peer stealing versus
+            /// the global queue. A high ratio of this metric relative to
+            /// `worker_steal_operations` means peer queues are usually empty by the
+            /// time this worker looks for work, so most balancing happens via the
+            /// global queue instead.
             ///
-            /// The timer is monotonically increasing. It is never decremented or reset
-            /// to zero.
+            /// This metric only applies to the **multi-threaded** runtime and will
+            /// always return `0` when using the current thread runtime.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
             ///
             /// # Arguments
             ///
@@ -575,28 +927,31 @@ impl RuntimeMetrics {
             /// async fn main() {
             ///     let metrics = Handle::current().metrics();
             ///
-            ///     let n = metrics.worker_total_busy_duration(0);
-            ///     println!("worker 0 was busy for a total of {:?}", n);
+            ///     let n = metrics.worker_steal_global_fallback_count(0);
+            ///     println!("worker 0 fell back to the global queue {} times", n);
             /// }
             /// ```
-            pub fn worker_total_busy_duration(&self, worker: usize) -> Duration {
-                let nanos = self
-                    .handle
+            ///
+            /// [`worker_steal_operations`]: RuntimeMetrics::worker_steal_operations
+            pub fn worker_steal_global_fallback_count(&self, worker: usize) -> u64 {
+                self.handle
                     .inner
                     .worker_metrics(worker)
-                    .busy_duration_total
-                    .load(Relaxed);
-                Duration::from_nanos(nanos)
+                    .steal_global_fallback_count
+                    .load(Relaxed)
             }
 
-            /// Returns the number of tasks scheduled from **within** the runtime on the
-            /// given worker's local queue.
+            /// Returns the number of times the given worker thread wanted to search
+            /// for tasks to steal but was denied by the idle subsystem's
+            /// half-searcher guard (more than half the workers were already
+            /// searching), and parked without stealing.
             ///
-            /// The local schedule count starts at zero when the runtime is created and
-            /// increases by one each time a task is woken from **inside** of the
-            /// runtime on the given worker. This usually means that a task is spawned
-            /// or notified from within a runtime thread and will be queued on the
-            /// worker-local queue.
+            /// Frequent denials mean workers are parking with available peer work
+            /// they weren't allowed to steal. Compare against [`peak_searching_workers`]
+            /// to see how close the runtime is running to the guard's limit.
+            ///
+            /// This metric only applies to the **multi-threaded** runtime and will
+            /// always return `0` when using the current thread runtime.
             ///
             /// The counter is monotonically increasing. It is never decremented or
             /// reset to zero.
@@ -622,33 +977,1147 @@ impl RuntimeMetrics {
             /// async fn main() {
             ///     let metrics = Handle::current().metrics();
             ///
-            ///     let n = metrics.worker_local_schedule_count(0);
-            ///     println!("{} tasks were scheduled on the worker's local queue", n);
+            ///     let n = metrics.worker_steal_search_denied_count(0);
+            ///     println!("worker 0 was denied permission to search {} times", n);
             /// }
             /// ```
-            pub fn worker_local_schedule_count(&self, worker: usize) -> u64 {
+            ///
+            /// [`peak_searching_workers`]: RuntimeMetrics::peak_searching_workers
+            pub fn worker_steal_search_denied_count(&self, worker: usize) -> u64 {
                 self.handle
                     .inner
                     .worker_metrics(worker)
-                    .local_schedule_count
+                    .steal_search_denied_count
                     .load(Relaxed)
             }
 
-            /// Returns the number of times the given worker thread saturated its local
-            /// queue.
-            ///
-            /// This metric only applies to the **multi-threaded** scheduler.
-            ///
-            /// The worker overflow count starts at zero when the runtime is created and
-            /// increases by one each time the worker attempts to schedule a task
-            /// locally, but its local queue is full. When this happens, half of the
-            /// local queue is moved to the injection queue.
-            ///
-            /// The counter is monotonically increasing. It is never decremented or
-            /// reset to zero.
-            ///
-            /// # Arguments
-            ///
+            cfg_64bit_metrics! {
+                /// Returns a matrix of steal counts between each pair of workers.
+                ///
+                /// The returned `Vec` has `num_workers()` rows, one per stealer, and
+                /// each row has `num_workers()` columns, one per victim.
+                /// `matrix[stealer][victim]` is the number of times the worker at
+                /// index `stealer` has successfully stolen tasks from the worker at
+                /// index `victim`.
+                ///
+                /// This metric only applies to the **multi-threaded** runtime. The
+                /// current thread runtime always returns a single-element matrix
+                /// containing `0`, since there is only one worker and it never
+                /// steals from itself. The alternate multi-threaded runtime does not
+                /// currently track this and always returns a matrix of zeroes.
+                ///
+                /// Each entry is monotonically increasing. It is never decremented
+                /// or reset to zero.
+                ///
+                /// This is a **best-effort** snapshot: each entry is read
+                /// independently, so the matrix as a whole may not reflect a single
+                /// consistent instant.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use tokio::runtime::Handle;
+                ///
+                /// #[tokio::main]
+                /// async fn main() {
+                ///     let metrics = Handle::current().metrics();
+                ///
+                ///     let matrix = metrics.steal_matrix();
+                ///     println!("{:?}", matrix);
+                /// }
+                /// ```
+                pub fn steal_matrix(&self) -> Vec<Vec<u64>> {
+                    self.handle.inner.steal_matrix()
+                }
+            }
+
+            /// Returns the number of times the given worker thread pushed a
+            /// just-stolen batch of tasks back to the injection queue because
+            /// the first task run from that batch blocked on its first poll.
+            ///
+            /// This metric only applies to the **multi-threaded** runtime and
+            /// will always return `0` when using the current thread runtime.
+            /// It is also always `0` unless the experimental steal-back mode
+            /// is enabled on the [`Builder`].
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_steal_back_count(0);
+            ///     println!("worker 0 has pushed back {} stolen batches", n);
+            /// }
+            /// ```
+            ///
+            /// [`Builder`]: crate::runtime::Builder
+            pub fn worker_steal_back_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .steal_back_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of times the given worker thread acquired a
+            /// core after fully parking, i.e. after it had released its core
+            /// and gone to sleep waiting for one to become available.
+            ///
+            /// This metric only applies to the **`multi_thread_alt`**
+            /// runtime, an experimental scheduler enabled via
+            /// [`RUSTFLAGS="--cfg tokio_unstable"`][unstable], and will
+            /// always return `0` otherwise. Combined with
+            /// [`worker_park_count`], a high acquisition count relative to
+            /// the park count indicates the worker is spending more of its
+            /// parks fully asleep than briefly yielding without releasing
+            /// its core.
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_core_acquisitions_count(0);
+            ///     println!("worker 0 acquired a core {} times", n);
+            /// }
+            /// ```
+            ///
+            /// [`worker_park_count`]: RuntimeMetrics::worker_park_count
+            /// [unstable]: crate#unstable-features
+            pub fn worker_core_acquisitions_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .core_acquisitions_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of tasks the given worker thread has polled.
+            ///
+            /// The worker poll count starts at zero when the runtime is created and
+            /// increases by one each time the worker polls a scheduled task.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_poll_count(0);
+            ///     println!("worker 0 has polled {} tasks", n);
+            /// }
+            /// ```
+            pub fn worker_poll_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .poll_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of tasks the given worker thread has polled to
+            /// completion.
+            ///
+            /// This counts individual `poll` calls that finished the task's
+            /// future, not tasks overall (a task may be polled, and thus
+            /// counted by [`worker_pending_poll_count`], any number of times
+            /// before it is finally counted here). Comparing this against
+            /// [`worker_pending_poll_count`] can help characterize a worker's
+            /// workload: a high completion ratio suggests short-lived tasks,
+            /// while a high pending ratio suggests tasks that await frequently.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// This metric only applies to the **multi-threaded** runtime and is
+            /// always `0` for the current thread runtime.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_completed_poll_count(0);
+            ///     println!("worker 0 has completed {} polls", n);
+            /// }
+            /// ```
+            ///
+            /// [`worker_pending_poll_count`]: crate::runtime::RuntimeMetrics::worker_pending_poll_count
+            pub fn worker_completed_poll_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .completed_poll_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of tasks the given worker thread has polled
+            /// without completing them.
+            ///
+            /// This counts individual `poll` calls that left the task pending
+            /// (i.e. the task returned `Pending` and did not wake itself
+            /// during that poll). See [`worker_completed_poll_count`] for the
+            /// complementary counter and how to interpret the two together.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// This metric only applies to the **multi-threaded** runtime and is
+            /// always `0` for the current thread runtime.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_pending_poll_count(0);
+            ///     println!("worker 0 left {} polls pending", n);
+            /// }
+            /// ```
+            ///
+            /// [`worker_completed_poll_count`]: crate::runtime::RuntimeMetrics::worker_completed_poll_count
+            pub fn worker_pending_poll_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .pending_poll_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of tasks the given worker thread has driven
+            /// to completion.
+            ///
+            /// Unlike [`worker_completed_poll_count`], which is only tracked by
+            /// the multi-threaded runtime, this counter is tracked by every
+            /// scheduler flavor, including the current thread runtime.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_completed_tasks(0);
+            ///     println!("worker 0 has completed {} tasks", n);
+            /// }
+            /// ```
+            ///
+            /// [`worker_completed_poll_count`]: crate::runtime::RuntimeMetrics::worker_completed_poll_count
+            pub fn worker_completed_tasks(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .completed_tasks
+                    .load(Relaxed)
+            }
+
+            /// Returns the amount of time the given worker thread has been busy.
+            ///
+            /// The worker busy duration starts at zero when the runtime is created and
+            /// increases whenever the worker is spending time processing work. Using
+            /// this value can indicate the load of the given worker. If a lot of time
+            /// is spent busy, then the worker is under load and will check for inbound
+            /// events less often.
+            ///
+            /// The timer is monotonically increasing. It is never decremented or reset
+            /// to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_total_busy_duration(0);
+            ///     println!("worker 0 was busy for a total of {:?}", n);
+            /// }
+            /// ```
+            pub fn worker_total_busy_duration(&self, worker: usize) -> Duration {
+                let nanos = self
+                    .handle
+                    .inner
+                    .worker_metrics(worker)
+                    .busy_duration_total
+                    .load(Relaxed);
+                Duration::from_nanos(nanos)
+            }
+
+            /// Returns the amount of CPU time the given worker thread has consumed,
+            /// as opposed to wall-clock time it has merely been alive for.
+            ///
+            /// Unlike [`worker_total_busy_duration`], which measures wall-clock
+            /// time and so includes time the worker was descheduled by the OS while
+            /// still "busy" from the scheduler's point of view, this measures actual
+            /// time spent executing on a CPU. A worker whose CPU time trails far
+            /// behind its busy duration is being starved by the OS scheduler rather
+            /// than doing genuine work.
+            ///
+            /// This is currently only available on Linux, via
+            /// `clock_gettime(CLOCK_THREAD_CPUTIME_ID)`. On every other platform
+            /// this always returns [`Duration::ZERO`].
+            ///
+            /// The timer is monotonically increasing. It is never decremented or reset
+            /// to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_total_cpu_time(0);
+            ///     println!("worker 0 has consumed {:?} of CPU time", n);
+            /// }
+            /// ```
+            ///
+            /// [`worker_total_busy_duration`]: RuntimeMetrics::worker_total_busy_duration
+            pub fn worker_total_cpu_time(&self, worker: usize) -> Duration {
+                let nanos = self
+                    .handle
+                    .inner
+                    .worker_metrics(worker)
+                    .cpu_time_total
+                    .load(Relaxed);
+                Duration::from_nanos(nanos)
+            }
+
+            /// Returns how long it took the given worker to observe that the
+            /// runtime was shutting down.
+            ///
+            /// This is the elapsed time between the runtime being closed and
+            /// the worker's maintenance loop first noticing the closed
+            /// injection queue, as described in the multi-threaded
+            /// scheduler's shutdown sequence. A worker stuck running a
+            /// long-running task without yielding will not run maintenance
+            /// and so will report a larger value here than one that yields
+            /// promptly.
+            ///
+            /// Returns [`Duration::ZERO`] if the runtime has not been shut
+            /// down, or if this worker has not yet observed the shutdown.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_shutdown_observed_after(0);
+            ///     println!("worker 0 took {:?} to observe shutdown", n);
+            /// }
+            /// ```
+            pub fn worker_shutdown_observed_after(&self, worker: usize) -> Duration {
+                let nanos = self
+                    .handle
+                    .inner
+                    .worker_metrics(worker)
+                    .shutdown_observed_after
+                    .load(Relaxed);
+                Duration::from_nanos(nanos)
+            }
+
+            /// Returns the number of tasks scheduled from **within** the runtime on the
+            /// given worker's local queue.
+            ///
+            /// The local schedule count starts at zero when the runtime is created and
+            /// increases by one each time a task is woken from **inside** of the
+            /// runtime on the given worker. This usually means that a task is spawned
+            /// or notified from within a runtime thread and will be queued on the
+            /// worker-local queue.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_local_schedule_count(0);
+            ///     println!("{} tasks were scheduled on the worker's local queue", n);
+            /// }
+            /// ```
+            pub fn worker_local_schedule_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .local_schedule_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of times the given worker thread saturated its local
+            /// queue.
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler.
+            ///
+            /// The worker overflow count starts at zero when the runtime is created and
+            /// increases by one each time the worker attempts to schedule a task
+            /// locally, but its local queue is full. When this happens, half of the
+            /// local queue is moved to the injection queue.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_overflow_count(0);
+            ///     println!("worker 0 has overflowed its queue {} times", n);
+            /// }
+            /// ```
+            pub fn worker_overflow_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .overflow_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the fraction of the given worker's local schedules that
+            /// overflowed its local queue, as a number between `0.0` and `1.0`.
+            ///
+            /// This is [`worker_overflow_count`] divided by the sum of
+            /// [`worker_overflow_count`] and [`worker_local_schedule_count`],
+            /// i.e. overflows as a share of all local scheduling attempts,
+            /// including the ones that overflowed. Returns `0.0` if the worker
+            /// hasn't scheduled anything locally yet.
+            ///
+            /// A high ratio means the worker's local queue is chronically too
+            /// small for its burst pattern; it's a more direct signal to alert
+            /// on than watching the two raw counters separately, and points
+            /// straight at whether the worker's local queue capacity needs to
+            /// go up.
+            ///
+            /// This metric only applies to the **multi-threaded** runtime and
+            /// will always return `0.0` when using the current thread runtime.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let ratio = metrics.worker_overflow_ratio(0);
+            ///     println!("worker 0's local queue overflowed {:.2}% of the time", ratio * 100.0);
+            /// }
+            /// ```
+            ///
+            /// [`worker_overflow_count`]: RuntimeMetrics::worker_overflow_count
+            /// [`worker_local_schedule_count`]: RuntimeMetrics::worker_local_schedule_count
+            pub fn worker_overflow_ratio(&self, worker: usize) -> f64 {
+                let overflow_count = self.worker_overflow_count(worker) as f64;
+                let local_schedule_count = self.worker_local_schedule_count(worker) as f64;
+
+                let total = overflow_count + local_schedule_count;
+
+                if total == 0.0 {
+                    0.0
+                } else {
+                    overflow_count / total
+                }
+            }
+
+            /// Returns the number of times the given worker thread rejected a
+            /// task straight to the injection queue instead of spilling its
+            /// local queue.
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler,
+            /// and only ever increases when the runtime's overflow policy is
+            /// set to reject rather than spill. With the default policy this
+            /// always returns `0`.
+            ///
+            /// The counter starts at zero when the runtime is created and
+            /// increases by one each time the worker's local queue is full
+            /// and a newly scheduled task is sent directly to the injection
+            /// queue rather than displacing any of the local queue's
+            /// contents. This is a backpressure signal: a rising count means
+            /// the worker is scheduling tasks locally faster than it can run
+            /// them.
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_overflow_reject_count(0);
+            ///     println!("worker 0 has rejected {} tasks to the injection queue", n);
+            /// }
+            /// ```
+            pub fn worker_overflow_reject_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .overflow_reject_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of times the given worker thread was woken
+            /// up but found no task waiting for it.
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler.
+            /// A worker is woken when another worker or a new task notifies
+            /// it, selecting it as the target via the idle worker selection
+            /// policy. If, by the time it wakes, some other worker has
+            /// already picked up the work it was notified about, the
+            /// notification was wasted: the worker finds nothing in its
+            /// local queue or LIFO slot and has to go searching for work to
+            /// steal instead.
+            ///
+            /// The counter starts at zero when the runtime is created and
+            /// increases by one each time this happens. A rising count
+            /// relative to [`worker_park_count`] indicates that worker
+            /// wakeups are not finding the notified worker any work, which
+            /// is useful when evaluating changes to how idle workers are
+            /// selected for notification.
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// [`worker_park_count`]: RuntimeMetrics::worker_park_count
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_notify_no_work_count(0);
+            ///     println!("worker 0 woke up to no work {} times", n);
+            /// }
+            /// ```
+            pub fn worker_notify_no_work_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .notify_no_work_count
+                    .load(Relaxed)
+            }
+
+            /// Returns, for each worker, how many tasks it has overflowed into
+            /// each shard of the injection queue.
+            ///
+            /// The returned `Vec` has `num_workers()` rows, one per worker.
+            /// This runtime's injection queue is **not sharded**, so every row
+            /// currently has exactly one column: `matrix[worker][0]` is the
+            /// same count as [`worker_overflow_count`]. The matrix shape
+            /// mirrors [`steal_matrix`]'s so that call sites written against
+            /// it do not need to change if the injection queue gains sharding
+            /// in the future; until then, it is a more verbose way to spot
+            /// which worker dominates overflow into the single shared queue.
+            ///
+            /// Each entry is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// This is a **best-effort** snapshot: each entry is read
+            /// independently, so the matrix as a whole may not reflect a
+            /// single consistent instant.
+            ///
+            /// [`worker_overflow_count`]: RuntimeMetrics::worker_overflow_count
+            /// [`steal_matrix`]: RuntimeMetrics::steal_matrix
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let matrix = metrics.overflow_shard_matrix();
+            ///     println!("{:?}", matrix);
+            /// }
+            /// ```
+            pub fn overflow_shard_matrix(&self) -> Vec<Vec<u64>> {
+                (0..self.num_workers())
+                    .map(|worker| vec![self.worker_overflow_count(worker)])
+                    .collect()
+            }
+
+            /// Returns the number of times the given worker thread evicted a task
+            /// already sitting in its LIFO slot to make room for a newer one.
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler.
+            ///
+            /// The counter starts at zero when the runtime is created and increases
+            /// by one each time the worker schedules a task locally while its LIFO
+            /// slot is already occupied: the occupant is pushed onto the local run
+            /// queue (or the injection queue, if the local queue is full) to make
+            /// room for the new task. A high count relative to how often tasks are
+            /// scheduled locally means the LIFO slot is rarely holding onto the
+            /// task that ends up running next.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_lifo_eviction_count(0);
+            ///     println!("worker 0 evicted its LIFO slot {} times", n);
+            /// }
+            /// ```
+            pub fn worker_lifo_eviction_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .lifo_eviction_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of times the given worker thread pushed a task
+            /// out of its LIFO slot and onto the back of the run queue because
+            /// its coop budget was exhausted.
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler.
+            ///
+            /// The counter starts at zero when the runtime is created and increases
+            /// by one each time `run_task` finds a task in the LIFO slot but
+            /// `coop::has_budget_remaining()` returns `false`, so the task is
+            /// demoted to the run queue instead of being polled immediately. This
+            /// is distinct from [`worker_lifo_eviction_count`], which counts a
+            /// different reason a task leaves the LIFO slot (a newer task claiming
+            /// an already-occupied slot). A high count relative to
+            /// [`worker_poll_count`] suggests tasks are frequently exhausting their
+            /// budget mid-LIFO-chain, which may be worth investigating.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// [`worker_lifo_eviction_count`]: RuntimeMetrics::worker_lifo_eviction_count
+            /// [`worker_poll_count`]: RuntimeMetrics::worker_poll_count
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_lifo_budget_demotion_count(0);
+            ///     println!("worker 0 demoted a LIFO task due to budget exhaustion {} times", n);
+            /// }
+            /// ```
+            pub fn worker_lifo_budget_demotion_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .lifo_budget_demotion_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the total number of wakers the given worker thread has
+            /// drained while flushing deferred tasks (tasks that deferred
+            /// rescheduling themselves until after the driver was polled).
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler.
+            ///
+            /// A large count relative to how often the worker polls the
+            /// driver indicates a lot of tasks are yielding and getting
+            /// rescheduled through the driver rather than directly, which
+            /// has locality implications: unlike a direct reschedule onto
+            /// the local run queue, a large deferred batch can end up
+            /// spread across the run queues of whichever workers happen to
+            /// be searching for work when it is flushed.
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_deferred_wake_count(0);
+            ///     println!("worker 0 has drained {} deferred wakers", n);
+            /// }
+            /// ```
+            pub fn worker_deferred_wake_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .deferred_wake_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the most wakers the given worker thread has drained in
+            /// a single deferred-task flush, since the runtime started or the
+            /// last call to [`reset_worker_deferred_wake_high_water_mark`].
+            ///
+            /// This metric only applies to the **multi-threaded** scheduler.
+            /// See [`worker_deferred_wake_count`] for what a deferred-task
+            /// flush is. Where that counter tracks the running total, this
+            /// tracks the single largest batch, which is more directly
+            /// comparable to per-tick limits such as `max_lifo_polls` when
+            /// gauging how much locality is lost to a single flush.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// [`worker_deferred_wake_count`]: RuntimeMetrics::worker_deferred_wake_count
+            /// [`reset_worker_deferred_wake_high_water_mark`]: RuntimeMetrics::reset_worker_deferred_wake_high_water_mark
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_deferred_wake_high_water_mark(0);
+            ///     println!("worker 0's largest deferred flush drained {} wakers", n);
+            /// }
+            /// ```
+            pub fn worker_deferred_wake_high_water_mark(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .deferred_wake_high_water_mark
+                    .load(Relaxed)
+            }
+
+            /// Resets the high-water mark returned by
+            /// [`worker_deferred_wake_high_water_mark`] back to zero.
+            ///
+            /// [`worker_deferred_wake_high_water_mark`]: RuntimeMetrics::worker_deferred_wake_high_water_mark
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            pub fn reset_worker_deferred_wake_high_water_mark(&self, worker: usize) {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .deferred_wake_high_water_mark
+                    .store(0, Relaxed);
+            }
+
+            /// Returns the number of times the given worker thread's task
+            /// self-woke, i.e. a task's waker was invoked while that same
+            /// task was the one currently being polled.
+            ///
+            /// This is only tracked when self-wake tracking has been
+            /// enabled on the `Builder` used to build this runtime, and is
+            /// always `0` otherwise. A consistently high count relative to
+            /// [`worker_poll_count`] suggests a future is busy-spinning
+            /// rather than registering a waker and returning `Pending`.
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// [`worker_poll_count`]: RuntimeMetrics::worker_poll_count
+            pub fn worker_self_wake_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .self_wake_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of tasks scheduled onto the given worker
+            /// thread as a direct result of the resource driver being
+            /// polled, i.e. while the worker held the driver rather than
+            /// while running a task.
+            ///
+            /// A worker only ever holds the driver while parking, so this
+            /// only increases for workers that actually end up polling it.
+            /// Comparing this across workers can help tell whether the
+            /// driver-owning worker is being overloaded with I/O-ready
+            /// tasks relative to its peers.
+            ///
+            /// The counter is monotonically increasing. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            pub fn worker_driver_scheduled_tasks(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .driver_scheduled_task_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of times the given worker thread's next task was
+            /// pulled from its local queue or LIFO slot.
+            ///
+            /// The counter starts at zero when the runtime is created and increases
+            /// by one each time the worker's `next_task` returns a task sourced
+            /// locally, rather than from the global (injection) queue.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_local_queue_pull_count(0);
+            ///     println!("worker 0 pulled its next task from the local queue {} times", n);
+            /// }
+            /// ```
+            pub fn worker_local_queue_pull_count(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .local_queue_pull_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of times the given worker thread's next task was
+            /// pulled from the global (injection) queue.
+            ///
+            /// The counter starts at zero when the runtime is created and increases
+            /// by one each time the worker's `next_task` returns a task sourced from
+            /// the global queue, rather than the worker's own local queue or LIFO
+            /// slot. A high ratio relative to
+            /// [`worker_local_queue_pull_count`](Self::worker_local_queue_pull_count)
+            /// suggests work isn't staying local to the worker that scheduled it,
+            /// which reduces the effectiveness of the LIFO slot.
+            ///
+            /// The counter is monotonically increasing. It is never decremented or
+            /// reset to zero.
+            ///
+            /// # Arguments
+            ///
             /// `worker` is the index of the worker being queried. The given value must
             /// be between 0 and `num_workers()`. The index uniquely identifies a single
             /// worker and will continue to identify the worker throughout the lifetime
@@ -668,17 +2137,139 @@ impl RuntimeMetrics {
             /// async fn main() {
             ///     let metrics = Handle::current().metrics();
             ///
-            ///     let n = metrics.worker_overflow_count(0);
-            ///     println!("worker 0 has overflowed its queue {} times", n);
+            ///     let n = metrics.worker_global_queue_pull_count(0);
+            ///     println!("worker 0 pulled its next task from the global queue {} times", n);
             /// }
             /// ```
-            pub fn worker_overflow_count(&self, worker: usize) -> u64 {
+            pub fn worker_global_queue_pull_count(&self, worker: usize) -> u64 {
                 self.handle
                     .inner
                     .worker_metrics(worker)
-                    .overflow_count
+                    .global_queue_pull_count
+                    .load(Relaxed)
+            }
+
+            /// Returns the number of consecutive scheduler loop passes in which
+            /// the given worker has found no work to run.
+            ///
+            /// This is reset to zero each time the worker runs a task, and
+            /// increases by one each time the worker fails to steal work from
+            /// another worker or the global queue. Unlike the other worker
+            /// metrics, this is a snapshot of current state rather than a
+            /// monotonically increasing counter: it can go back down. A worker
+            /// with a large value here across many consecutive samples is a
+            /// good candidate for being considered idle for the purposes of an
+            /// elastic worker pool.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let n = metrics.worker_consecutive_idle(0);
+            ///     println!("worker 0 has found no work for {} consecutive cycles", n);
+            /// }
+            /// ```
+            pub fn worker_consecutive_idle(&self, worker: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .consecutive_idle
                     .load(Relaxed)
             }
+
+            /// Returns the current `global_queue_interval` of every worker, i.e.
+            /// how many tasks each worker polls from its local queue before
+            /// checking the injection queue.
+            ///
+            /// Unless [`Builder::global_queue_interval`] is set explicitly, each
+            /// worker self-tunes this value independently based on its own
+            /// observed task poll times, so it can vary from worker to worker.
+            /// Wide divergence across the returned values suggests workers are
+            /// seeing very different task-duration profiles, which can help
+            /// explain uneven responsiveness to the injection queue.
+            ///
+            /// The returned `Vec` has `num_workers()` entries, indexed the same
+            /// way as the `worker` argument accepted by other worker metrics.
+            ///
+            /// This is a **best-effort** snapshot: each entry is read
+            /// independently, so the list as a whole may not reflect a single
+            /// consistent instant.
+            ///
+            /// [`Builder::global_queue_interval`]: crate::runtime::Builder::global_queue_interval
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let intervals = metrics.worker_global_queue_intervals();
+            ///     println!("{:?}", intervals);
+            /// }
+            /// ```
+            pub fn worker_global_queue_intervals(&self) -> Vec<u32> {
+                self.handle.inner.worker_global_queue_intervals()
+            }
+
+            /// Returns the human-readable label of the given worker.
+            ///
+            /// Labels are purely diagnostic: they have no effect on
+            /// scheduling. By default a worker's label is `"worker-{index}"`,
+            /// but a custom label can be assigned via
+            /// [`Builder::worker_labels`], which is useful for tagging
+            /// workers by role (e.g. "io", "cpu") in exported metrics so
+            /// dashboards don't have to work backwards from a bare index.
+            ///
+            /// [`Builder::worker_labels`]: crate::runtime::Builder::worker_labels
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::Handle;
+            ///
+            /// #[tokio::main]
+            /// async fn main() {
+            ///     let metrics = Handle::current().metrics();
+            ///
+            ///     let label = metrics.worker_label(0);
+            ///     println!("worker 0 is labeled {:?}", label);
+            /// }
+            /// ```
+            pub fn worker_label(&self, worker: usize) -> &str {
+                self.handle.inner.worker_label(worker)
+            }
         }
 
         /// Returns the number of tasks currently scheduled in the given worker's
@@ -719,6 +2310,134 @@ impl RuntimeMetrics {
             self.handle.inner.worker_local_queue_depth(worker)
         }
 
+        /// Returns the number of additional tasks that can be pushed into
+        /// the given worker's local queue before it would overflow to the
+        /// injection queue.
+        ///
+        /// This is a best-effort snapshot taken the last time the worker
+        /// submitted its metrics, not a live read, so the returned value may
+        /// be stale by the time it's observed. A value that stays near zero
+        /// on a worker that keeps pulling batches from the injection queue
+        /// (see [`worker_global_queue_pull_count`]) suggests its local queue
+        /// capacity (`Config::local_queue_capacity`) is a bottleneck forcing
+        /// tasks to overflow. Combine with [`worker_local_queue_depth`] to
+        /// see how full the queue currently is versus how much headroom it
+        /// had at last submission.
+        ///
+        /// This metric only applies to the **multi-threaded** runtime and
+        /// always returns `usize::MAX` when using the current thread
+        /// runtime, whose local queue is unbounded.
+        ///
+        /// # Arguments
+        ///
+        /// `worker` is the index of the worker being queried. The given value must
+        /// be between 0 and `num_workers()`. The index uniquely identifies a single
+        /// worker and will continue to identify the worker throughout the lifetime
+        /// of the runtime instance.
+        ///
+        /// # Panics
+        ///
+        /// The method panics when `worker` represents an invalid worker, i.e. is
+        /// greater than or equal to `num_workers()`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let n = metrics.worker_run_queue_remaining(0);
+        ///     println!("{} more tasks can be pushed into worker 0's local queue", n);
+        /// }
+        /// ```
+        ///
+        /// [`worker_global_queue_pull_count`]: Self::worker_global_queue_pull_count
+        /// [`worker_local_queue_depth`]: Self::worker_local_queue_depth
+        pub fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+            self.handle.inner.worker_run_queue_remaining(worker)
+        }
+
+        /// Returns the current status of the given worker: whether it is
+        /// running a task, searching for work to steal, or parked.
+        ///
+        /// This metric only applies to the **multi-threaded** runtime and
+        /// will always return [`WorkerStatus::Parked`] when using the
+        /// current thread runtime.
+        ///
+        /// This metric returns the **current** status of the worker. As
+        /// such, the returned value may change between two consecutive
+        /// calls.
+        ///
+        /// # Arguments
+        ///
+        /// `worker` is the index of the worker being queried. The given value must
+        /// be between 0 and `num_workers()`. The index uniquely identifies a single
+        /// worker and will continue to identify the worker throughout the lifetime
+        /// of the runtime instance.
+        ///
+        /// # Panics
+        ///
+        /// The method panics when `worker` represents an invalid worker, i.e. is
+        /// greater than or equal to `num_workers()`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let status = metrics.worker_status(0);
+        ///     println!("worker 0 is currently {:?}", status);
+        /// }
+        /// ```
+        pub fn worker_status(&self, worker: usize) -> crate::runtime::WorkerStatus {
+            self.handle.inner.worker_metrics(worker).worker_status()
+        }
+
+        /// Returns whether the given worker's LIFO slot currently holds a task.
+        ///
+        /// This metric only applies to the **multi-threaded** runtime and
+        /// always returns `false` when using the current thread runtime.
+        ///
+        /// This metric reflects the worker's LIFO slot as of its last
+        /// maintenance cycle rather than every individual slot mutation, so
+        /// it may be briefly stale.
+        ///
+        /// # Arguments
+        ///
+        /// `worker` is the index of the worker being queried. The given value must
+        /// be between 0 and `num_workers()`. The index uniquely identifies a single
+        /// worker and will continue to identify the worker throughout the lifetime
+        /// of the runtime instance.
+        ///
+        /// # Panics
+        ///
+        /// The method panics when `worker` represents an invalid worker, i.e. is
+        /// greater than or equal to `num_workers()`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::Handle;
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        ///     let metrics = Handle::current().metrics();
+        ///
+        ///     let occupied = metrics.worker_lifo_slot_occupied(0);
+        ///     println!("worker 0's LIFO slot is occupied: {}", occupied);
+        /// }
+        /// ```
+        pub fn worker_lifo_slot_occupied(&self, worker: usize) -> bool {
+            self.handle.inner.worker_metrics(worker).lifo_slot_occupied()
+        }
+
         /// Returns `true` if the runtime is tracking the distribution of task poll
         /// times.
         ///
@@ -844,7 +2563,105 @@ impl RuntimeMetrics {
                 .unwrap_or_default()
         }
 
+        /// Returns the number of histogram buckets tracking the distribution
+        /// of `run_task`'s LIFO polling-chain lengths.
+        ///
+        /// Bucket `i` (for `i` less than the last bucket) counts chains of
+        /// exactly `i` tasks polled in a row from a worker's LIFO slot; the
+        /// last bucket also catches every chain at or above the scheduler's
+        /// internal cap. Unlike the task poll time histogram, this is always
+        /// tracked and requires no configuration. Only tracked by the
+        /// multi-threaded runtime.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use tokio::runtime::{self, Handle};
+        ///
+        /// fn main() {
+        ///     runtime::Builder::new_multi_thread()
+        ///         .build()
+        ///         .unwrap()
+        ///         .block_on(async {
+        ///             let metrics = Handle::current().metrics();
+        ///             let buckets = metrics.lifo_chain_length_histogram_num_buckets();
+        ///
+        ///             println!("LIFO chain length histogram buckets: {:?}", buckets);
+        ///         });
+        /// }
+        /// ```
+        pub fn lifo_chain_length_histogram_num_buckets(&self) -> usize {
+            self.handle
+                .inner
+                .worker_metrics(0)
+                .lifo_chain_length_histogram
+                .as_ref()
+                .map(|histogram| histogram.num_buckets())
+                .unwrap_or_default()
+        }
+
         cfg_64bit_metrics! {
+            /// Returns the number of times the given worker observed a LIFO
+            /// polling chain of the given bucket's length.
+            ///
+            /// Each worker maintains its own histogram and the counts for each
+            /// bucket start at zero when the runtime is created. Each time
+            /// `run_task` finishes its LIFO polling loop, it increments the
+            /// bucket matching how many tasks in a row it polled from the LIFO
+            /// slot (`0` if it never entered the loop).
+            ///
+            /// Each bucket is a monotonically increasing counter. It is never
+            /// decremented or reset to zero.
+            ///
+            /// # Arguments
+            ///
+            /// `worker` is the index of the worker being queried. The given value must
+            /// be between 0 and `num_workers()`. The index uniquely identifies a single
+            /// worker and will continue to identify the worker throughout the lifetime
+            /// of the runtime instance.
+            ///
+            /// `bucket` is the index of the bucket being queried, scoped to the
+            /// worker. Every worker maintains identical bucket ranges.
+            ///
+            /// # Panics
+            ///
+            /// The method panics when `worker` represents an invalid worker, i.e. is
+            /// greater than or equal to `num_workers()` or if `bucket` represents an
+            /// invalid bucket.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use tokio::runtime::{self, Handle};
+            ///
+            /// fn main() {
+            ///     runtime::Builder::new_multi_thread()
+            ///         .build()
+            ///         .unwrap()
+            ///         .block_on(async {
+            ///             let metrics = Handle::current().metrics();
+            ///             let buckets = metrics.lifo_chain_length_histogram_num_buckets();
+            ///
+            ///             for worker in 0..metrics.num_workers() {
+            ///                 for i in 0..buckets {
+            ///                     let count = metrics.lifo_chain_length_histogram_bucket_count(worker, i);
+            ///                     println!("LIFO chain length {} count: {}", i, count);
+            ///                 }
+            ///             }
+            ///         });
+            /// }
+            /// ```
+            #[track_caller]
+            pub fn lifo_chain_length_histogram_bucket_count(&self, worker: usize, bucket: usize) -> u64 {
+                self.handle
+                    .inner
+                    .worker_metrics(worker)
+                    .lifo_chain_length_histogram
+                    .as_ref()
+                    .map(|histogram| histogram.get(bucket))
+                    .unwrap_or_default()
+            }
+
             /// Returns the number of times the given worker polled tasks with a poll
             /// duration within the given bucket's range.
             ///
@@ -1,5 +1,5 @@
 use crate::loom::sync::atomic::Ordering::Relaxed;
-use crate::util::metric_atomics::MetricAtomicU64;
+use crate::util::metric_atomics::{MetricAtomicU64, MetricAtomicUsize};
 
 /// Retrieves metrics from the Tokio runtime.
 ///
@@ -13,13 +13,91 @@ pub(crate) struct SchedulerMetrics {
     /// Number of tasks that are scheduled from outside the runtime.
     pub(super) remote_schedule_count: MetricAtomicU64,
     pub(super) budget_forced_yield_count: MetricAtomicU64,
+
+    /// Number of worker threads currently executing `run()`. This includes
+    /// the configured worker threads as well as any threads temporarily
+    /// running a worker's core after it was handed off by `block_in_place`.
+    pub(super) live_worker_thread_count: MetricAtomicUsize,
+
+    /// Number of `block_in_place` calls that are currently holding a core
+    /// handed off from their original worker thread. Incremented when the
+    /// handoff happens and decremented once the original thread reacquires
+    /// (or gives up trying to reacquire) its core.
+    pub(super) outstanding_block_in_place_count: MetricAtomicUsize,
+
+    /// Exponentially-weighted moving average, in nanoseconds, of how long a
+    /// task spent in the injection queue before a worker popped it. Only
+    /// ever written while holding the scheduler's inject-queue lock, so
+    /// updates never race each other; the atomic is here purely so the
+    /// average can be read from any thread.
+    #[cfg(tokio_unstable)]
+    pub(super) mean_inject_queue_wait_ns: MetricAtomicU64,
+
+    /// Aggregate time, in nanoseconds, spent waiting to acquire the
+    /// scheduler's `synced` mutex, which guards cores, idle state, the
+    /// injection queue, and the driver handoff. Only a fraction of
+    /// acquisitions are timed (see `synced_lock_sample_counter`), with each
+    /// sample scaled up by the sample rate so the total remains an unbiased
+    /// estimate of the true aggregate.
+    #[cfg(tokio_unstable)]
+    pub(super) synced_lock_contention_ns: MetricAtomicU64,
+
+    /// Counts every call to the sampled `synced`-lock helper, so that only
+    /// one in `SYNCED_LOCK_SAMPLE_RATE` acquisitions pays for a pair of
+    /// `Instant::now()` calls.
+    #[cfg(tokio_unstable)]
+    pub(super) synced_lock_sample_counter: MetricAtomicUsize,
+
+    /// Elapsed time, in nanoseconds, that the most recent shutdown's
+    /// single-threaded final phase spent draining workers' local run
+    /// queues. Stays `0` until a shutdown reaches that phase.
+    #[cfg(tokio_unstable)]
+    pub(super) shutdown_task_drain_ns: MetricAtomicU64,
+
+    /// Elapsed time, in nanoseconds, that the same phase spent shutting
+    /// down the resource driver. Timed on whichever thread runs it, which
+    /// is a dedicated thread rather than the phase's own thread when
+    /// `Builder::offload_driver_shutdown_to_dedicated_thread` is set.
+    #[cfg(tokio_unstable)]
+    pub(super) shutdown_driver_ns: MetricAtomicU64,
+
+    /// Elapsed time, in nanoseconds, that the same phase spent draining the
+    /// injection queue.
+    #[cfg(tokio_unstable)]
+    pub(super) shutdown_inject_drain_ns: MetricAtomicU64,
 }
 
+/// Weight given to a fresh sample when folding it into
+/// `mean_inject_queue_wait_ns`. Matches the smoothing used for
+/// `Stats::task_poll_time_ewma`.
+#[cfg(tokio_unstable)]
+const INJECT_QUEUE_WAIT_EWMA_ALPHA: f64 = 0.1;
+
+/// One in this many `synced`-lock acquisitions is timed to feed
+/// `synced_lock_contention_ns`. Sampling keeps the overhead of the metric
+/// off the vast majority of lock acquisitions.
+#[cfg(tokio_unstable)]
+const SYNCED_LOCK_SAMPLE_RATE: usize = 32;
+
 impl SchedulerMetrics {
     pub(crate) fn new() -> SchedulerMetrics {
         SchedulerMetrics {
             remote_schedule_count: MetricAtomicU64::new(0),
             budget_forced_yield_count: MetricAtomicU64::new(0),
+            live_worker_thread_count: MetricAtomicUsize::new(0),
+            outstanding_block_in_place_count: MetricAtomicUsize::new(0),
+            #[cfg(tokio_unstable)]
+            mean_inject_queue_wait_ns: MetricAtomicU64::new(0),
+            #[cfg(tokio_unstable)]
+            synced_lock_contention_ns: MetricAtomicU64::new(0),
+            #[cfg(tokio_unstable)]
+            synced_lock_sample_counter: MetricAtomicUsize::new(0),
+            #[cfg(tokio_unstable)]
+            shutdown_task_drain_ns: MetricAtomicU64::new(0),
+            #[cfg(tokio_unstable)]
+            shutdown_driver_ns: MetricAtomicU64::new(0),
+            #[cfg(tokio_unstable)]
+            shutdown_inject_drain_ns: MetricAtomicU64::new(0),
         }
     }
 
@@ -32,4 +110,146 @@ impl SchedulerMetrics {
     pub(crate) fn inc_budget_forced_yield_count(&self) {
         self.budget_forced_yield_count.add(1, Relaxed);
     }
+
+    /// Increment the number of worker threads currently executing `run()`.
+    pub(crate) fn inc_live_worker_thread_count(&self) {
+        self.live_worker_thread_count.increment();
+    }
+
+    /// Decrement the number of worker threads currently executing `run()`.
+    pub(crate) fn dec_live_worker_thread_count(&self) {
+        self.live_worker_thread_count.decrement();
+    }
+
+    /// Resets the accumulated counters to zero.
+    ///
+    /// `live_worker_thread_count` and `outstanding_block_in_place_count` are
+    /// left untouched, as they reflect current state rather than
+    /// accumulated history, as are the `shutdown_*` durations, which each
+    /// record a single phase of the most recent shutdown.
+    pub(crate) fn reset(&self) {
+        self.remote_schedule_count.store(0, Relaxed);
+        self.budget_forced_yield_count.store(0, Relaxed);
+        #[cfg(tokio_unstable)]
+        {
+            self.mean_inject_queue_wait_ns.store(0, Relaxed);
+            self.synced_lock_contention_ns.store(0, Relaxed);
+        }
+    }
+
+    /// Returns the number of worker threads currently executing `run()`.
+    pub(crate) fn live_worker_thread_count(&self) -> usize {
+        self.live_worker_thread_count.load(Relaxed)
+    }
+
+    /// Increment the number of outstanding `block_in_place` core handoffs.
+    pub(crate) fn inc_outstanding_block_in_place_count(&self) {
+        self.outstanding_block_in_place_count.increment();
+    }
+
+    /// Decrement the number of outstanding `block_in_place` core handoffs.
+    pub(crate) fn dec_outstanding_block_in_place_count(&self) {
+        self.outstanding_block_in_place_count.decrement();
+    }
+
+    /// Returns the number of outstanding `block_in_place` core handoffs.
+    pub(crate) fn outstanding_block_in_place_count(&self) -> usize {
+        self.outstanding_block_in_place_count.load(Relaxed)
+    }
+
+    /// Folds a freshly observed inject-queue wait time into the moving
+    /// average.
+    ///
+    /// Must only be called while holding the scheduler's inject-queue lock,
+    /// so that concurrent updates are serialized by the caller.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn record_inject_queue_wait(&self, wait: std::time::Duration) {
+        let sample = wait.as_nanos() as f64;
+        let prev = self.mean_inject_queue_wait_ns.load(Relaxed) as f64;
+
+        // Nothing to blend the first sample with; take it as-is rather than
+        // pulling it toward zero.
+        let next = if prev == 0.0 {
+            sample
+        } else {
+            INJECT_QUEUE_WAIT_EWMA_ALPHA * sample + (1.0 - INJECT_QUEUE_WAIT_EWMA_ALPHA) * prev
+        };
+
+        self.mean_inject_queue_wait_ns.store(next as u64, Relaxed);
+    }
+
+    /// Returns the moving average of how long a task waits in the injection
+    /// queue before being popped by a worker.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn mean_inject_queue_wait(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.mean_inject_queue_wait_ns.load(Relaxed))
+    }
+
+    /// Returns `true` for the caller that should time this acquisition of
+    /// the `synced` mutex, roughly one in every `SYNCED_LOCK_SAMPLE_RATE`
+    /// calls.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn sample_synced_lock_contention(&self) -> bool {
+        self.synced_lock_sample_counter.increment() % SYNCED_LOCK_SAMPLE_RATE == 0
+    }
+
+    /// Folds a sampled `synced`-lock wait time into the aggregate, scaling
+    /// it up by the sample rate to keep the total an unbiased estimate of
+    /// the time every acquisition (not just the sampled ones) spent
+    /// waiting.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn record_synced_lock_contention(&self, wait: std::time::Duration) {
+        let scaled = wait.as_nanos() as u64 * SYNCED_LOCK_SAMPLE_RATE as u64;
+        self.synced_lock_contention_ns.add(scaled, Relaxed);
+    }
+
+    /// Returns the aggregate estimated time spent waiting to acquire the
+    /// scheduler's `synced` mutex.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn synced_lock_contention_time(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.synced_lock_contention_ns.load(Relaxed))
+    }
+
+    /// Records how long the most recent shutdown's single-threaded final
+    /// phase spent draining workers' local run queues.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn set_shutdown_task_drain_duration(&self, elapsed: std::time::Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.shutdown_task_drain_ns.store(nanos, Relaxed);
+    }
+
+    /// Returns how long the most recent shutdown's single-threaded final
+    /// phase spent draining workers' local run queues.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn shutdown_task_drain_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.shutdown_task_drain_ns.load(Relaxed))
+    }
+
+    /// Records how long the same phase spent shutting down the resource
+    /// driver.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn set_shutdown_driver_duration(&self, elapsed: std::time::Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.shutdown_driver_ns.store(nanos, Relaxed);
+    }
+
+    /// Returns how long the same phase spent shutting down the resource
+    /// driver.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn shutdown_driver_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.shutdown_driver_ns.load(Relaxed))
+    }
+
+    /// Records how long the same phase spent draining the injection queue.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn set_shutdown_inject_drain_duration(&self, elapsed: std::time::Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.shutdown_inject_drain_ns.store(nanos, Relaxed);
+    }
+
+    /// Returns how long the same phase spent draining the injection queue.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn shutdown_inject_drain_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.shutdown_inject_drain_ns.load(Relaxed))
+    }
 }
@@ -0,0 +1,113 @@
+use crate::runtime::metrics::{RuntimeMetrics, WorkerStatus};
+
+/// A point-in-time snapshot of the full scheduler state, gathered by
+/// [`Handle::scheduler_dump`].
+///
+/// Every field is captured by reading the same lock-free counters
+/// [`RuntimeMetrics`] exposes individually, so, like [`steal_matrix`], this
+/// is a **best-effort** snapshot: each field is read independently and the
+/// dump as a whole may not reflect a single consistent instant.
+///
+/// [`Handle::scheduler_dump`]: crate::runtime::Handle::scheduler_dump
+/// [`steal_matrix`]: RuntimeMetrics::steal_matrix
+#[derive(Debug, Clone)]
+pub struct SchedulerDump {
+    workers: Vec<WorkerDump>,
+    injection_queue_depth: usize,
+    #[cfg(target_has_atomic = "64")]
+    steal_matrix: Vec<Vec<u64>>,
+}
+
+/// A point-in-time snapshot of a single worker's state, as part of a
+/// [`SchedulerDump`].
+#[derive(Debug, Clone)]
+pub struct WorkerDump {
+    index: usize,
+    status: WorkerStatus,
+    local_queue_depth: usize,
+    lifo_slot_occupied: bool,
+    global_queue_interval: u32,
+}
+
+impl SchedulerDump {
+    pub(crate) fn capture(metrics: &RuntimeMetrics) -> SchedulerDump {
+        let global_queue_intervals = metrics.worker_global_queue_intervals();
+
+        let workers = (0..metrics.num_workers())
+            .map(|index| WorkerDump {
+                index,
+                status: metrics.worker_status(index),
+                local_queue_depth: metrics.worker_local_queue_depth(index),
+                lifo_slot_occupied: metrics.worker_lifo_slot_occupied(index),
+                global_queue_interval: global_queue_intervals[index],
+            })
+            .collect();
+
+        SchedulerDump {
+            workers,
+            injection_queue_depth: metrics.injection_queue_depth(),
+            #[cfg(target_has_atomic = "64")]
+            steal_matrix: metrics.steal_matrix(),
+        }
+    }
+
+    /// Per-worker snapshots, ordered by worker index.
+    pub fn workers(&self) -> &[WorkerDump] {
+        &self.workers
+    }
+
+    /// The number of tasks currently sitting in the injection (global) queue.
+    pub fn injection_queue_depth(&self) -> usize {
+        self.injection_queue_depth
+    }
+
+    /// The number of workers currently searching for work to steal.
+    pub fn num_searching(&self) -> usize {
+        self.count_workers_with_status(WorkerStatus::Searching)
+    }
+
+    /// The number of workers currently parked.
+    pub fn num_parked(&self) -> usize {
+        self.count_workers_with_status(WorkerStatus::Parked)
+    }
+
+    fn count_workers_with_status(&self, status: WorkerStatus) -> usize {
+        self.workers.iter().filter(|w| w.status == status).count()
+    }
+
+    /// A matrix of steal counts between each pair of workers. See
+    /// [`RuntimeMetrics::steal_matrix`].
+    #[cfg(target_has_atomic = "64")]
+    pub fn steal_matrix(&self) -> &[Vec<u64>] {
+        &self.steal_matrix
+    }
+}
+
+impl WorkerDump {
+    /// This worker's index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Whether this worker is running a task, searching for work to steal,
+    /// or parked.
+    pub fn status(&self) -> WorkerStatus {
+        self.status
+    }
+
+    /// The number of tasks currently in this worker's local queue.
+    pub fn local_queue_depth(&self) -> usize {
+        self.local_queue_depth
+    }
+
+    /// Whether this worker's LIFO slot currently holds a task.
+    pub fn lifo_slot_occupied(&self) -> bool {
+        self.lifo_slot_occupied
+    }
+
+    /// This worker's current global queue interval: how many tasks it polls
+    /// from its local queue before checking the injection queue.
+    pub fn global_queue_interval(&self) -> u32 {
+        self.global_queue_interval
+    }
+}
@@ -1,9 +1,83 @@
-use crate::runtime::metrics::Histogram;
+use crate::runtime::metrics::{Histogram, HistogramBuilder, HistogramScale};
 use crate::runtime::Config;
 use crate::util::metric_atomics::{MetricAtomicU64, MetricAtomicUsize};
 use std::sync::atomic::Ordering::Relaxed;
-use std::sync::Mutex;
+use std::sync::{Mutex, OnceLock};
 use std::thread::ThreadId;
+use std::time::{Duration, Instant};
+
+/// A fixed point in time used to encode `Instant`s as nanosecond offsets
+/// that fit in an atomic. Any single fixed `Instant` works as the epoch;
+/// this one is simply whichever one gets requested first.
+fn metrics_epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+/// Number of buckets in `lifo_chain_length_histogram`: one per chain length
+/// from `0` (no LIFO task polled this tick) up to `3`, the default
+/// `Config::max_lifo_polls` cap of both multi-threaded schedulers'
+/// `run_task` loops. Longer chains, possible with a higher cap, are counted
+/// in the last bucket.
+const LIFO_CHAIN_LENGTH_BUCKETS: usize = 4;
+
+cfg_unstable! {
+    /// The current status of a runtime worker: whether it is running a
+    /// task, searching for work to steal, or parked waiting for new work.
+    ///
+    /// See [`RuntimeMetrics::worker_status`][worker_status].
+    ///
+    /// [worker_status]: crate::runtime::RuntimeMetrics::worker_status
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    #[non_exhaustive]
+    pub enum WorkerStatus {
+        /// The worker is currently polling a task.
+        Running,
+
+        /// The worker has no more local work and is searching for tasks to
+        /// steal from other workers.
+        Searching,
+
+        /// The worker is parked, waiting to be woken up by new work.
+        Parked,
+    }
+
+    /// A worker's metrics accumulated since its previous submit, handed to
+    /// [`Builder::metrics_sink`] each time a worker submits.
+    ///
+    /// Unlike [`RuntimeMetrics`], which reports cumulative totals since the
+    /// runtime started (or since the last [`RuntimeMetrics::reset_metrics`]),
+    /// these are deltas: the amount each counter grew over exactly the
+    /// interval between this submit and the previous one.
+    ///
+    /// [`Builder::metrics_sink`]: crate::runtime::Builder::metrics_sink
+    /// [`RuntimeMetrics`]: crate::runtime::RuntimeMetrics
+    /// [`RuntimeMetrics::reset_metrics`]: crate::runtime::RuntimeMetrics::reset_metrics
+    #[derive(Debug, Clone, Copy, Default)]
+    #[non_exhaustive]
+    pub struct WorkerMetricsDelta {
+        /// Tasks polled since the previous submit.
+        pub poll_count: u64,
+
+        /// Tasks scheduled onto this worker's local queue since the previous
+        /// submit.
+        pub local_schedule_count: u64,
+
+        /// Tasks moved from the local queue to the global queue to free
+        /// space since the previous submit.
+        pub overflow_count: u64,
+
+        /// Tasks this worker drove to completion since the previous submit.
+        pub completed_tasks: u64,
+
+        /// Number of times the worker parked since the previous submit.
+        pub park_count: u64,
+
+        /// Time spent busy (i.e. between resuming from park and parking
+        /// again) since the previous submit.
+        pub busy_duration: Duration,
+    }
+}
 
 /// Retrieve runtime worker metrics.
 ///
@@ -30,28 +104,193 @@ pub(crate) struct WorkerMetrics {
     /// Number of times the worker stole
     pub(crate) steal_operations: MetricAtomicU64,
 
+    /// Number of times a steal succeeded against the very first victim
+    /// examined, without having to scan past any empty/shallow candidates
+    /// first. Compare against `steal_operations` to gauge how often the
+    /// worker's starting victim (see `Config::steal_order`) pays off.
+    pub(crate) steal_first_try_success_count: MetricAtomicU64,
+
+    /// Number of times a batch of stolen tasks was pushed back to the
+    /// injection queue because the first task run from it blocked on its
+    /// first poll. Only incremented when `Config::steal_back` is enabled.
+    pub(crate) steal_back_count: MetricAtomicU64,
+
+    /// Number of times `steal_work` found no task to steal from any peer
+    /// worker and fell back to the global (injection) queue, where it did
+    /// find a task. Compare against `steal_operations` to gauge how often
+    /// peer queues are actually helping vs. balancing happening through the
+    /// global queue. Only incremented by the multi-threaded scheduler.
+    pub(crate) steal_global_fallback_count: MetricAtomicU64,
+
+    /// Number of times the worker wanted to search for work to steal but was
+    /// denied by the idle subsystem's half-searcher guard (more than half
+    /// the workers were already searching) and parked without stealing.
+    /// Frequent denials mean workers are parking with available peer work
+    /// they weren't allowed to steal.
+    pub(crate) steal_search_denied_count: MetricAtomicU64,
+
+    /// Number of times the worker acquired a core after fully parking (i.e.
+    /// after it had released its core and gone to sleep on a condition
+    /// variable). Only incremented by the `multi_thread_alt` scheduler.
+    pub(crate) core_acquisitions_count: MetricAtomicU64,
+
     /// Number of tasks the worker polled.
     pub(crate) poll_count: MetricAtomicU64,
 
+    /// Wall-clock time this worker last entered `run_task`, encoded as
+    /// nanoseconds past [`metrics_epoch`]. Updated continuously (not just
+    /// when metrics are submitted) so a watchdog can compare it against the
+    /// current time to detect a worker stuck inside a long-running,
+    /// non-yielding poll. Zero until the worker's first task poll.
+    pub(crate) last_poll_start: MetricAtomicU64,
+
+    /// Number of individual polls that completed the task. Only incremented
+    /// by the multi-threaded scheduler.
+    pub(crate) completed_poll_count: MetricAtomicU64,
+
+    /// Number of individual polls that left the task pending. Only
+    /// incremented by the multi-threaded scheduler.
+    pub(crate) pending_poll_count: MetricAtomicU64,
+
+    /// Number of tasks this worker has driven to completion. Unlike
+    /// `completed_poll_count`, this is tracked by every scheduler flavor, so
+    /// it can be combined with wall-clock time for a tasks-per-second
+    /// throughput graph regardless of which scheduler is in use.
+    pub(crate) completed_tasks: MetricAtomicU64,
+
     /// EWMA task poll time, in nanoseconds.
     pub(crate) mean_poll_time: MetricAtomicU64,
 
     /// Amount of time the worker spent doing work vs. parking.
     pub(crate) busy_duration_total: MetricAtomicU64,
 
+    /// The worker thread's total CPU time, sampled via
+    /// `clock_gettime(CLOCK_THREAD_CPUTIME_ID)` each time metrics are
+    /// submitted. Stays `0` on platforms where that clock isn't available.
+    /// Unlike `busy_duration_total`, this isn't accumulated by us: the OS
+    /// already reports it as a running total for the thread's lifetime, so
+    /// each sample simply overwrites the last.
+    pub(crate) cpu_time_total: MetricAtomicU64,
+
     /// Number of tasks scheduled for execution on the worker's local queue.
     pub(crate) local_schedule_count: MetricAtomicU64,
 
     /// Number of tasks moved from the local queue to the global queue to free space.
     pub(crate) overflow_count: MetricAtomicU64,
 
+    /// Number of times a task was pushed straight to the global queue instead
+    /// of the local queue because `Config::overflow_policy` is
+    /// `OverflowPolicy::Reject` and the local queue was full. Only
+    /// incremented by the multi-threaded scheduler.
+    pub(crate) overflow_reject_count: MetricAtomicU64,
+
+    /// Number of times the worker was woken (not by a park timeout) and, on
+    /// waking, found no task waiting for it in its local queue or LIFO slot.
+    /// This means the notification that woke it up was wasted, since another
+    /// worker had already claimed the work it was woken for. Only
+    /// incremented by the multi-threaded scheduler.
+    pub(crate) notify_no_work_count: MetricAtomicU64,
+
+    /// Number of times a task already sitting in the LIFO slot was evicted
+    /// to the run queue by a newer task claiming the slot. Only incremented
+    /// by the multi-threaded scheduler.
+    pub(crate) lifo_eviction_count: MetricAtomicU64,
+
+    /// Number of times a task in the LIFO slot was pushed to the back of the
+    /// run queue instead of being polled immediately because the worker had
+    /// exhausted its coop budget. Only incremented by the multi-threaded
+    /// scheduler.
+    pub(crate) lifo_budget_demotion_count: MetricAtomicU64,
+
+    /// Number of times `next_task` returned a task pulled from the worker's
+    /// local queue or LIFO slot.
+    pub(crate) local_queue_pull_count: MetricAtomicU64,
+
+    /// Number of times `next_task` returned a task pulled from the global
+    /// (injection) queue.
+    pub(crate) global_queue_pull_count: MetricAtomicU64,
+
+    /// Number of consecutive run-loop passes the worker has found no work
+    /// (i.e. its last steal attempt came back empty), reset whenever it runs
+    /// a task. Used only by the multi-threaded scheduler.
+    pub(crate) consecutive_idle: MetricAtomicU64,
+
     /// Number of tasks currently in the local queue. Used only by the
     /// current-thread scheduler.
     pub(crate) queue_depth: MetricAtomicUsize,
 
+    /// The number of additional tasks that could be pushed into the
+    /// worker's local run queue the last time it pulled a batch of tasks
+    /// from the injection queue, before the queue would have overflowed.
+    /// A value near zero on a worker that keeps pulling inject batches
+    /// means its local queue capacity (`Config::local_queue_capacity`) is
+    /// forcing overflow. Used only by the multi-threaded schedulers, and
+    /// updated on a best-effort basis during `Stats::submit` rather than
+    /// on every push/pop.
+    pub(crate) run_queue_remaining: MetricAtomicUsize,
+
+    /// Whether the worker's LIFO slot is currently occupied. Used only by
+    /// the multi-threaded scheduler, and updated on a best-effort basis
+    /// during maintenance rather than on every LIFO slot mutation.
+    pub(crate) lifo_slot_occupied: MetricAtomicUsize,
+
+    /// Total number of wakers drained across every `Context::defer` flush
+    /// (i.e. tasks that deferred rescheduling themselves until after the
+    /// driver was polled). Only incremented by the multi-threaded
+    /// scheduler.
+    pub(crate) deferred_wake_count: MetricAtomicU64,
+
+    /// The most wakers drained in a single `Context::defer` flush, since
+    /// the last reset. Only incremented by the multi-threaded scheduler.
+    pub(crate) deferred_wake_high_water_mark: MetricAtomicU64,
+
+    /// Number of times a task's waker was invoked while that same task was
+    /// the one currently being polled on this worker, i.e. the task woke
+    /// itself instead of being woken by an external event. A high rate here
+    /// usually means a future is busy-spinning rather than registering a
+    /// waker and returning `Pending`. Only tracked when
+    /// `Config::track_self_wake_count` is enabled.
+    pub(crate) self_wake_count: MetricAtomicU64,
+
+    /// Number of tasks scheduled onto this worker as a direct result of the
+    /// resource driver being polled, i.e. while this worker held the driver
+    /// (`Context::park`/`park_timeout`) rather than while running a task.
+    /// Helps tell whether the driver-owning worker is being overloaded with
+    /// I/O-ready tasks. Only incremented by the multi-threaded scheduler.
+    pub(crate) driver_scheduled_task_count: MetricAtomicU64,
+
+    /// Elapsed time, in nanoseconds, between the scheduler being closed and
+    /// this worker's maintenance loop first observing the closed injection
+    /// queue. Stays `0` until observed. Used only by the multi-threaded
+    /// scheduler.
+    pub(crate) shutdown_observed_after: MetricAtomicU64,
+
+    /// The worker's current `global_queue_interval`: how many tasks it polls
+    /// from its local queue before checking the injection queue. Self-tuned
+    /// per worker based on observed task poll times, unless
+    /// `Config::global_queue_interval` is explicitly set. Used only by the
+    /// multi-threaded schedulers; the current-thread scheduler publishes a
+    /// fixed value here since it never self-tunes.
+    pub(crate) global_queue_interval: MetricAtomicUsize,
+
+    /// Current status of the worker: running a task, searching for work to
+    /// steal, or parked. Encoded as `0` (parked), `1` (searching), or `2`
+    /// (running) so the setters stay available even when `WorkerStatus`
+    /// itself is not (see the mock implementation used when metrics are
+    /// disabled). Used only by the multi-threaded scheduler.
+    pub(crate) status: MetricAtomicUsize,
+
     /// If `Some`, tracks the number of polls by duration range.
     pub(super) poll_count_histogram: Option<Histogram>,
 
+    /// Distribution of `run_task`'s LIFO polling-chain lengths: how many
+    /// tasks in a row a worker polled straight out of its LIFO slot before
+    /// falling back to its run queue, bucketed by length. Unlike
+    /// `poll_count_histogram`, this isn't opt-in: with only
+    /// `LIFO_CHAIN_LENGTH_BUCKETS` buckets it's cheap enough to always
+    /// track. Only tracked by the multi-threaded schedulers.
+    pub(super) lifo_chain_length_histogram: Option<Histogram>,
+
     /// Thread id of worker thread.
     thread_id: Mutex<Option<ThreadId>>,
 }
@@ -63,11 +302,25 @@ impl WorkerMetrics {
             .metrics_poll_count_histogram
             .as_ref()
             .map(|histogram_builder| histogram_builder.build());
+        // The queue starts out empty, so all of its capacity is remaining;
+        // this is overwritten once the worker submits its first stats.
+        worker_metrics.run_queue_remaining =
+            MetricAtomicUsize::new(config.local_queue_capacity);
         worker_metrics
     }
 
     pub(crate) fn new() -> WorkerMetrics {
-        WorkerMetrics::default()
+        WorkerMetrics {
+            lifo_chain_length_histogram: Some(
+                HistogramBuilder {
+                    scale: HistogramScale::Linear,
+                    resolution: 1,
+                    num_buckets: LIFO_CHAIN_LENGTH_BUCKETS,
+                }
+                .build(),
+            ),
+            ..Default::default()
+        }
     }
 
     pub(crate) fn queue_depth(&self) -> usize {
@@ -78,6 +331,143 @@ impl WorkerMetrics {
         self.queue_depth.store(len, Relaxed);
     }
 
+    pub(crate) fn run_queue_remaining(&self) -> usize {
+        self.run_queue_remaining.load(Relaxed)
+    }
+
+    pub(crate) fn set_run_queue_remaining(&self, remaining: usize) {
+        self.run_queue_remaining.store(remaining, Relaxed);
+    }
+
+    pub(crate) fn lifo_slot_occupied(&self) -> bool {
+        self.lifo_slot_occupied.load(Relaxed) != 0
+    }
+
+    pub(crate) fn set_lifo_slot_occupied(&self, occupied: bool) {
+        self.lifo_slot_occupied.store(occupied as usize, Relaxed);
+    }
+
+    /// Records that a `Context::defer` flush drained `count` wakers,
+    /// updating both the running total and the high-water mark.
+    pub(crate) fn record_deferred_wake_count(&self, count: u64) {
+        if count == 0 {
+            return;
+        }
+        self.deferred_wake_count.add(count, Relaxed);
+        self.deferred_wake_high_water_mark.fetch_max(count, Relaxed);
+    }
+
+    /// Records that a task woke itself from within its own poll.
+    pub(crate) fn incr_self_wake_count(&self) {
+        self.self_wake_count.add(1, Relaxed);
+    }
+
+    /// Records that a task was scheduled onto this worker while it held the
+    /// resource driver, i.e. as a result of the driver being polled rather
+    /// than a task's own wake.
+    pub(crate) fn incr_driver_scheduled_task_count(&self) {
+        self.driver_scheduled_task_count.add(1, Relaxed);
+    }
+
+    pub(crate) fn set_shutdown_observed_after(&self, elapsed: std::time::Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.shutdown_observed_after.store(nanos, Relaxed);
+    }
+
+    pub(crate) fn set_worker_status_running(&self) {
+        self.status.store(2, Relaxed);
+    }
+
+    pub(crate) fn set_worker_status_searching(&self) {
+        self.status.store(1, Relaxed);
+    }
+
+    pub(crate) fn set_worker_status_parked(&self) {
+        self.status.store(0, Relaxed);
+    }
+
+    cfg_unstable! {
+        pub(crate) fn worker_status(&self) -> WorkerStatus {
+            match self.status.load(Relaxed) {
+                2 => WorkerStatus::Running,
+                1 => WorkerStatus::Searching,
+                _ => WorkerStatus::Parked,
+            }
+        }
+    }
+
+    pub(crate) fn set_consecutive_idle(&self, value: u64) {
+        self.consecutive_idle.store(value, Relaxed);
+    }
+
+    pub(crate) fn global_queue_interval(&self) -> u32 {
+        self.global_queue_interval.load(Relaxed) as u32
+    }
+
+    pub(crate) fn set_global_queue_interval(&self, value: u32) {
+        self.global_queue_interval.store(value as usize, Relaxed);
+    }
+
+    /// Records that a task poll started right now.
+    pub(crate) fn record_poll_start(&self) {
+        let nanos = u64::try_from(metrics_epoch().elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.last_poll_start.store(nanos, Relaxed);
+    }
+
+    /// The wall-clock time this worker last entered `run_task`, or the time
+    /// this function was first called anywhere in the process if the worker
+    /// has not yet polled a task.
+    pub(crate) fn last_activity(&self) -> Instant {
+        let nanos = self.last_poll_start.load(Relaxed);
+        metrics_epoch() + Duration::from_nanos(nanos)
+    }
+
+    /// Resets the accumulated counters to zero.
+    ///
+    /// `queue_depth`, `run_queue_remaining`, `lifo_slot_occupied`, `status`,
+    /// `shutdown_observed_after`, `last_poll_start`, and
+    /// `global_queue_interval` are left untouched, as they reflect current
+    /// state rather than accumulated history.
+    pub(crate) fn reset(&self) {
+        self.park_count.store(0, Relaxed);
+        self.park_unpark_count.store(0, Relaxed);
+        self.noop_count.store(0, Relaxed);
+        self.steal_count.store(0, Relaxed);
+        self.steal_operations.store(0, Relaxed);
+        self.steal_first_try_success_count.store(0, Relaxed);
+        self.steal_back_count.store(0, Relaxed);
+        self.steal_global_fallback_count.store(0, Relaxed);
+        self.steal_search_denied_count.store(0, Relaxed);
+        self.core_acquisitions_count.store(0, Relaxed);
+        self.poll_count.store(0, Relaxed);
+        self.completed_poll_count.store(0, Relaxed);
+        self.pending_poll_count.store(0, Relaxed);
+        self.completed_tasks.store(0, Relaxed);
+        self.mean_poll_time.store(0, Relaxed);
+        self.busy_duration_total.store(0, Relaxed);
+        self.local_schedule_count.store(0, Relaxed);
+        self.overflow_count.store(0, Relaxed);
+        self.overflow_reject_count.store(0, Relaxed);
+        self.notify_no_work_count.store(0, Relaxed);
+        self.lifo_eviction_count.store(0, Relaxed);
+        self.lifo_budget_demotion_count.store(0, Relaxed);
+        self.local_queue_pull_count.store(0, Relaxed);
+        self.global_queue_pull_count.store(0, Relaxed);
+        self.consecutive_idle.store(0, Relaxed);
+        self.deferred_wake_count.store(0, Relaxed);
+        self.deferred_wake_high_water_mark.store(0, Relaxed);
+        self.self_wake_count.store(0, Relaxed);
+        self.driver_scheduled_task_count.store(0, Relaxed);
+
+        if let Some(histogram) = &self.poll_count_histogram {
+            histogram.reset();
+        }
+
+        if let Some(histogram) = &self.lifo_chain_length_histogram {
+            histogram.reset();
+        }
+    }
+
     pub(crate) fn thread_id(&self) -> Option<ThreadId> {
         *self.thread_id.lock().unwrap()
     }
@@ -350,6 +350,11 @@ cfg_rt! {
 
     mod config;
     use config::Config;
+    use config::DeadlockDetectorConfig;
+    use config::OverflowPolicy;
+    use config::ParkBackoffConfig;
+    use config::StartupDistribution;
+    use config::StealOrder;
 
     mod blocking;
     #[cfg_attr(target_os = "wasi", allow(unused_imports))]
@@ -371,7 +376,13 @@ cfg_rt! {
         pub use id::Id;
 
         pub use self::builder::UnhandledPanic;
+        pub use self::builder::ShutdownOrder;
+        pub use self::task::TaskPriority;
         pub use crate::util::rand::RngSeed;
+
+        pub use self::coop::current_task_budget;
+
+        pub use self::context::shutdown_requested;
     }
 
     cfg_taskdump! {
@@ -382,12 +393,16 @@ cfg_rt! {
     mod task_hooks;
     pub(crate) use task_hooks::{TaskHooks, TaskCallback};
     #[cfg(tokio_unstable)]
-    pub use task_hooks::TaskMeta;
+    pub(crate) use task_hooks::PlacementFn;
+    #[cfg(tokio_unstable)]
+    pub use task_hooks::{TaskMeta, Placement};
     #[cfg(not(tokio_unstable))]
     pub(crate) use task_hooks::TaskMeta;
 
     mod handle;
     pub use handle::{EnterGuard, Handle, TryCurrentError};
+    #[cfg(tokio_unstable)]
+    pub use handle::RuntimeConfigMut;
 
     mod runtime;
     pub use runtime::{Runtime, RuntimeFlavor};
@@ -408,6 +423,9 @@ cfg_rt! {
 
     cfg_unstable_metrics! {
         pub use metrics::HistogramScale;
+        pub use metrics::WorkerStatus;
+        pub use metrics::WorkerMetricsDelta;
+        pub use metrics::{SchedulerDump, WorkerDump};
 
         cfg_net! {
             pub(crate) use metrics::IoDriverMetrics;
@@ -418,4 +436,37 @@ cfg_rt! {
 
     /// After thread starts / before thread stops
     type Callback = std::sync::Arc<dyn Fn() + Send + Sync>;
+
+    /// Computes a worker thread's stack size, given its index. See
+    /// `Builder::worker_stack_size`.
+    type WorkerStackSizeFn = std::sync::Arc<dyn Fn(usize) -> usize + Send + Sync>;
+
+    /// Invoked with a worker's index each time its tick crosses
+    /// `event_interval`. See `Builder::on_event_interval`.
+    type EventIntervalCallback = std::sync::Arc<dyn Fn(usize) + Send + Sync>;
+
+    /// Test-only override of the RNG used to pick a work-stealing victim.
+    /// See `Builder::test_only_rand_hook`.
+    #[cfg(tokio_unstable)]
+    type StealOrderHook = std::sync::Arc<dyn Fn(u32) -> u32 + Send + Sync>;
+
+    /// Fired with a worker's index when it acquires or releases a core.
+    /// See `Builder::on_core_acquired` and `Builder::on_core_released`.
+    #[cfg(tokio_unstable)]
+    type WorkerCallback = std::sync::Arc<dyn Fn(usize) + Send + Sync>;
+
+    /// Computes a worker's coop budget, given its index. See
+    /// `Builder::task_budget`.
+    type TaskBudgetFn = std::sync::Arc<dyn Fn(usize) -> u32 + Send + Sync>;
+
+    /// Invoked with a suggested budget when the last worker is about to
+    /// park. See `Builder::on_all_idle`.
+    #[cfg(tokio_unstable)]
+    type IdleCallback = std::sync::Arc<dyn Fn(std::time::Duration) + Send + Sync>;
+
+    /// Invoked on a worker thread during maintenance with the worker's index
+    /// and the metrics it accumulated since its previous submit. See
+    /// `Builder::metrics_sink`.
+    #[cfg(tokio_unstable)]
+    type MetricsSinkFn = std::sync::Arc<dyn Fn(usize, &metrics::WorkerMetricsDelta) + Send + Sync>;
 }
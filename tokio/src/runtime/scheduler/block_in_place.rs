@@ -19,3 +19,25 @@ where
 
     scheduler::multi_thread::block_in_place(f)
 }
+
+cfg_unstable! {
+    /// Like [`block_in_place`], but treats the handoff as optional below a
+    /// configurable threshold. Only supported by the default multi-threaded
+    /// scheduler; the [alternate multi-threaded scheduler][alt] falls back
+    /// to always handing off, same as `block_in_place`.
+    ///
+    /// [alt]: crate::runtime::RuntimeFlavor::MultiThreadAlt
+    #[track_caller]
+    pub(crate) fn block_in_place_for<F, R>(hint_duration: std::time::Duration, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        use crate::runtime::{Handle, RuntimeFlavor::MultiThreadAlt};
+
+        if let Ok(MultiThreadAlt) = Handle::try_current().map(|h| h.runtime_flavor()) {
+            return scheduler::multi_thread_alt::block_in_place(f);
+        }
+
+        scheduler::multi_thread::block_in_place_for(hint_duration, f)
+    }
+}
@@ -93,6 +93,11 @@ struct Shared {
 
     /// This scheduler only has one worker.
     worker_metrics: WorkerMetrics,
+
+    /// Human-readable label for the single worker. Purely diagnostic; see
+    /// `Config::worker_labels`.
+    #[cfg(tokio_unstable)]
+    worker_label: Box<str>,
 }
 
 /// Thread-local context.
@@ -131,10 +136,21 @@ impl CurrentThread {
         let worker_metrics = WorkerMetrics::from_config(&config);
         worker_metrics.set_thread_id(thread::current().id());
 
-        // Get the configured global queue interval, or use the default.
+        // Get the configured global queue interval, or use the default. This
+        // scheduler has no self-tuning, so the published value never changes
+        // after this point.
         let global_queue_interval = config
             .global_queue_interval
             .unwrap_or(DEFAULT_GLOBAL_QUEUE_INTERVAL);
+        worker_metrics.set_global_queue_interval(global_queue_interval);
+
+        #[cfg(tokio_unstable)]
+        let worker_label = config
+            .worker_labels
+            .first()
+            .cloned()
+            .unwrap_or_else(|| "worker-0".to_string())
+            .into_boxed_str();
 
         let handle = Arc::new(Handle {
             task_hooks: TaskHooks {
@@ -143,11 +159,13 @@ impl CurrentThread {
             },
             shared: Shared {
                 inject: Inject::new(),
-                owned: OwnedTasks::new(1),
+                owned: OwnedTasks::new(1, config.max_live_tasks()),
                 woken: AtomicBool::new(false),
                 config,
                 scheduler_metrics: SchedulerMetrics::new(),
                 worker_metrics,
+                #[cfg(tokio_unstable)]
+                worker_label,
             },
             driver: driver_handle,
             blocking_spawner,
@@ -308,12 +326,28 @@ impl Core {
 
     fn next_task(&mut self, handle: &Handle) -> Option<Notified> {
         if self.tick % self.global_queue_interval == 0 {
-            handle
-                .next_remote_task()
-                .or_else(|| self.next_local_task(handle))
+            if let Some(task) = handle.next_remote_task() {
+                self.metrics.incr_global_queue_pull_count();
+                return Some(task);
+            }
+
+            let task = self.next_local_task(handle);
+            if task.is_some() {
+                self.metrics.incr_local_queue_pull_count();
+            }
+            task
         } else {
-            self.next_local_task(handle)
-                .or_else(|| handle.next_remote_task())
+            let task = self.next_local_task(handle);
+            if task.is_some() {
+                self.metrics.incr_local_queue_pull_count();
+                return task;
+            }
+
+            let task = handle.next_remote_task();
+            if task.is_some() {
+                self.metrics.incr_global_queue_pull_count();
+            }
+            task
         }
     }
 
@@ -354,6 +388,7 @@ impl Context {
     /// Execute the closure with the given scheduler core stored in the
     /// thread-local context.
     fn run_task<R>(&self, mut core: Box<Core>, f: impl FnOnce() -> R) -> (Box<Core>, R) {
+        self.handle.shared.worker_metrics.record_poll_start();
         core.metrics.start_poll();
         let mut ret = self.enter(core, || crate::runtime::coop::budget(f));
         ret.0.metrics.end_poll();
@@ -429,6 +464,16 @@ impl Context {
     pub(crate) fn defer(&self, waker: &Waker) {
         self.defer.defer(waker);
     }
+
+    cfg_unstable! {
+        /// The current-thread scheduler has no idle peers to offer a
+        /// backlog to, so this is a no-op.
+        pub(crate) fn yield_core_hint(&self) {}
+
+        pub(crate) fn shutdown_requested(&self) -> bool {
+            self.handle.shared.inject.is_closed()
+        }
+    }
 }
 
 // ===== impl Handle =====
@@ -444,7 +489,13 @@ impl Handle {
         F: crate::future::Future + Send + 'static,
         F::Output: Send + 'static,
     {
-        let (handle, notified) = me.shared.owned.bind(future, me.clone(), id);
+        let (handle, notified) = me.shared.owned.bind(
+            future,
+            me.clone(),
+            id,
+            false,
+            crate::runtime::task::TaskPriority::Normal,
+        );
 
         me.task_hooks.spawn(&TaskMeta {
             #[cfg(tokio_unstable)]
@@ -494,7 +545,7 @@ impl Handle {
 
             traces = trace_current_thread(&self.shared.owned, local, &self.shared.inject)
                 .into_iter()
-                .map(|(id, trace)| dump::Task::new(id, trace))
+                .map(|(id, migration_count, trace)| dump::Task::new(id, migration_count, trace))
                 .collect();
 
             // Avoid double borrow panic
@@ -529,6 +580,11 @@ impl Handle {
         self.shared.owned.num_alive_tasks()
     }
 
+    #[cfg(tokio_unstable)]
+    pub(crate) fn max_live_tasks(&self) -> Option<usize> {
+        self.shared.config.max_live_tasks()
+    }
+
     pub(crate) fn injection_queue_depth(&self) -> usize {
         self.shared.inject.len()
     }
@@ -549,6 +605,14 @@ cfg_unstable_metrics! {
             self.worker_metrics(worker).queue_depth()
         }
 
+        // The current-thread scheduler's local queue is an unbounded
+        // `VecDeque`, so it never overflows and there is effectively
+        // always room for more tasks.
+        pub(crate) fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+            assert_eq!(0, worker);
+            usize::MAX
+        }
+
         pub(crate) fn num_blocking_threads(&self) -> usize {
             self.blocking_spawner.num_threads()
         }
@@ -566,6 +630,46 @@ cfg_unstable_metrics! {
                 self.shared.owned.spawned_tasks_count()
             }
         }
+
+        // The current-thread scheduler always runs on exactly the thread
+        // that drives it; there is no core handoff mechanism like
+        // `block_in_place` on the multi-threaded scheduler.
+        pub(crate) fn live_worker_thread_count(&self) -> usize {
+            1
+        }
+
+        // For the same reason, there is never an outstanding `block_in_place`
+        // handoff to report.
+        pub(crate) fn outstanding_block_in_place_count(&self) -> usize {
+            0
+        }
+
+        pub(crate) fn worker_global_queue_intervals(&self) -> Vec<u32> {
+            vec![self.shared.worker_metrics.global_queue_interval()]
+        }
+
+        /// Best-effort snapshot of the total number of pending tasks. The
+        /// current-thread scheduler has a single queue, so this is just its
+        /// depth plus the injection queue depth.
+        pub(crate) fn total_pending_tasks(&self) -> usize {
+            self.worker_local_queue_depth(0) + self.injection_queue_depth()
+        }
+
+        // The current-thread scheduler has a single worker and never steals,
+        // so there is no concurrent searching to have a high-water mark of.
+        pub(crate) fn peak_searching_workers(&self) -> usize {
+            0
+        }
+
+        pub(crate) fn reset_peak_searching_workers(&self) {}
+
+        cfg_64bit_metrics! {
+            /// The current-thread scheduler has a single worker, so there is
+            /// no stealing between workers to report.
+            pub(crate) fn steal_matrix(&self) -> Vec<Vec<u64>> {
+                vec![vec![0]]
+            }
+        }
     }
 }
 
@@ -576,6 +680,33 @@ cfg_unstable! {
         pub(crate) fn owned_id(&self) -> NonZeroU64 {
             self.shared.owned.id
         }
+
+        /// The current-thread scheduler doesn't capture the seed used for
+        /// its own `FastRand`, since it has no steal order to reproduce.
+        pub(crate) fn worker_rng_seeds(&self) -> Vec<u64> {
+            Vec::new()
+        }
+
+        pub(crate) fn worker_label(&self, worker: usize) -> &str {
+            assert_eq!(worker, 0, "the current-thread scheduler only has one worker");
+            &self.shared.worker_label
+        }
+
+        /// The current-thread scheduler has no LIFO slot to disable, so this
+        /// is a no-op.
+        pub(crate) fn set_lifo_enabled_all(&self, _enabled: bool) {}
+
+        /// The current-thread scheduler has no work-stealing to steal back
+        /// from, so this is a no-op.
+        pub(crate) fn set_steal_back_enabled(&self, _enabled: bool) {}
+
+        /// The current-thread scheduler has no other workers whose local
+        /// work would benefit from pausing injection, so this is a no-op.
+        pub(crate) fn pause_inject(&self) {}
+
+        /// The current-thread scheduler has no other workers whose local
+        /// work would benefit from pausing injection, so this is a no-op.
+        pub(crate) fn resume_inject(&self) {}
     }
 }
 
@@ -622,6 +753,18 @@ impl Schedule for Arc<Handle> {
         }
     }
 
+    fn yield_now(&self, task: task::Notified<Self>) {
+        // `yield_now` is only ever reached when a task's own poll notified
+        // its own waker: the task can't be resubmitted mid-poll, so it's
+        // queued for another go once the current poll returns.
+        #[cfg(tokio_unstable)]
+        if self.shared.config.track_self_wake_count {
+            self.shared.worker_metrics.incr_self_wake_count();
+        }
+
+        self.schedule(task);
+    }
+
     cfg_unstable! {
         fn unhandled_panic(&self) {
             use crate::runtime::UnhandledPanic;
@@ -732,11 +875,13 @@ impl CoreGuard<'_> {
 
                     let task = context.handle.shared.owned.assert_owner(task);
 
-                    let (c, ()) = context.run_task(core, || {
-                        task.run();
-                    });
+                    let (c, outcome) = context.run_task(core, || task.run());
 
                     core = c;
+
+                    if outcome.is_complete {
+                        core.metrics.incr_completed_tasks();
+                    }
                 }
 
                 core.metrics.end_processing_scheduled_tasks();
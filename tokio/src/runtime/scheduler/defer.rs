@@ -29,10 +29,27 @@ impl Defer {
         self.deferred.borrow().is_empty()
     }
 
-    pub(crate) fn wake(&self) {
+    /// Wakes every deferred waker, in effect rescheduling the tasks that
+    /// deferred themselves back onto the scheduler.
+    ///
+    /// This just calls `Waker::wake`, so it goes through the regular
+    /// `Schedule::schedule` path rather than pushing onto a queue directly.
+    /// For schedulers whose `schedule` implementation checks whether the
+    /// calling thread already holds the task's owning core (as
+    /// `multi_thread` and `current_thread` do), that means a deferred task
+    /// lands back on the local run queue for free as long as `wake` is
+    /// called from the worker that deferred it with its core still in hand,
+    /// which is the case for every current call site of this method.
+    ///
+    /// Returns the number of wakers that were drained, so callers can feed
+    /// it into metrics.
+    pub(crate) fn wake(&self) -> usize {
+        let mut count = 0;
         while let Some(waker) = self.deferred.borrow_mut().pop() {
             waker.wake();
+            count += 1;
         }
+        count
     }
 
     #[cfg(tokio_taskdump)]
@@ -6,6 +6,8 @@ use crate::runtime::task;
 mod pop;
 pub(crate) use pop::Pop;
 
+pub(crate) mod priority;
+
 mod shared;
 pub(crate) use shared::Shared;
 
@@ -36,7 +38,7 @@ impl<T: 'static> Inject<T> {
     }
 
     // Kind of annoying to have to include the cfg here
-    #[cfg(tokio_taskdump)]
+    #[cfg(any(tokio_taskdump, tokio_unstable))]
     pub(crate) fn is_closed(&self) -> bool {
         let synced = self.synced.lock();
         self.shared.is_closed(&synced)
@@ -55,7 +57,9 @@ impl<T: 'static> Inject<T> {
     pub(crate) fn push(&self, task: task::Notified<T>) {
         let mut synced = self.synced.lock();
         // safety: passing correct `Synced`
-        unsafe { self.shared.push(&mut synced, task) }
+        unsafe {
+            self.shared.push(&mut synced, task);
+        }
     }
 
     pub(crate) fn pop(&self) -> Option<task::Notified<T>> {
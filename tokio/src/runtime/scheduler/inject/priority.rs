@@ -0,0 +1,125 @@
+// No worker's task dispatch loop uses this queue; only its own tests
+// exercise it.
+#![allow(dead_code)]
+
+use super::Inject;
+use crate::loom::sync::Mutex;
+use crate::runtime::task;
+
+/// A multi-class variant of [`Inject`] that drains classes with weighted
+/// fairness instead of strict priority order.
+///
+/// Each class is its own FIFO queue. `next_remote_task_batch` visits the
+/// classes in a round, crediting each with its configured weight before
+/// draining it, so a class is only popped from while it still has credit
+/// left in the current round. This is the deficit round-robin scheduling
+/// algorithm: over the long run each class is drained in proportion to its
+/// weight, while a low-weight class can never be starved indefinitely by
+/// busier ones, unlike strict priority ordering.
+///
+/// This is a standalone building block: no worker's task dispatch loop
+/// uses it, and `spawn_with_priority` places tasks without going through
+/// it.
+pub(crate) struct PriorityInject<T: 'static> {
+    classes: Box<[Inject<T>]>,
+    weights: Box<[u32]>,
+    // Accumulated, unspent draining credit for each class. Guarded by a
+    // single lock since a round always visits every class in order.
+    deficits: Mutex<Box<[u32]>>,
+}
+
+impl<T: 'static> PriorityInject<T> {
+    /// Creates a new queue with one class per weight. `weights[i]` is how
+    /// many tasks class `i` may drain per round relative to the other
+    /// classes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty or contains a `0`.
+    pub(crate) fn new(weights: Vec<u32>) -> PriorityInject<T> {
+        assert!(!weights.is_empty(), "must have at least one class");
+        assert!(
+            weights.iter().all(|&w| w > 0),
+            "class weights must be greater than 0"
+        );
+
+        let classes = weights.iter().map(|_| Inject::new()).collect();
+        let deficits = vec![0; weights.len()].into_boxed_slice();
+
+        PriorityInject {
+            classes,
+            weights: weights.into_boxed_slice(),
+            deficits: Mutex::new(deficits),
+        }
+    }
+
+    /// Number of priority classes in the queue.
+    pub(crate) fn num_classes(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// Number of tasks currently queued in the given class.
+    pub(crate) fn class_len(&self, class: usize) -> usize {
+        self.classes[class].len()
+    }
+
+    /// Pushes a task onto the given class's queue.
+    pub(crate) fn push(&self, class: usize, task: task::Notified<T>) {
+        self.classes[class].push(task);
+    }
+
+    /// Closes every class's queue.
+    pub(crate) fn close(&self) {
+        for class in &*self.classes {
+            class.close();
+        }
+    }
+
+    /// Pops up to `max` tasks total, drained across classes with weighted
+    /// fairness.
+    ///
+    /// Keeps running full rounds, crediting every class with its weight,
+    /// until either `max` tasks have been popped or a full round drains
+    /// nothing (i.e. every class is empty).
+    pub(crate) fn next_remote_task_batch(&self, max: usize) -> Vec<task::Notified<T>> {
+        let mut out = Vec::with_capacity(max);
+
+        if max == 0 {
+            return out;
+        }
+
+        let mut deficits = self.deficits.lock();
+
+        loop {
+            let mut made_progress = false;
+
+            for class in 0..self.classes.len() {
+                deficits[class] += self.weights[class];
+
+                while deficits[class] > 0 {
+                    match self.classes[class].pop() {
+                        Some(task) => {
+                            out.push(task);
+                            deficits[class] -= 1;
+                            made_progress = true;
+
+                            if out.len() >= max {
+                                return out;
+                            }
+                        }
+                        None => {
+                            // Nothing left to spend this round's credit on;
+                            // don't let it carry over and burst later.
+                            deficits[class] = 0;
+                            break;
+                        }
+                    }
+                }
+            }
+
+            if !made_progress {
+                return out;
+            }
+        }
+    }
+}
@@ -32,7 +32,11 @@ impl<T: 'static> Shared<T> {
         I: Iterator<Item = task::Notified<T>>,
     {
         let first = match iter.next() {
-            Some(first) => first.into_raw(),
+            Some(first) => {
+                #[cfg(tokio_unstable)]
+                first.set_inject_enqueued_at(std::time::Instant::now());
+                first.into_raw()
+            }
             None => return,
         };
 
@@ -44,6 +48,8 @@ impl<T: 'static> Shared<T> {
         // iterator overrides `for_each` to something that is easier for the
         // compiler to optimize than a loop.
         iter.for_each(|next| {
+            #[cfg(tokio_unstable)]
+            next.set_inject_enqueued_at(std::time::Instant::now());
             let next = next.into_raw();
 
             // safety: Holding the Notified for a task guarantees exclusive
@@ -62,16 +62,21 @@ impl<T: 'static> Shared<T> {
     ///
     /// This does nothing if the queue is closed.
     ///
+    /// Returns `true` if the push transitioned the queue's atomic length
+    /// from empty to non-empty.
+    ///
     /// # Safety
     ///
     /// Must be called with the same `Synced` instance returned by `Inject::new`
-    pub(crate) unsafe fn push(&self, synced: &mut Synced, task: task::Notified<T>) {
+    pub(crate) unsafe fn push(&self, synced: &mut Synced, task: task::Notified<T>) -> bool {
         if synced.is_closed {
-            return;
+            return false;
         }
 
         // safety: only mutated with the lock held
         let len = self.len.unsync_load();
+        #[cfg(tokio_unstable)]
+        task.set_inject_enqueued_at(std::time::Instant::now());
         let task = task.into_raw();
 
         // The next pointer should already be null
@@ -87,6 +92,8 @@ impl<T: 'static> Shared<T> {
 
         synced.tail = Some(task);
         self.len.store(len + 1, Release);
+
+        len == 0
     }
 
     /// Pop a value from the queue.
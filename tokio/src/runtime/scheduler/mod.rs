@@ -14,6 +14,9 @@ cfg_rt! {
 cfg_rt_multi_thread! {
     mod block_in_place;
     pub(crate) use block_in_place::block_in_place;
+    cfg_unstable! {
+        pub(crate) use block_in_place::block_in_place_for;
+    }
 
     mod lock;
     use lock::Lock;
@@ -129,6 +132,171 @@ cfg_rt! {
             }
         }
 
+        cfg_unstable! {
+            /// Spawns a future, polling it once inline on the current worker
+            /// before falling back to normal scheduling.
+            ///
+            /// Only `multi_thread` distinguishes an inline poll from a
+            /// normal one; the other flavors just spawn normally, since a
+            /// `current_thread` runtime always polls freshly spawned tasks
+            /// from its single worker loop anyway, and `multi_thread_alt`
+            /// doesn't support the tracking the inline path needs.
+            pub(crate) fn spawn_inline<F>(&self, future: F, id: Id) -> JoinHandle<F::Output>
+            where
+                F: Future + Send + 'static,
+                F::Output: Send + 'static,
+            {
+                match self {
+                    Handle::CurrentThread(h) => {
+                        current_thread::Handle::spawn(h, future, id)
+                    }
+
+                    #[cfg(feature = "rt-multi-thread")]
+                    Handle::MultiThread(h) => multi_thread::Handle::spawn_inline(h, future, id),
+
+                    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                    Handle::MultiThreadAlt(h) => {
+                        multi_thread_alt::Handle::spawn(h, future, id)
+                    }
+                }
+            }
+
+            /// Spawns a future that only runs once every worker has run out
+            /// of other work.
+            ///
+            /// Only `multi_thread` has a notion of workers running out of
+            /// work to fall back to; the other flavors just spawn normally.
+            pub(crate) fn spawn_when_idle<F>(&self, future: F, id: Id) -> JoinHandle<F::Output>
+            where
+                F: Future + Send + 'static,
+                F::Output: Send + 'static,
+            {
+                match self {
+                    Handle::CurrentThread(h) => {
+                        current_thread::Handle::spawn(h, future, id)
+                    }
+
+                    #[cfg(feature = "rt-multi-thread")]
+                    Handle::MultiThread(h) => multi_thread::Handle::spawn_when_idle(h, future, id),
+
+                    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                    Handle::MultiThreadAlt(h) => {
+                        multi_thread_alt::Handle::spawn(h, future, id)
+                    }
+                }
+            }
+
+            /// Spawns a future with the given execution-order priority hint.
+            ///
+            /// Only `multi_thread` consults the hint when placing and
+            /// stealing the task; the other flavors just spawn normally,
+            /// since `current_thread` has no other workers to reorder
+            /// against and `multi_thread_alt` doesn't implement the
+            /// placement or stealing logic that consults it.
+            pub(crate) fn spawn_with_priority<F>(
+                &self,
+                future: F,
+                id: Id,
+                priority: crate::runtime::task::TaskPriority,
+            ) -> JoinHandle<F::Output>
+            where
+                F: Future + Send + 'static,
+                F::Output: Send + 'static,
+            {
+                match self {
+                    Handle::CurrentThread(h) => {
+                        current_thread::Handle::spawn(h, future, id)
+                    }
+
+                    #[cfg(feature = "rt-multi-thread")]
+                    Handle::MultiThread(h) => {
+                        multi_thread::Handle::spawn_with_priority(h, future, id, priority)
+                    }
+
+                    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                    Handle::MultiThreadAlt(h) => {
+                        multi_thread_alt::Handle::spawn(h, future, id)
+                    }
+                }
+            }
+
+            /// Spawns a future, delivering it directly to `worker`'s
+            /// low-latency fast-path mailbox instead of going through the
+            /// normal placement logic.
+            ///
+            /// Only `multi_thread` has per-worker mailboxes to deliver into;
+            /// the other flavors just spawn normally, ignoring `worker`.
+            pub(crate) fn notify_fast<F>(&self, worker: usize, future: F, id: Id) -> JoinHandle<F::Output>
+            where
+                F: Future + Send + 'static,
+                F::Output: Send + 'static,
+            {
+                match self {
+                    Handle::CurrentThread(h) => {
+                        current_thread::Handle::spawn(h, future, id)
+                    }
+
+                    #[cfg(feature = "rt-multi-thread")]
+                    Handle::MultiThread(h) => multi_thread::Handle::notify_fast(h, worker, future, id),
+
+                    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                    Handle::MultiThreadAlt(h) => {
+                        multi_thread_alt::Handle::spawn(h, future, id)
+                    }
+                }
+            }
+
+            /// Spawns a future, delivering it to `worker`'s per-worker
+            /// inject queue instead of going through the normal placement
+            /// logic.
+            ///
+            /// Only `multi_thread` has per-worker inject queues to deliver
+            /// into; the other flavors just spawn normally, ignoring
+            /// `worker`.
+            pub(crate) fn inject_to_worker<F>(&self, worker: usize, future: F, id: Id) -> JoinHandle<F::Output>
+            where
+                F: Future + Send + 'static,
+                F::Output: Send + 'static,
+            {
+                match self {
+                    Handle::CurrentThread(h) => {
+                        current_thread::Handle::spawn(h, future, id)
+                    }
+
+                    #[cfg(feature = "rt-multi-thread")]
+                    Handle::MultiThread(h) => {
+                        multi_thread::Handle::inject_to_worker(h, worker, future, id)
+                    }
+
+                    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                    Handle::MultiThreadAlt(h) => {
+                        multi_thread_alt::Handle::spawn(h, future, id)
+                    }
+                }
+            }
+
+            /// Tries to drive the resource (I/O, timer, ...) driver once,
+            /// with a zero timeout, from the current worker.
+            ///
+            /// Only `multi_thread` supports this: it's the only flavor where
+            /// the driver is handed off between workers rather than owned
+            /// outright by whichever thread is currently driving the
+            /// runtime, so it's the only one that needs an explicit,
+            /// best-effort attempt to grab it on demand. Returns `false`
+            /// without doing anything on the other flavors.
+            pub(crate) fn drive_once(&self) -> bool {
+                match self {
+                    Handle::CurrentThread(_) => false,
+
+                    #[cfg(feature = "rt-multi-thread")]
+                    Handle::MultiThread(h) => h.drive_once(),
+
+                    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+                    Handle::MultiThreadAlt(_) => false,
+                }
+            }
+        }
+
         pub(crate) fn shutdown(&self) {
             match *self {
                 Handle::CurrentThread(_) => {},
@@ -190,6 +358,11 @@ cfg_rt! {
             match_flavor!(self, Handle(handle) => handle.num_alive_tasks())
         }
 
+        #[cfg(tokio_unstable)]
+        pub(crate) fn max_live_tasks(&self) -> Option<usize> {
+            match_flavor!(self, Handle(handle) => handle.max_live_tasks())
+        }
+
         pub(crate) fn injection_queue_depth(&self) -> usize {
             match_flavor!(self, Handle(handle) => handle.injection_queue_depth())
         }
@@ -225,9 +398,47 @@ cfg_rt! {
                 match_flavor!(self, Handle(handle) => handle.worker_local_queue_depth(worker))
             }
 
+            pub(crate) fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+                match_flavor!(self, Handle(handle) => handle.worker_run_queue_remaining(worker))
+            }
+
             pub(crate) fn blocking_queue_depth(&self) -> usize {
                 match_flavor!(self, Handle(handle) => handle.blocking_queue_depth())
             }
+
+            pub(crate) fn live_worker_thread_count(&self) -> usize {
+                match_flavor!(self, Handle(handle) => handle.live_worker_thread_count())
+            }
+
+            pub(crate) fn outstanding_block_in_place_count(&self) -> usize {
+                match_flavor!(self, Handle(handle) => handle.outstanding_block_in_place_count())
+            }
+
+            pub(crate) fn worker_global_queue_intervals(&self) -> Vec<u32> {
+                match_flavor!(self, Handle(handle) => handle.worker_global_queue_intervals())
+            }
+
+            pub(crate) fn worker_label(&self, worker: usize) -> &str {
+                match_flavor!(self, Handle(handle) => handle.worker_label(worker))
+            }
+
+            pub(crate) fn total_pending_tasks(&self) -> usize {
+                match_flavor!(self, Handle(handle) => handle.total_pending_tasks())
+            }
+
+            pub(crate) fn peak_searching_workers(&self) -> usize {
+                match_flavor!(self, Handle(handle) => handle.peak_searching_workers())
+            }
+
+            pub(crate) fn reset_peak_searching_workers(&self) {
+                match_flavor!(self, Handle(handle) => handle.reset_peak_searching_workers())
+            }
+
+            cfg_64bit_metrics! {
+                pub(crate) fn steal_matrix(&self) -> Vec<Vec<u64>> {
+                    match_flavor!(self, Handle(handle) => handle.steal_matrix())
+                }
+            }
         }
     }
 
@@ -245,6 +456,16 @@ cfg_rt! {
             match_flavor!(self, Context(context) => context.defer(waker));
         }
 
+        cfg_unstable! {
+            pub(crate) fn yield_core_hint(&self) {
+                match_flavor!(self, Context(context) => context.yield_core_hint());
+            }
+
+            pub(crate) fn shutdown_requested(&self) -> bool {
+                match_flavor!(self, Context(context) => context.shutdown_requested())
+            }
+        }
+
         cfg_rt_multi_thread! {
             #[track_caller]
             pub(crate) fn expect_multi_thread(&self) -> &multi_thread::Context {
@@ -0,0 +1,155 @@
+use crate::loom::sync::atomic::{AtomicBool, Ordering};
+use crate::loom::sync::{Arc, Condvar, Mutex};
+use crate::runtime::scheduler::multi_thread::Handle;
+use crate::sync::notify::Notify;
+
+use std::time::{Duration, Instant};
+
+/// The `Handle::barrier` callback, boxed before being wrapped in an `Arc` so
+/// that the coercion to `dyn Fn` happens on the (real, unsized-coercion
+/// capable) `std::boxed::Box` rather than on `crate::loom::sync::Arc`, which
+/// under `--cfg loom` doesn't support it.
+pub(super) type BarrierCallback = Arc<Box<dyn Fn() + Send + Sync>>;
+
+/// Coordinates a `Handle::barrier` request across every worker, mirroring
+/// the taskdump `TraceStatus` protocol: an atomic flag each worker polls
+/// between tasks, a pair of barriers to rendezvous entry and exit, and a
+/// `Notify` to signal the caller once every worker has run the callback.
+pub(super) struct BarrierStatus {
+    requested: AtomicBool,
+    pub(super) barrier_start: Rendezvous,
+    pub(super) barrier_end: Rendezvous,
+    pub(super) callback: Mutex<Option<BarrierCallback>>,
+    pub(super) completed: Notify,
+}
+
+impl BarrierStatus {
+    pub(super) fn new(remotes_len: usize) -> Self {
+        Self {
+            requested: AtomicBool::new(false),
+            barrier_start: Rendezvous::new(remotes_len),
+            barrier_end: Rendezvous::new(remotes_len),
+            callback: Mutex::new(None),
+            completed: Notify::new(),
+        }
+    }
+
+    pub(super) fn requested(&self) -> bool {
+        self.requested.load(Ordering::Relaxed)
+    }
+
+    pub(super) fn callback(&self) -> Option<BarrierCallback> {
+        self.callback.lock().clone()
+    }
+
+    pub(super) async fn start_barrier_request(&self, handle: &Handle) {
+        while self
+            .requested
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            handle.notify_all();
+            crate::task::yield_now().await;
+        }
+    }
+
+    pub(super) async fn end_barrier_request(&self, handle: &Handle) {
+        while self
+            .requested
+            .compare_exchange(true, false, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            handle.notify_all();
+            crate::task::yield_now().await;
+        }
+    }
+}
+
+/// A single-use-per-generation rendezvous point supporting a timed wait,
+/// built on `Mutex`/`Condvar` rather than `loom::sync::Barrier`, which has
+/// no timeout support. Mirrors `std::sync::Barrier`, plus `wait_timeout`.
+pub(super) struct Rendezvous {
+    state: Mutex<RendezvousState>,
+    condvar: Condvar,
+    num_threads: usize,
+}
+
+struct RendezvousState {
+    count: usize,
+    generation: usize,
+}
+
+/// Whether this thread was the one to complete the rendezvous.
+pub(super) struct RendezvousResult(bool);
+
+impl RendezvousResult {
+    pub(super) fn is_leader(&self) -> bool {
+        self.0
+    }
+}
+
+impl Rendezvous {
+    pub(super) fn new(num_threads: usize) -> Self {
+        Self {
+            state: Mutex::new(RendezvousState {
+                count: 0,
+                generation: 0,
+            }),
+            condvar: Condvar::new(),
+            num_threads,
+        }
+    }
+
+    /// Blocks until every thread has called `wait`, then returns.
+    pub(super) fn wait(&self) -> RendezvousResult {
+        let mut state = self.state.lock();
+        let generation = state.generation;
+        state.count += 1;
+
+        if state.count < self.num_threads {
+            while generation == state.generation {
+                state = self.condvar.wait(state).unwrap();
+            }
+            RendezvousResult(false)
+        } else {
+            state.count = 0;
+            state.generation = state.generation.wrapping_add(1);
+            self.condvar.notify_all();
+            RendezvousResult(true)
+        }
+    }
+
+    /// Blocks until every thread has called `wait`/`wait_timeout`, or gives
+    /// up and returns `None` once `timeout` has elapsed without that
+    /// happening.
+    pub(super) fn wait_timeout(&self, timeout: Duration) -> Option<RendezvousResult> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.state.lock();
+        let generation = state.generation;
+        state.count += 1;
+
+        if state.count < self.num_threads {
+            while generation == state.generation {
+                let now = Instant::now();
+                if now >= deadline {
+                    // Give up without having rendezvoused; leave the count
+                    // as-is so a straggler thread still completes the
+                    // generation once it (eventually) shows up.
+                    return None;
+                }
+                let (guard, timeout_result) =
+                    self.condvar.wait_timeout(state, deadline - now).unwrap();
+                state = guard;
+                if timeout_result.timed_out() && generation == state.generation {
+                    return None;
+                }
+            }
+            Some(RendezvousResult(false))
+        } else {
+            state.count = 0;
+            state.generation = state.generation.wrapping_add(1);
+            self.condvar.notify_all();
+            Some(RendezvousResult(true))
+        }
+    }
+}
@@ -16,6 +16,10 @@ cfg_taskdump! {
     mod taskdump;
 }
 
+cfg_unstable! {
+    mod barrier;
+}
+
 /// Handle to the multi thread scheduler
 pub(crate) struct Handle {
     /// Task spawner
@@ -36,7 +40,11 @@ pub(crate) struct Handle {
 
 impl Handle {
     /// Spawns a future onto the thread pool
-    pub(crate) fn spawn<F>(me: &Arc<Self>, future: F, id: task::Id) -> JoinHandle<F::Output>
+    pub(crate) fn spawn<F>(
+        me: &Arc<Self>,
+        future: F,
+        id: task::Id,
+    ) -> JoinHandle<F::Output>
     where
         F: crate::future::Future + Send + 'static,
         F::Output: Send + 'static,
@@ -48,23 +56,36 @@ impl Handle {
         self.close();
     }
 
-    pub(super) fn bind_new_task<T>(me: &Arc<Self>, future: T, id: task::Id) -> JoinHandle<T::Output>
+    pub(super) fn bind_new_task<T>(
+        me: &Arc<Self>,
+        future: T,
+        id: task::Id,
+    ) -> JoinHandle<T::Output>
     where
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        let (handle, notified) = me.shared.owned.bind(future, me.clone(), id);
+        let (handle, notified) = me.shared.owned.bind(
+            future,
+            me.clone(),
+            id,
+            false,
+            task::TaskPriority::Normal,
+        );
 
-        me.task_hooks.spawn(&TaskMeta {
+        let meta = TaskMeta {
             #[cfg(tokio_unstable)]
             id,
             _phantom: Default::default(),
-        });
+        };
 
-        me.schedule_option_task_without_yield(notified);
+        me.task_hooks.spawn(&meta);
+
+        me.schedule_new_task(notified, &meta);
 
         handle
     }
+
 }
 
 cfg_unstable! {
@@ -74,6 +95,223 @@ cfg_unstable! {
         pub(crate) fn owned_id(&self) -> NonZeroU64 {
             self.shared.owned.id
         }
+
+        pub(crate) fn worker_rng_seeds(&self) -> Vec<u64> {
+            self.shared.worker_rng_seeds.to_vec()
+        }
+
+        pub(crate) fn worker_label(&self, worker: usize) -> &str {
+            &self.shared.worker_labels[worker]
+        }
+
+        pub(crate) fn set_lifo_enabled_all(&self, enabled: bool) {
+            self.shared
+                .lifo_enabled_all
+                .store(enabled, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        pub(crate) fn pause_inject(&self) {
+            self.shared
+                .inject_paused
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        pub(crate) fn resume_inject(&self) {
+            self.shared
+                .inject_paused
+                .store(false, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        pub(crate) fn set_steal_back_enabled(&self, enabled: bool) {
+            self.shared
+                .steal_back_enabled
+                .store(enabled, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        /// Spawns a future onto the thread pool, trying to poll it once
+        /// inline on the current worker before falling back to normal
+        /// scheduling.
+        pub(crate) fn spawn_inline<F>(me: &Arc<Self>, future: F, id: task::Id) -> JoinHandle<F::Output>
+        where
+            F: crate::future::Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            Self::bind_new_task_inline(me, future, id)
+        }
+
+        fn bind_new_task_inline<T>(me: &Arc<Self>, future: T, id: task::Id) -> JoinHandle<T::Output>
+        where
+            T: Future + Send + 'static,
+            T::Output: Send + 'static,
+        {
+            let (handle, notified) = me.shared.owned.bind(
+                future,
+                me.clone(),
+                id,
+                false,
+                task::TaskPriority::Normal,
+            );
+
+            let meta = TaskMeta {
+                #[cfg(tokio_unstable)]
+                id,
+                _phantom: Default::default(),
+            };
+
+            me.task_hooks.spawn(&meta);
+
+            let notified = me.try_spawn_inline(notified);
+            me.schedule_new_task(notified, &meta);
+
+            handle
+        }
+
+        /// Spawns a future, delivering it directly to `worker`'s low-latency
+        /// fast-path mailbox instead of going through the normal placement
+        /// logic.
+        ///
+        /// See `Handle::push_fast` for the delivery semantics.
+        pub(crate) fn notify_fast<F>(
+            me: &Arc<Self>,
+            worker: usize,
+            future: F,
+            id: task::Id,
+        ) -> JoinHandle<F::Output>
+        where
+            F: crate::future::Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let (handle, notified) = me.shared.owned.bind(
+                future,
+                me.clone(),
+                id,
+                false,
+                task::TaskPriority::Normal,
+            );
+
+            let meta = TaskMeta {
+                #[cfg(tokio_unstable)]
+                id,
+                _phantom: Default::default(),
+            };
+
+            me.task_hooks.spawn(&meta);
+
+            if let Some(notified) = notified {
+                me.push_fast(worker, notified);
+            }
+
+            handle
+        }
+
+        /// Spawns a future, delivering it to `worker`'s per-worker inject
+        /// queue instead of going through the normal placement logic.
+        ///
+        /// See `Handle::push_to_worker` for the delivery semantics.
+        pub(crate) fn inject_to_worker<F>(
+            me: &Arc<Self>,
+            worker: usize,
+            future: F,
+            id: task::Id,
+        ) -> JoinHandle<F::Output>
+        where
+            F: crate::future::Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let (handle, notified) = me.shared.owned.bind(
+                future,
+                me.clone(),
+                id,
+                false,
+                task::TaskPriority::Normal,
+            );
+
+            let meta = TaskMeta {
+                #[cfg(tokio_unstable)]
+                id,
+                _phantom: Default::default(),
+            };
+
+            me.task_hooks.spawn(&meta);
+
+            if let Some(notified) = notified {
+                me.push_to_worker(worker, notified);
+            }
+
+            handle
+        }
+
+        /// Spawns a future that only runs once every worker has run out of
+        /// other work.
+        ///
+        /// Unlike `spawn`, this bypasses `Config::placement` entirely: the
+        /// task is pushed straight onto `Shared::idle_tasks` rather than a
+        /// worker's local queue or the injection queue, and stays there for
+        /// its entire lifetime (re-scheduling after a `Pending` poll goes
+        /// back through the same path, see `Handle::schedule_task`).
+        pub(crate) fn spawn_when_idle<F>(me: &Arc<Self>, future: F, id: task::Id) -> JoinHandle<F::Output>
+        where
+            F: crate::future::Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let (handle, notified) = me.shared.owned.bind(
+                future,
+                me.clone(),
+                id,
+                true,
+                task::TaskPriority::Normal,
+            );
+
+            let meta = TaskMeta {
+                #[cfg(tokio_unstable)]
+                id,
+                _phantom: Default::default(),
+            };
+
+            me.task_hooks.spawn(&meta);
+
+            if let Some(notified) = notified {
+                me.shared.idle_tasks.lock().push_back(notified);
+                me.notify_parked_local();
+            }
+
+            handle
+        }
+
+        /// Spawns a future onto the thread pool with the given execution-order
+        /// priority hint.
+        ///
+        /// See [`task::TaskPriority`] for what each tier means; unlike
+        /// `spawn`, the placement and stealing logic this task goes through
+        /// (`Handle::schedule_local`, `Steal::steal_into`) consult the hint
+        /// instead of treating every task the same.
+        pub(crate) fn spawn_with_priority<F>(
+            me: &Arc<Self>,
+            future: F,
+            id: task::Id,
+            priority: task::TaskPriority,
+        ) -> JoinHandle<F::Output>
+        where
+            F: crate::future::Future + Send + 'static,
+            F::Output: Send + 'static,
+        {
+            let (handle, notified) =
+                me.shared
+                    .owned
+                    .bind(future, me.clone(), id, false, priority);
+
+            let meta = TaskMeta {
+                #[cfg(tokio_unstable)]
+                id,
+                _phantom: Default::default(),
+            };
+
+            me.task_hooks.spawn(&meta);
+
+            me.schedule_new_task(notified, &meta);
+
+            handle
+        }
     }
 }
 
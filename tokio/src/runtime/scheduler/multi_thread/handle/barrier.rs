@@ -0,0 +1,38 @@
+use super::Handle;
+
+use crate::loom::sync::Arc;
+use crate::runtime::scheduler::multi_thread::barrier::BarrierCallback;
+
+impl Handle {
+    /// Runs `f` once on every worker, at a safe point between tasks, and
+    /// resolves once every worker has done so.
+    ///
+    /// Useful for consistent global operations across the whole pool, e.g.
+    /// swapping a shared config or flushing per-worker caches, without
+    /// racing an individual worker mid-poll.
+    ///
+    /// Concurrent `barrier` calls are serialized: a second caller blocks
+    /// until the first one's callback has finished running on every worker.
+    pub(crate) async fn barrier<F>(&self, f: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let barrier_status = &self.shared.barrier_status;
+
+        // If a barrier is already in progress, wait our turn.
+        barrier_status.start_barrier_request(self).await;
+
+        let callback: BarrierCallback = Arc::new(Box::new(f) as Box<dyn Fn() + Send + Sync>);
+        *barrier_status.callback.lock() = Some(callback);
+
+        loop {
+            self.notify_all();
+            barrier_status.completed.notified().await;
+            if barrier_status.callback.lock().is_none() {
+                break;
+            }
+        }
+
+        barrier_status.end_barrier_request(self).await;
+    }
+}
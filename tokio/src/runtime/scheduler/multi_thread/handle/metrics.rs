@@ -17,6 +17,11 @@ impl Handle {
         self.shared.injection_queue_depth()
     }
 
+    #[cfg(tokio_unstable)]
+    pub(crate) fn max_live_tasks(&self) -> Option<usize> {
+        self.shared.config.max_live_tasks()
+    }
+
     cfg_unstable_metrics! {
         cfg_64bit_metrics! {
             pub(crate) fn spawned_tasks_count(&self) -> u64 {
@@ -47,8 +52,60 @@ impl Handle {
             self.shared.worker_local_queue_depth(worker)
         }
 
+        pub(crate) fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+            self.shared.worker_run_queue_remaining(worker)
+        }
+
         pub(crate) fn blocking_queue_depth(&self) -> usize {
             self.blocking_spawner.queue_depth()
         }
+
+        pub(crate) fn live_worker_thread_count(&self) -> usize {
+            self.shared.scheduler_metrics.live_worker_thread_count()
+        }
+
+        pub(crate) fn outstanding_block_in_place_count(&self) -> usize {
+            self.shared.scheduler_metrics.outstanding_block_in_place_count()
+        }
+
+        pub(crate) fn worker_global_queue_intervals(&self) -> Vec<u32> {
+            self.shared
+                .worker_metrics
+                .iter()
+                .map(|w| w.global_queue_interval())
+                .collect()
+        }
+
+        pub(crate) fn total_pending_tasks(&self) -> usize {
+            self.shared.total_pending_tasks()
+        }
+
+        pub(crate) fn peak_searching_workers(&self) -> usize {
+            self.shared.idle.peak_searching_workers()
+        }
+
+        pub(crate) fn reset_peak_searching_workers(&self) {
+            self.shared.idle.reset_peak_searching_workers()
+        }
+
+        cfg_64bit_metrics! {
+            /// Returns a `num_workers x num_workers` matrix of steal counts,
+            /// where `matrix[stealer][victim]` is the number of times
+            /// `stealer` has successfully stolen tasks from `victim`.
+            pub(crate) fn steal_matrix(&self) -> Vec<Vec<u64>> {
+                let num_workers = self.num_workers();
+
+                (0..num_workers)
+                    .map(|stealer| {
+                        (0..num_workers)
+                            .map(|victim| {
+                                self.shared.steal_matrix[stealer * num_workers + victim]
+                                    .load(std::sync::atomic::Ordering::Relaxed)
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+        }
     }
 }
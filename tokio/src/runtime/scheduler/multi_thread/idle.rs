@@ -4,7 +4,7 @@ use crate::loom::sync::atomic::AtomicUsize;
 use crate::runtime::scheduler::multi_thread::Shared;
 
 use std::fmt;
-use std::sync::atomic::Ordering::{self, SeqCst};
+use std::sync::atomic::Ordering::{self, Relaxed, SeqCst};
 
 pub(super) struct Idle {
     /// Tracks both the number of searching workers and the number of unparked
@@ -13,10 +13,32 @@ pub(super) struct Idle {
     /// Used as a fast-path to avoid acquiring the lock when needed.
     state: AtomicUsize,
 
+    /// The highest number of workers observed searching at the same time,
+    /// updated every time a worker transitions into the searching state.
+    /// Reveals peak steal-storm intensity: a value near `num_workers`
+    /// indicates the half-searcher guard in
+    /// `transition_worker_to_searching` isn't preventing as much wasteful
+    /// simultaneous searching as intended.
+    max_searching: AtomicUsize,
+
+    /// Index of the worker most recently observed waking from park while its
+    /// run queue already had tasks in it, e.g. because another thread
+    /// scheduled directly to it while it was asleep. `usize::MAX` means none
+    /// has been observed yet.
+    ///
+    /// Consulted by `steal_work` when `Config::steal_order` is
+    /// `StealOrder::LastParked`, on the theory that such a worker likely
+    /// hasn't caught up on its backlog yet and is a good steal target.
+    last_parked_with_backlog: AtomicUsize,
+
     /// Total number of workers.
     num_workers: usize,
 }
 
+/// Sentinel stored in `last_parked_with_backlog` meaning no worker has been
+/// observed waking from park with a backlog yet.
+const NO_LAST_PARKED_WITH_BACKLOG: usize = usize::MAX;
+
 /// Data synchronized by the scheduler mutex
 pub(super) struct Synced {
     /// Sleeping workers
@@ -36,6 +58,8 @@ impl Idle {
 
         let idle = Idle {
             state: AtomicUsize::new(init.into()),
+            max_searching: AtomicUsize::new(0),
+            last_parked_with_backlog: AtomicUsize::new(NO_LAST_PARKED_WITH_BACKLOG),
             num_workers,
         };
 
@@ -110,10 +134,27 @@ impl Idle {
         // It is possible for this routine to allow more than 50% of the workers
         // to search. That is OK. Limiting searchers is only an optimization to
         // prevent too much contention.
-        State::inc_num_searching(&self.state, SeqCst);
+        let prev = State::inc_num_searching(&self.state, SeqCst);
+        self.max_searching
+            .fetch_max(prev.num_searching() + 1, Relaxed);
         true
     }
 
+    cfg_unstable_metrics! {
+        /// Returns the highest number of workers observed searching at the
+        /// same time since the last reset.
+        pub(super) fn peak_searching_workers(&self) -> usize {
+            self.max_searching.load(Relaxed)
+        }
+
+        /// Resets the high-water mark returned by `peak_searching_workers`
+        /// back to the number of workers currently searching.
+        pub(super) fn reset_peak_searching_workers(&self) {
+            let num_searching = State::load(&self.state, SeqCst).num_searching();
+            self.max_searching.store(num_searching, Relaxed);
+        }
+    }
+
     /// A lightweight transition from searching -> running.
     ///
     /// Returns `true` if this is the final searching worker. The caller
@@ -122,6 +163,21 @@ impl Idle {
         State::dec_num_searching(&self.state)
     }
 
+    /// Records that `worker` just woke from parking and found tasks already
+    /// waiting in its run queue.
+    pub(super) fn record_parked_with_backlog(&self, worker: usize) {
+        self.last_parked_with_backlog.store(worker, Relaxed);
+    }
+
+    /// Returns the worker most recently recorded by `record_parked_with_backlog`,
+    /// if any.
+    pub(super) fn last_parked_with_backlog(&self) -> Option<usize> {
+        match self.last_parked_with_backlog.load(Relaxed) {
+            NO_LAST_PARKED_WITH_BACKLOG => None,
+            worker => Some(worker),
+        }
+    }
+
     /// Unpark a specific worker. This happens if tasks are submitted from
     /// within the worker's park routine.
     ///
@@ -150,6 +206,11 @@ impl Idle {
         lock.idle.sleepers.contains(&worker_id)
     }
 
+    /// Returns the number of workers that are not currently parked.
+    pub(super) fn num_unparked(&self) -> usize {
+        State::load(&self.state, SeqCst).num_unparked()
+    }
+
     fn notify_should_wakeup(&self) -> bool {
         let state = State(self.state.fetch_add(0, SeqCst));
         state.num_searching() == 0 && state.num_unparked() < self.num_workers
@@ -173,8 +234,9 @@ impl State {
         cell.fetch_add(num_searching | (1 << UNPARK_SHIFT), SeqCst);
     }
 
-    fn inc_num_searching(cell: &AtomicUsize, ordering: Ordering) {
-        cell.fetch_add(1, ordering);
+    /// Returns the state just before the increment.
+    fn inc_num_searching(cell: &AtomicUsize, ordering: Ordering) -> State {
+        State(cell.fetch_add(1, ordering))
     }
 
     /// Returns `true` if this is the final searching worker
@@ -238,3 +300,16 @@ fn test_state() {
     assert_eq!(10, state.num_unparked());
     assert_eq!(0, state.num_searching());
 }
+
+#[test]
+fn test_last_parked_with_backlog() {
+    let (idle, _synced) = Idle::new(4);
+
+    assert_eq!(None, idle.last_parked_with_backlog());
+
+    idle.record_parked_with_backlog(2);
+    assert_eq!(Some(2), idle.last_parked_with_backlog());
+
+    idle.record_parked_with_backlog(0);
+    assert_eq!(Some(0), idle.last_parked_with_backlog());
+}
@@ -36,6 +36,12 @@ cfg_not_taskdump! {
 }
 
 pub(crate) use worker::block_in_place;
+cfg_unstable! {
+    pub(crate) use worker::block_in_place_for;
+
+    mod barrier;
+    use barrier::BarrierStatus;
+}
 
 use crate::loom::sync::Arc;
 use crate::runtime::{
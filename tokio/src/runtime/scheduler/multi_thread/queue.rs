@@ -2,6 +2,7 @@
 
 use crate::loom::cell::UnsafeCell;
 use crate::loom::sync::Arc;
+use crate::runtime::config::OverflowPolicy;
 use crate::runtime::scheduler::multi_thread::{Overflow, Stats};
 use crate::runtime::task;
 
@@ -179,14 +180,17 @@ impl<T> Local<T> {
     /// Pushes a task to the back of the local queue, if there is not enough
     /// capacity in the queue, this triggers the overflow operation.
     ///
-    /// When the queue overflows, half of the current contents of the queue is
-    /// moved to the given Injection queue. This frees up capacity for more
-    /// tasks to be pushed into the local queue.
+    /// What happens on overflow is governed by `policy`; see
+    /// [`OverflowPolicy`] for the available choices. By default
+    /// (`OverflowPolicy::SpillOldest`), half of the current contents of the
+    /// queue is moved to the given Injection queue, freeing up capacity for
+    /// more tasks to be pushed into the local queue.
     pub(crate) fn push_back_or_overflow<O: Overflow<T>>(
         &mut self,
         mut task: task::Notified<T>,
         overflow: &O,
         stats: &mut Stats,
+        policy: OverflowPolicy,
     ) {
         let tail = loop {
             let head = self.inner.head.load(Acquire);
@@ -203,11 +207,24 @@ impl<T> Local<T> {
                 // push the task onto the inject queue
                 overflow.push(task);
                 return;
+            } else if policy == OverflowPolicy::Reject {
+                // Leave the local queue untouched and hand the task straight
+                // to the injection queue, signalling backpressure instead.
+                overflow.push(task);
+                stats.incr_overflow_reject_count();
+                return;
             } else {
-                // Push the current task and half of the queue into the
-                // inject queue.
-                match self.push_overflow(task, real, tail, overflow, stats) {
-                    Ok(_) => return,
+                // Move half of the queue into the inject queue. With
+                // `SpillNewest`, the task that triggered the overflow is
+                // kept local instead of moving along with that half.
+                let keep_local = policy == OverflowPolicy::SpillNewest;
+                match self.push_overflow(task, real, tail, overflow, stats, keep_local) {
+                    Ok(None) => return,
+                    // Kept local: place it at the now-freed tail slot.
+                    Ok(Some(v)) => {
+                        task = v;
+                        break tail;
+                    }
                     // Lost the race, try again
                     Err(v) => {
                         task = v;
@@ -246,6 +263,12 @@ impl<T> Local<T> {
     /// Once `push_overflow` is done, a notification is sent out, so if other
     /// workers "missed" some of the tasks during a steal, they will get
     /// another opportunity.
+    ///
+    /// If `keep_local` is `true`, `task` is not moved into the inject queue
+    /// along with the evicted half; it is returned as `Ok(Some(task))` so the
+    /// caller can place it into the capacity this call just freed up
+    /// instead. If `keep_local` is `false`, `task` moves with the rest of
+    /// the batch and this returns `Ok(None)`.
     #[inline(never)]
     fn push_overflow<O: Overflow<T>>(
         &mut self,
@@ -254,7 +277,8 @@ impl<T> Local<T> {
         tail: UnsignedShort,
         overflow: &O,
         stats: &mut Stats,
-    ) -> Result<(), task::Notified<T>> {
+        keep_local: bool,
+    ) -> Result<Option<task::Notified<T>>, task::Notified<T>> {
         /// How many elements are we taking from the local queue.
         ///
         /// This is one less than the number of tasks pushed to the inject
@@ -335,12 +359,16 @@ impl<T> Local<T> {
             head: head as UnsignedLong,
             i: 0,
         };
-        overflow.push_batch(batch_iter.chain(std::iter::once(task)));
-
         // Add 1 to factor in the task currently being scheduled.
         stats.incr_overflow_count();
 
-        Ok(())
+        if keep_local {
+            overflow.push_batch(batch_iter);
+            Ok(Some(task))
+        } else {
+            overflow.push_batch(batch_iter.chain(std::iter::once(task)));
+            Ok(None)
+        }
     }
 
     /// Pops a task from the local queue.
@@ -390,11 +418,13 @@ impl<T> Steal<T> {
         self.0.is_empty()
     }
 
-    /// Steals half the tasks from self and place them into `dst`.
+    /// Steals half the tasks from self and place them into `dst`, or, when
+    /// `max` is `Some`, at most `max` of them.
     pub(crate) fn steal_into(
         &self,
         dst: &mut Local<T>,
         dst_stats: &mut Stats,
+        max: Option<usize>,
     ) -> Option<task::Notified<T>> {
         // Safety: the caller is the only thread that mutates `dst.tail` and
         // holds a mutable reference.
@@ -413,13 +443,19 @@ impl<T> Steal<T> {
 
         // Steal the tasks into `dst`'s buffer. This does not yet expose the
         // tasks in `dst`.
-        let mut n = self.steal_into2(dst, dst_tail);
+        let mut n = self.steal_into2(dst, dst_tail, max);
 
         if n == 0 {
             // No tasks were stolen
             return None;
         }
 
+        // The stolen range isn't exposed to `dst`'s owner or to other
+        // stealers yet (that happens below, via `dst.inner.tail.store`), so
+        // it's still exclusively ours to rearrange by priority.
+        #[cfg(tokio_unstable)]
+        reorder_stolen_by_priority(dst, dst_tail, n);
+
         dst_stats.incr_steal_count(n as u16);
         dst_stats.incr_steal_operations();
 
@@ -446,7 +482,12 @@ impl<T> Steal<T> {
 
     // Steal tasks from `self`, placing them into `dst`. Returns the number of
     // tasks that were stolen.
-    fn steal_into2(&self, dst: &mut Local<T>, dst_tail: UnsignedShort) -> UnsignedShort {
+    fn steal_into2(
+        &self,
+        dst: &mut Local<T>,
+        dst_tail: UnsignedShort,
+        max: Option<usize>,
+    ) -> UnsignedShort {
         let mut prev_packed = self.0.head.load(Acquire);
         let mut next_packed;
 
@@ -460,9 +501,14 @@ impl<T> Steal<T> {
                 return 0;
             }
 
-            // Number of available tasks to steal
+            // Number of available tasks to steal. Defaults to roughly half
+            // the queue; `Config::steal_batch` caps it further.
             let n = src_tail.wrapping_sub(src_head_real);
             let n = n - n / 2;
+            let n = match max {
+                Some(max) => n.min(max as UnsignedShort),
+                None => n,
+            };
 
             if n == 0 {
                 // No tasks available to steal
@@ -511,6 +557,10 @@ impl<T> Steal<T> {
             // safety: We acquired the task with the atomic exchange above.
             let task = self.0.buffer[src_idx].with(|ptr| unsafe { ptr::read((*ptr).as_ptr()) });
 
+            #[cfg(tokio_unstable)]
+            task.incr_migration_count();
+            task.set_stolen();
+
             // Write the task to the new slot
             //
             // safety: `dst` queue is empty and we are the only producer to
@@ -546,12 +596,62 @@ impl<T> Steal<T> {
     }
 }
 
-cfg_unstable_metrics! {
-    impl<T> Steal<T> {
-        pub(crate) fn len(&self) -> usize {
-            self.0.len() as _
+/// Rearranges the `n` tasks `steal_into2` just wrote into `dst`'s buffer at
+/// `dst_tail..dst_tail + n`, so that the single highest-priority task ends
+/// up last (where `steal_into` hands it straight to the stealing thread to
+/// run immediately) and the rest are ordered highest-to-lowest starting
+/// from `dst_tail` (so `dst`'s own front-first consumption still prefers
+/// higher priority).
+///
+/// This range was just written by `steal_into2` and hasn't been exposed to
+/// `dst`'s owner or to other stealers yet (that happens after this call
+/// returns, via `dst.inner.tail.store`), so the calling thread has
+/// exclusive access to it.
+///
+/// This is a coarse, best-effort reordering, not a real priority queue: it
+/// only ever reorders within a single steal batch.
+#[cfg(tokio_unstable)]
+fn reorder_stolen_by_priority<T>(dst: &mut Local<T>, dst_tail: UnsignedShort, n: UnsignedShort) {
+    use crate::runtime::task::TaskPriority;
+
+    fn rank(priority: TaskPriority) -> u8 {
+        match priority {
+            TaskPriority::High => 0,
+            TaskPriority::Normal => 1,
+            TaskPriority::Low => 2,
         }
     }
+
+    let mut tasks: Vec<task::Notified<T>> = (0..n)
+        .map(|i| {
+            let idx = dst_tail.wrapping_add(i) as usize & MASK;
+            // safety: this slot was just written by `steal_into2` and isn't
+            // exposed to any other thread yet.
+            dst.inner.buffer[idx].with(|ptr| unsafe { ptr::read((*ptr).as_ptr()) })
+        })
+        .collect();
+
+    let best = tasks
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, task)| rank(task.priority()))
+        .map(|(i, _)| i)
+        .unwrap();
+    let best = tasks.remove(best);
+    tasks.sort_by_key(|task| rank(task.priority()));
+    tasks.push(best);
+
+    for (i, task) in tasks.into_iter().enumerate() {
+        let idx = dst_tail.wrapping_add(i as UnsignedShort) as usize & MASK;
+        // safety: same exclusive access as above.
+        dst.inner.buffer[idx].with_mut(|ptr| unsafe { ptr::write((*ptr).as_mut_ptr(), task) });
+    }
+}
+
+impl<T> Steal<T> {
+    pub(crate) fn len(&self) -> usize {
+        self.0.len() as _
+    }
 }
 
 impl<T> Clone for Steal<T> {
@@ -1,4 +1,6 @@
 use crate::runtime::{Config, MetricsBatch, WorkerMetrics};
+#[cfg(tokio_unstable)]
+use crate::runtime::WorkerMetricsDelta;
 
 use std::time::{Duration, Instant};
 
@@ -24,6 +26,19 @@ pub(crate) struct Stats {
     /// Tracked in nanoseconds, stored as a `f64` since that is what we use with
     /// the EWMA calculations
     task_poll_time_ewma: f64,
+
+    /// Number of consecutive passes of the run loop in which this worker has
+    /// found no work. Reset whenever the worker runs a task, incremented whenever a
+    /// steal attempt comes up empty.
+    consecutive_idle: u64,
+
+    /// The delta computed by the most recent `submit`, handed to
+    /// `Builder::metrics_sink` by the caller. Kept here rather than
+    /// threading `Config` into `submit` itself, since only the caller
+    /// (which already has `Config` in scope) knows whether a sink is
+    /// registered.
+    #[cfg(tokio_unstable)]
+    last_delta: WorkerMetricsDelta,
 }
 
 /// How to weigh each individual poll time, value is plucked from thin air.
@@ -49,6 +64,9 @@ impl Stats {
             processing_scheduled_tasks_started_at: Instant::now(),
             tasks_polled_in_batch: 0,
             task_poll_time_ewma,
+            consecutive_idle: 0,
+            #[cfg(tokio_unstable)]
+            last_delta: WorkerMetricsDelta::default(),
         }
     }
 
@@ -66,8 +84,38 @@ impl Stats {
         tasks_per_interval.clamp(2, MAX_TASKS_POLLED_PER_GLOBAL_QUEUE_INTERVAL)
     }
 
-    pub(crate) fn submit(&mut self, to: &WorkerMetrics) {
+    pub(crate) fn submit(
+        &mut self,
+        to: &WorkerMetrics,
+        global_queue_interval: u32,
+        run_queue_remaining: usize,
+    ) {
+        #[cfg(tokio_unstable)]
+        {
+            self.last_delta = self.batch.submit(to, self.task_poll_time_ewma as u64);
+        }
+        #[cfg(not(tokio_unstable))]
         self.batch.submit(to, self.task_poll_time_ewma as u64);
+
+        to.set_consecutive_idle(self.consecutive_idle);
+        to.set_global_queue_interval(global_queue_interval);
+        to.set_run_queue_remaining(run_queue_remaining);
+    }
+
+    /// The delta computed by the most recent `submit`, i.e. how much each
+    /// counter grew since the previous one. Read by the caller right after
+    /// `submit` to feed `Builder::metrics_sink`.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn last_delta(&self) -> WorkerMetricsDelta {
+        self.last_delta
+    }
+
+    pub(crate) fn reset_consecutive_idle(&mut self) {
+        self.consecutive_idle = 0;
+    }
+
+    pub(crate) fn incr_consecutive_idle(&mut self) {
+        self.consecutive_idle += 1;
     }
 
     pub(crate) fn about_to_park(&mut self) {
@@ -82,6 +130,14 @@ impl Stats {
         self.batch.inc_local_schedule_count();
     }
 
+    pub(crate) fn incr_local_queue_pull_count(&mut self) {
+        self.batch.incr_local_queue_pull_count();
+    }
+
+    pub(crate) fn incr_global_queue_pull_count(&mut self) {
+        self.batch.incr_global_queue_pull_count();
+    }
+
     pub(crate) fn start_processing_scheduled_tasks(&mut self) {
         self.batch.start_processing_scheduled_tasks();
 
@@ -131,7 +187,55 @@ impl Stats {
         self.batch.incr_steal_operations();
     }
 
+    pub(crate) fn incr_steal_first_try_success(&mut self) {
+        self.batch.incr_steal_first_try_success();
+    }
+
+    pub(crate) fn incr_steal_back_count(&mut self, by: u16) {
+        self.batch.incr_steal_back_count(by);
+    }
+
+    pub(crate) fn incr_steal_global_fallback_count(&mut self) {
+        self.batch.incr_steal_global_fallback_count();
+    }
+
+    pub(crate) fn incr_steal_search_denied_count(&mut self) {
+        self.batch.incr_steal_search_denied_count();
+    }
+
     pub(crate) fn incr_overflow_count(&mut self) {
         self.batch.incr_overflow_count();
     }
+
+    pub(crate) fn incr_overflow_reject_count(&mut self) {
+        self.batch.incr_overflow_reject_count();
+    }
+
+    pub(crate) fn incr_notify_no_work_count(&mut self) {
+        self.batch.incr_notify_no_work_count();
+    }
+
+    pub(crate) fn incr_lifo_eviction_count(&mut self) {
+        self.batch.incr_lifo_eviction_count();
+    }
+
+    pub(crate) fn incr_lifo_budget_demotion_count(&mut self) {
+        self.batch.incr_lifo_budget_demotion_count();
+    }
+
+    pub(crate) fn record_lifo_chain_length(&mut self, length: usize) {
+        self.batch.record_lifo_chain_length(length);
+    }
+
+    pub(crate) fn incr_completed_poll_count(&mut self) {
+        self.batch.incr_completed_poll_count();
+    }
+
+    pub(crate) fn incr_pending_poll_count(&mut self) {
+        self.batch.incr_pending_poll_count();
+    }
+
+    pub(crate) fn incr_completed_tasks(&mut self) {
+        self.batch.incr_completed_tasks();
+    }
 }
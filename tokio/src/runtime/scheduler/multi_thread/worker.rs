@@ -24,8 +24,9 @@
 //!     shutdown. These calls will push their core to `Shared::shutdown_cores`,
 //!     and the last thread to push its core will finish the shutdown procedure.
 //!
-//!  6. The local run queue of each core is emptied, then the inject queue is
-//!     emptied.
+//!  6. The local run queue of each core is emptied (which also drains that
+//!     worker's fast-path mailbox and per-worker inject queue, see
+//!     `Core::drain_local_queue`), then the global inject queue is emptied.
 //!
 //! At this point, shutdown has completed. It is not possible for any of the
 //! collections to contain any tasks at this point, as each collection was
@@ -61,19 +62,35 @@ use crate::runtime;
 use crate::runtime::scheduler::multi_thread::{
     idle, queue, Counters, Handle, Idle, Overflow, Parker, Stats, TraceStatus, Unparker,
 };
+#[cfg(tokio_unstable)]
+use crate::runtime::scheduler::multi_thread::BarrierStatus;
+use crate::runtime::config::StartupDistribution;
 use crate::runtime::scheduler::{inject, Defer, Lock};
 use crate::runtime::task::{OwnedTasks, TaskHarnessScheduleHooks};
+#[cfg(tokio_unstable)]
+use crate::runtime::task::TaskPriority;
+#[cfg(tokio_unstable)]
+use crate::runtime::Placement;
 use crate::runtime::{
-    blocking, coop, driver, scheduler, task, Config, SchedulerMetrics, WorkerMetrics,
+    blocking, coop, driver, scheduler, task, Config, DeadlockDetectorConfig, SchedulerMetrics,
+    StealOrder, TaskMeta, WorkerMetrics,
 };
 use crate::runtime::{context, TaskHooks};
+use crate::loom::sync::atomic::AtomicBool;
+use crate::loom::sync::atomic::AtomicUsize;
 use crate::util::atomic_cell::AtomicCell;
 use crate::util::rand::{FastRand, RngSeedGenerator};
+#[cfg(all(tokio_unstable, target_has_atomic = "64"))]
+use crate::util::metric_atomics::MetricAtomicU64;
+
+use std::sync::atomic::Ordering::Relaxed;
 
 use std::cell::RefCell;
+#[cfg(tokio_unstable)]
+use std::collections::VecDeque;
 use std::task::Waker;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 mod metrics;
 
@@ -85,6 +102,10 @@ cfg_not_taskdump! {
     mod taskdump_mock;
 }
 
+cfg_unstable! {
+    mod barrier;
+}
+
 /// A scheduler worker
 pub(super) struct Worker {
     /// Reference to scheduler's handle
@@ -99,6 +120,12 @@ pub(super) struct Worker {
 
 /// Core data
 struct Core {
+    /// This core's index into `Shared::remotes`. Kept alongside the rest of
+    /// the core's state so it stays available even after a core has been
+    /// handed off between threads or collected into `Shared::shutdown_cores`
+    /// in whatever order the workers happened to reach shutdown.
+    index: usize,
+
     /// Used to schedule bookkeeping tasks every so often.
     tick: u32,
 
@@ -126,6 +153,10 @@ struct Core {
     /// True if the scheduler is being traced
     is_traced: bool,
 
+    /// True if a `Handle::barrier` request is pending for this worker.
+    #[cfg(tokio_unstable)]
+    is_barrier_requested: bool,
+
     /// Parker
     ///
     /// Stored in an `Option` as the parker is added / removed to make the
@@ -140,6 +171,10 @@ struct Core {
 
     /// Fast random number generator.
     rand: FastRand,
+
+    /// Next victim index to start scanning from when `Config::steal_order`
+    /// is `StealOrder::RoundRobin`. Advanced on every steal attempt.
+    next_steal_index: usize,
 }
 
 /// State shared across all workers
@@ -154,11 +189,17 @@ pub(crate) struct Shared {
     pub(super) inject: inject::Shared<Arc<Handle>>,
 
     /// Coordinates idle workers
-    idle: Idle,
+    pub(super) idle: Idle,
 
     /// Collection of all active tasks spawned onto this executor.
     pub(crate) owned: OwnedTasks<Arc<Handle>>,
 
+    /// Tasks spawned via `Handle::spawn_when_idle`. A worker only checks
+    /// this collection after `steal_work` comes up empty, right before it
+    /// would otherwise park, and never targets it with work-stealing.
+    #[cfg(tokio_unstable)]
+    pub(super) idle_tasks: Mutex<VecDeque<Notified>>,
+
     /// Data synchronized by the scheduler mutex
     pub(super) synced: Mutex<Synced>,
 
@@ -172,14 +213,77 @@ pub(crate) struct Shared {
     /// The number of cores that have observed the trace signal.
     pub(super) trace_status: TraceStatus,
 
+    /// Coordinates `Handle::barrier` requests across every worker.
+    #[cfg(tokio_unstable)]
+    pub(super) barrier_status: BarrierStatus,
+
     /// Scheduler configuration options
-    config: Config,
+    pub(super) config: Config,
+
+    /// Whether the LIFO slot is enabled, dynamically overriding
+    /// `config.disable_lifo_slot` at runtime. Initialized from the static
+    /// config and flipped by `Handle::set_lifo_enabled_all`.
+    ///
+    /// Workers only pick this up when they next reset their own
+    /// `lifo_enabled` flag (via `reset_lifo_enabled`, e.g. after running a
+    /// task or reacquiring a core), so there is a brief window after a
+    /// flip where some workers still observe the old setting.
+    pub(super) lifo_enabled_all: AtomicBool,
+
+    /// Whether steal-back is enabled, dynamically overriding
+    /// `config.steal_back` at runtime. Initialized from the static config
+    /// and flipped by `Handle::update_config`.
+    pub(super) steal_back_enabled: AtomicBool,
+
+    /// Whether workers are currently allowed to pull tasks from the
+    /// injection queue, flipped by `Handle::pause_inject`/`resume_inject`.
+    /// Tasks submitted remotely while paused simply accumulate in the
+    /// injection queue instead of being lost; workers keep running their
+    /// local queues as normal and only stop reaching into the shared queue.
+    pub(super) inject_paused: AtomicBool,
+
+    /// Set by any push/overflow path that can make a task visible to
+    /// stealers, and consulted by the last searching worker's final recheck
+    /// in `Core::transition_to_parked` when `Config::cached_idle_recheck` is
+    /// set, instead of that recheck scanning every remote and the injection
+    /// queue. See `Handle::notify_if_work_pending`.
+    #[cfg(tokio_unstable)]
+    pub(super) has_work_hint: AtomicBool,
 
     /// Collects metrics from the runtime.
     pub(super) scheduler_metrics: SchedulerMetrics,
 
     pub(super) worker_metrics: Box<[WorkerMetrics]>,
 
+    /// Flattened `num_workers x num_workers` matrix of steal counts.
+    /// `steal_matrix[stealer * num_workers + victim]` counts the number of
+    /// times `stealer` has successfully stolen tasks from `victim`.
+    #[cfg(all(tokio_unstable, target_has_atomic = "64"))]
+    pub(super) steal_matrix: Box<[MetricAtomicU64]>,
+
+    /// The seed used to initialize each worker's `Core::rand`, captured at
+    /// creation time so a failure can be reproduced later by replaying the
+    /// same seeds via `Builder::rng_seed`.
+    #[cfg(tokio_unstable)]
+    pub(super) worker_rng_seeds: Box<[u64]>,
+
+    /// Human-readable label for each worker, indexed by worker index. Purely
+    /// diagnostic; see `Config::worker_labels`.
+    #[cfg(tokio_unstable)]
+    pub(super) worker_labels: Box<[Box<str>]>,
+
+    /// How many more freshly spawned tasks should be round-robined directly
+    /// to a worker instead of going through the default placement, when
+    /// `config.startup_distribution` is `RoundRobinLocal`. Counts down from
+    /// `STARTUP_ROUND_ROBIN_TASKS_PER_WORKER * remotes.len()` to zero; once
+    /// it reaches zero, newly spawned tasks fall back to the default
+    /// placement for the rest of the runtime's life.
+    startup_tasks_remaining: AtomicUsize,
+
+    /// The worker index the next round-robined startup task should be sent
+    /// to, cycling through `0..remotes.len()`.
+    startup_rr_index: AtomicUsize,
+
     /// Only held to trigger some code on drop. This is used to get internal
     /// runtime metrics that can be useful when doing performance
     /// investigations. This does nothing (empty struct, no drop impl) unless
@@ -194,6 +298,12 @@ pub(crate) struct Synced {
 
     /// Synchronized state for `Inject`.
     pub(crate) inject: inject::Synced,
+
+    /// When the scheduler was closed, set once by `Shared::close`. Read by
+    /// `Core::maintenance` to compute how long each worker took to observe
+    /// the closed injection queue, for `WorkerMetrics::shutdown_observed_after`.
+    #[cfg(tokio_unstable)]
+    pub(super) shutdown_started_at: Option<Instant>,
 }
 
 /// Used to communicate with a worker from other threads.
@@ -203,6 +313,26 @@ struct Remote {
 
     /// Unparks the associated worker thread
     unpark: Unparker,
+
+    /// Best-effort single-slot mailbox for `Handle::notify_fast`.
+    ///
+    /// This is a lock-free bypass of `synced`/`inject` meant for
+    /// latency-sensitive remote wakeups, e.g. from an I/O completion thread.
+    /// `Core::next_local_task` checks it before the run queue. It is sized
+    /// for a single producer per worker: if a task is already waiting here
+    /// when another one arrives, the new one falls back to the inject queue
+    /// instead of overwriting it, so nothing is dropped.
+    fast_slot: AtomicCell<Notified>,
+
+    /// Small per-worker injection queue for `Handle::inject_to_worker`.
+    ///
+    /// Meant for off-runtime producers that already know which worker they
+    /// want to target, to spread injection load across workers instead of
+    /// funneling everything through the single global inject queue.
+    /// `Core::next_local_task` drains it before falling through to the
+    /// global inject queue, and idle peers can steal from it the same way
+    /// they steal from `steal`.
+    inject: inject::Inject<Arc<Handle>>,
 }
 
 /// Thread-local context
@@ -232,11 +362,20 @@ type Task = task::Task<Arc<Handle>>;
 /// A notified task handle
 type Notified = task::Notified<Arc<Handle>>;
 
-/// Value picked out of thin-air. Running the LIFO slot a handful of times
-/// seems sufficient to benefit from locality. More than 3 times probably is
-/// overweighing. The value can be tuned in the future with data that shows
-/// improvements.
-const MAX_LIFO_POLLS_PER_TICK: usize = 3;
+/// With `StartupDistribution::RoundRobinLocal`, this many tasks per worker
+/// are round-robined directly at spawn time before falling back to the
+/// default placement. Picked to comfortably cover a typical startup burst
+/// (e.g. one task per connection handler) without round-robining for the
+/// runtime's entire lifetime.
+const STARTUP_ROUND_ROBIN_TASKS_PER_WORKER: usize = 8;
+
+/// Suggested budget passed to `Config::on_all_idle`. Purely advisory: the
+/// worker parks normally regardless of how long the embedder's callback
+/// actually takes, so this is just a hint at how long the runtime expects
+/// to otherwise sit idle before the next scheduled timer or maintenance
+/// tick might wake it.
+#[cfg(tokio_unstable)]
+const ON_ALL_IDLE_SUGGESTED_BUDGET: std::time::Duration = std::time::Duration::from_millis(10);
 
 pub(super) fn create(
     size: usize,
@@ -249,38 +388,83 @@ pub(super) fn create(
     let mut cores = Vec::with_capacity(size);
     let mut remotes = Vec::with_capacity(size);
     let mut worker_metrics = Vec::with_capacity(size);
+    #[cfg(tokio_unstable)]
+    let mut worker_rng_seeds = Vec::with_capacity(size);
+    #[cfg(tokio_unstable)]
+    let mut worker_labels = Vec::with_capacity(size);
+
+    #[cfg(tokio_unstable)]
+    let lifo_slot_disabled =
+        config.disable_lifo_slot || config.strict_fifo || config.max_lifo_polls == 0;
+    #[cfg(not(tokio_unstable))]
+    let lifo_slot_disabled = config.disable_lifo_slot || config.max_lifo_polls == 0;
 
     // Create the local queues
-    for _ in 0..size {
+    #[cfg_attr(not(tokio_unstable), allow(unused_variables))]
+    for i in 0..size {
         let (steal, run_queue) = queue::local();
 
         let park = park.clone();
         let unpark = park.unpark();
         let metrics = WorkerMetrics::from_config(&config);
         let stats = Stats::new(&metrics);
+        let seed = config.seed_generator.next_seed();
+
+        #[cfg(tokio_unstable)]
+        worker_rng_seeds.push(seed.as_u64());
+
+        #[cfg(tokio_unstable)]
+        worker_labels.push(
+            config
+                .worker_labels
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| format!("worker-{i}"))
+                .into_boxed_str(),
+        );
 
         cores.push(Box::new(Core {
+            index: i,
             tick: 0,
             lifo_slot: None,
-            lifo_enabled: !config.disable_lifo_slot,
+            lifo_enabled: !lifo_slot_disabled,
             run_queue,
             is_searching: false,
             is_shutdown: false,
             is_traced: false,
+            #[cfg(tokio_unstable)]
+            is_barrier_requested: false,
             park: Some(park),
             global_queue_interval: stats.tuned_global_queue_interval(&config),
             stats,
-            rand: FastRand::from_seed(config.seed_generator.next_seed()),
+            rand: FastRand::from_seed(seed),
+            next_steal_index: 0,
         }));
 
-        remotes.push(Remote { steal, unpark });
+        remotes.push(Remote {
+            steal,
+            unpark,
+            fast_slot: AtomicCell::new(None),
+            inject: inject::Inject::new(),
+        });
         worker_metrics.push(metrics);
     }
 
     let (idle, idle_synced) = Idle::new(size);
     let (inject, inject_synced) = inject::Shared::new();
 
+    let maintenance_interval = config.maintenance_interval;
+    let deadlock_detector = config.deadlock_detector.clone();
+    let lifo_enabled_all = AtomicBool::new(!lifo_slot_disabled);
+    let steal_back_enabled = AtomicBool::new(config.steal_back);
+    let inject_paused = AtomicBool::new(false);
     let remotes_len = remotes.len();
+    let startup_tasks_remaining = AtomicUsize::new(match config.startup_distribution {
+        StartupDistribution::InjectAndSteal => 0,
+        StartupDistribution::RoundRobinLocal => {
+            STARTUP_ROUND_ROBIN_TASKS_PER_WORKER.saturating_mul(remotes_len)
+        }
+    });
     let handle = Arc::new(Handle {
         task_hooks: TaskHooks {
             task_spawn_callback: config.before_spawn.clone(),
@@ -290,16 +474,35 @@ pub(super) fn create(
             remotes: remotes.into_boxed_slice(),
             inject,
             idle,
-            owned: OwnedTasks::new(size),
+            owned: OwnedTasks::new(size, config.max_live_tasks()),
+            #[cfg(tokio_unstable)]
+            idle_tasks: Mutex::new(VecDeque::new()),
             synced: Mutex::new(Synced {
                 idle: idle_synced,
                 inject: inject_synced,
+                #[cfg(tokio_unstable)]
+                shutdown_started_at: None,
             }),
             shutdown_cores: Mutex::new(vec![]),
             trace_status: TraceStatus::new(remotes_len),
+            #[cfg(tokio_unstable)]
+            barrier_status: BarrierStatus::new(remotes_len),
             config,
+            lifo_enabled_all,
+            steal_back_enabled,
+            inject_paused,
+            #[cfg(tokio_unstable)]
+            has_work_hint: AtomicBool::new(false),
             scheduler_metrics: SchedulerMetrics::new(),
             worker_metrics: worker_metrics.into_boxed_slice(),
+            #[cfg(all(tokio_unstable, target_has_atomic = "64"))]
+            steal_matrix: (0..size * size).map(|_| MetricAtomicU64::new(0)).collect(),
+            #[cfg(tokio_unstable)]
+            worker_rng_seeds: worker_rng_seeds.into_boxed_slice(),
+            #[cfg(tokio_unstable)]
+            worker_labels: worker_labels.into_boxed_slice(),
+            startup_tasks_remaining,
+            startup_rr_index: AtomicUsize::new(0),
             _counters: Counters,
         },
         driver: driver_handle,
@@ -307,6 +510,14 @@ pub(super) fn create(
         seed_generator,
     });
 
+    if let Some(interval) = maintenance_interval {
+        spawn_maintenance_monitor(&handle, interval);
+    }
+
+    if let Some(deadlock_detector) = deadlock_detector {
+        spawn_deadlock_detector(&handle, deadlock_detector);
+    }
+
     let mut launch = Launch(vec![]);
 
     for (index, core) in cores.drain(..).enumerate() {
@@ -320,8 +531,109 @@ pub(super) fn create(
     (handle, launch)
 }
 
+/// Spawns a dedicated thread that periodically wakes every worker via the
+/// unpark path, so that maintenance is reconsidered at a wall-clock cadence
+/// rather than solely whenever a worker's tick counter crosses
+/// `event_interval`.
+///
+/// Shutdown is driven explicitly by `Handle::shutdown` closing the
+/// injection queue, not by this thread's `Arc<Handle>` clone being dropped,
+/// so the monitor thread can hold a strong reference: it simply checks the
+/// injection queue's closed flag on each wake and exits once shutdown has
+/// been observed, instead of relying on a weak reference (which loom's
+/// `Arc` doesn't support).
+fn spawn_maintenance_monitor(handle: &Arc<Handle>, interval: Duration) {
+    let handle = handle.clone();
+
+    let res = thread::Builder::new()
+        .name("tokio-maintenance-timer".into())
+        .spawn(move || loop {
+            thread::sleep(interval);
+
+            let synced = handle.shared.synced.lock();
+            if handle.shared.inject.is_closed(&synced.inject) {
+                return;
+            }
+            drop(synced);
+
+            handle.notify_all();
+        });
+
+    // If spawning the monitor thread fails (e.g. the OS is out of
+    // resources), maintenance simply continues to run on the usual
+    // tick-based schedule.
+    drop(res);
+}
+
+/// Spawns a dedicated thread that periodically checks for the scheduler's
+/// classic lost-wakeup signature: every worker parked while the injection
+/// queue or some worker's local queue still holds a runnable task.
+///
+/// The check is purely diagnostic. It never touches scheduler state; it only
+/// invokes `deadlock_detector.callback` on the monitor thread when the
+/// signature is observed.
+///
+/// Shutdown is driven explicitly by `Handle::shutdown` closing the
+/// injection queue, not by this thread's `Arc<Handle>` clone being dropped,
+/// so the monitor thread can hold a strong reference: it simply checks the
+/// injection queue's closed flag on each wake and exits once shutdown has
+/// been observed, instead of relying on a weak reference (which loom's
+/// `Arc` doesn't support).
+fn spawn_deadlock_detector(handle: &Arc<Handle>, deadlock_detector: DeadlockDetectorConfig) {
+    let handle = handle.clone();
+
+    let res = thread::Builder::new()
+        .name("tokio-deadlock-detector".into())
+        .spawn(move || loop {
+            thread::sleep(deadlock_detector.interval);
+
+            let synced = handle.shared.synced.lock();
+            let is_closed = handle.shared.inject.is_closed(&synced.inject);
+            drop(synced);
+            if is_closed {
+                return;
+            }
+
+            let shared = &handle.shared;
+
+            if shared.idle.num_unparked() == 0
+                && (!shared.inject.is_empty()
+                    || shared.remotes.iter().any(|remote| !remote.steal.is_empty()))
+            {
+                (deadlock_detector.callback)();
+            }
+        });
+
+    // If spawning the monitor thread fails (e.g. the OS is out of
+    // resources), the runtime simply runs without this diagnostic.
+    drop(res);
+}
+
 #[track_caller]
 pub(crate) fn block_in_place<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    block_in_place_impl(None, f)
+}
+
+/// Like [`block_in_place`], but skips the core handoff entirely when
+/// `hint_duration` is below `Config::block_in_place_threshold`, running `f`
+/// inline instead. See `Handle::block_in_place_for` for the full
+/// documentation.
+///
+/// Only reachable when `tokio_unstable` is set; otherwise dead code.
+#[cfg_attr(not(tokio_unstable), allow(dead_code))]
+#[track_caller]
+pub(crate) fn block_in_place_for<F, R>(hint_duration: Duration, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    block_in_place_impl(Some(hint_duration), f)
+}
+
+#[track_caller]
+fn block_in_place_impl<F, R>(hint_duration: Option<Duration>, f: F) -> R
 where
     F: FnOnce() -> R,
 {
@@ -336,7 +648,20 @@ where
             with_current(|maybe_cx| {
                 if let Some(cx) = maybe_cx {
                     if self.take_core {
-                        let core = cx.worker.core.take();
+                        let shared = &cx.worker.handle.shared;
+
+                        // Unless configured to always take priority, leave the
+                        // core with the handoff thread if a worker is already
+                        // parked and could use it, rather than immediately
+                        // racing the handoff thread for it.
+                        let yield_to_parked = !shared.config.block_in_place_reacquire_priority
+                            && shared.idle.num_unparked() < shared.remotes.len();
+
+                        let core = if yield_to_parked {
+                            None
+                        } else {
+                            cx.worker.core.take()
+                        };
 
                         if core.is_some() {
                             cx.worker.handle.shared.worker_metrics[cx.worker.index]
@@ -346,6 +671,12 @@ where
                         let mut cx_core = cx.core.borrow_mut();
                         assert!(cx_core.is_none());
                         *cx_core = core;
+
+                        cx.worker
+                            .handle
+                            .shared
+                            .scheduler_metrics
+                            .dec_outstanding_block_in_place_count();
                     }
 
                     // Reset the task budget as we are re-entering the
@@ -409,17 +740,65 @@ where
             None => return Ok(()),
         };
 
+        // If the blocking section is hinted to be shorter than the
+        // configured threshold, skip the handoff and run it inline instead,
+        // pausing the rest of this worker's queued tasks for the duration
+        // of the call rather than spawning a new worker thread to take over
+        // the core.
+        if let Some(hint_duration) = hint_duration {
+            if hint_duration < cx.worker.handle.shared.config.block_in_place_threshold {
+                *cx.core.borrow_mut() = Some(core);
+                return Ok(());
+            }
+        }
+
+        // If we are already at the configured cap on worker-running
+        // threads, don't spawn another one to take over this core. Run the
+        // blocking closure inline instead, degrading concurrency on this
+        // worker rather than letting the thread count grow unbounded.
+        if let Some(max) = cx.worker.handle.shared.config.max_worker_threads {
+            if cx.worker.handle.shared.scheduler_metrics.live_worker_thread_count() >= max {
+                *cx.core.borrow_mut() = Some(core);
+                return Ok(());
+            }
+        }
+
+        // Likewise, if we are already at the configured cap on outstanding
+        // `block_in_place` handoffs specifically, run inline instead of
+        // adding another one.
+        if let Some(max) = cx.worker.handle.shared.config.max_concurrent_block_in_place {
+            if cx
+                .worker
+                .handle
+                .shared
+                .scheduler_metrics
+                .outstanding_block_in_place_count()
+                >= max
+            {
+                *cx.core.borrow_mut() = Some(core);
+                return Ok(());
+            }
+        }
+
         // If we heavily call `spawn_blocking`, there might be no available thread to
         // run this core. Except for the task in the lifo_slot, all tasks can be
         // stolen, so we move the task out of the lifo_slot to the run_queue.
         if let Some(task) = core.lifo_slot.take() {
+            let policy = cx.worker.handle.shared.config.overflow_policy;
             core.run_queue
-                .push_back_or_overflow(task, &*cx.worker.handle, &mut core.stats);
+                .push_back_or_overflow(task, &*cx.worker.handle, &mut core.stats, policy);
+            #[cfg(tokio_unstable)]
+            cx.worker.handle.mark_work_available();
         }
 
         // We are taking the core from the context and sending it to another
         // thread.
         take_core = true;
+        cx.worker
+            .handle
+            .shared
+            .scheduler_metrics
+            .inc_outstanding_block_in_place_count();
 
         // The parker should be set here
         assert!(core.park.is_some());
@@ -436,7 +815,7 @@ where
         // Once the blocking task is done executing, we will attempt to
         // steal the core back.
         let worker = cx.worker.clone();
-        runtime::spawn_blocking(move || run(worker));
+        spawn_worker(worker);
         Ok(())
     });
 
@@ -461,6 +840,31 @@ where
 impl Launch {
     pub(crate) fn launch(mut self) {
         for worker in self.0.drain(..) {
+            spawn_worker(worker);
+        }
+    }
+}
+
+/// Spawns the thread that runs `worker`.
+///
+/// Ordinarily, worker threads are spawned onto the blocking pool, so that
+/// `block_in_place` can hand a worker's core off to a fresh blocking-pool
+/// thread while the original keeps running the blocking closure. When
+/// `Config::worker_stack_size` is set, this instead spawns a dedicated OS
+/// thread with the size it computes for `worker.index`, independent of the
+/// blocking pool's `thread_stack_size` and outside of its thread cap and
+/// shutdown bookkeeping.
+fn spawn_worker(worker: Arc<Worker>) {
+    match worker.handle.shared.config.worker_stack_size.as_ref() {
+        Some(worker_stack_size) => {
+            let stack_size = worker_stack_size(worker.index);
+            thread::Builder::new()
+                .name("tokio-runtime-worker".into())
+                .stack_size(stack_size)
+                .spawn(move || run(worker))
+                .unwrap_or_else(|e| panic!("OS can't spawn worker thread: {}", e));
+        }
+        None => {
             runtime::spawn_blocking(move || run(worker));
         }
     }
@@ -491,6 +895,17 @@ fn run(worker: Arc<Worker>) {
         None => return,
     };
 
+    // Track that a thread (either the original worker thread or a thread
+    // that took over the core via `block_in_place`) is now executing `run`.
+    worker.handle.shared.scheduler_metrics.inc_live_worker_thread_count();
+    struct LiveWorkerThreadGuard(Arc<Handle>);
+    impl Drop for LiveWorkerThreadGuard {
+        fn drop(&mut self) {
+            self.0.shared.scheduler_metrics.dec_live_worker_thread_count();
+        }
+    }
+    let _live_worker_thread_guard = LiveWorkerThreadGuard(worker.handle.clone());
+
     worker.handle.shared.worker_metrics[worker.index].set_thread_id(thread::current().id());
 
     let handle = scheduler::Handle::MultiThread(worker.handle.clone());
@@ -513,7 +928,9 @@ fn run(worker: Arc<Worker>) {
             // Check if there are any deferred tasks to notify. This can happen when
             // the worker core is lost due to `block_in_place()` being called from
             // within the task.
-            cx.defer.wake();
+            let deferred_count = cx.defer.wake();
+            cx.worker.handle.shared.worker_metrics[cx.worker.index]
+                .record_deferred_wake_count(deferred_count as u64);
         });
     });
 }
@@ -529,21 +946,32 @@ impl Context {
         core.stats.start_processing_scheduled_tasks();
 
         while !core.is_shutdown {
-            self.assert_lifo_enabled_is_correct(&core);
+            // Pick up any `Handle::set_lifo_enabled_all` flip promptly, since
+            // it would otherwise only be observed the next time a task is
+            // stolen or `block_in_place` hands the core back.
+            self.reset_lifo_enabled(&mut core);
 
             if core.is_traced {
                 core = self.worker.handle.trace_core(core);
             }
 
+            #[cfg(tokio_unstable)]
+            if core.is_barrier_requested {
+                core = self.worker.handle.run_barrier(core);
+            }
+
             // Increment the tick
             core.tick();
 
+            // Submit stats on their own cadence, independent of maintenance.
+            core = self.submit_stats_if_needed(core);
+
             // Run maintenance, if needed
             core = self.maintenance(core);
 
             // First, check work available to the current worker.
             if let Some(task) = core.next_task(&self.worker) {
-                core = self.run_task(task, core)?;
+                core = self.run_task(task, core, false)?;
                 continue;
             }
 
@@ -555,8 +983,16 @@ impl Context {
             if let Some(task) = core.steal_work(&self.worker) {
                 // Found work, switch back to processing
                 core.stats.start_processing_scheduled_tasks();
-                core = self.run_task(task, core)?;
+                core = self.run_task(task, core, true)?;
+            } else if let Some(task) = self.next_idle_task() {
+                // Nothing to steal either; fall back to a low-priority task
+                // that only runs when the scheduler is otherwise idle.
+                core.stats.start_processing_scheduled_tasks();
+                core = self.run_task(task, core, false)?;
             } else {
+                // Stealing came up empty; this worker is idle for another cycle.
+                core.stats.incr_consecutive_idle();
+
                 // Wait for work
                 core = if !self.defer.is_empty() {
                     self.park_timeout(core, Some(Duration::from_millis(0)))
@@ -569,32 +1005,81 @@ impl Context {
 
         core.pre_shutdown(&self.worker);
         // Signal shutdown
-        self.worker.handle.shutdown_core(core);
+        Handle::shutdown_core(&self.worker.handle, core);
         Err(())
     }
 
-    fn run_task(&self, task: Notified, mut core: Box<Core>) -> RunResult {
+    fn run_task(&self, task: Notified, mut core: Box<Core>, just_stolen: bool) -> RunResult {
         let task = self.worker.handle.shared.owned.assert_owner(task);
 
+        core.stats.reset_consecutive_idle();
+
         // Make sure the worker is not in the **searching** state. This enables
         // another idle worker to try to steal work.
         core.transition_from_searching(&self.worker);
 
+        let worker_metrics = &self.worker.handle.shared.worker_metrics[self.worker.index];
+        worker_metrics.set_worker_status_running();
+        worker_metrics.record_poll_start();
+
         self.assert_lifo_enabled_is_correct(&core);
 
         // Measure the poll start time. Note that we may end up polling other
         // tasks under this measurement. In this case, the tasks came from the
         // LIFO slot and are considered part of the current task for scheduling
         // purposes. These tasks inherent the "parent"'s limits.
+        //
+        // Unless `measure_lifo_polls_individually` is set, in which case each
+        // LIFO-loop task below takes its own `start_poll`/`end_poll`
+        // measurement instead, at the cost of an extra pair of calls per LIFO
+        // task.
+        let measure_lifo_polls_individually =
+            self.worker.handle.shared.config.measure_lifo_polls_individually;
         core.stats.start_poll();
 
+        // If this task was just stolen, the rest of the batch it came with is
+        // sitting in `run_queue`. Remember how big that batch is so we can
+        // give it back if this task blocks immediately below.
+        let stolen_batch_len = if just_stolen { core.run_queue.len() } else { 0 };
+
         // Make the core available to the runtime context
         *self.core.borrow_mut() = Some(core);
 
         // Run the task
-        coop::budget(|| {
-            task.run();
+        let task_budget = self
+            .worker
+            .handle
+            .shared
+            .config
+            .task_budget
+            .as_ref()
+            .map(|f| f(self.worker.index));
+        coop::budget_with(task_budget, || {
+            let outcome = task.run();
+            self.record_poll_outcome(&outcome);
+            let is_blocked = outcome.is_blocked;
+
+            if just_stolen
+                && is_blocked
+                && stolen_batch_len > 0
+                && self.worker.handle.shared.steal_back_enabled.load(Relaxed)
+            {
+                let taken_core = self.core.borrow_mut().take();
+                if let Some(mut core) = taken_core {
+                    let batch_len = core.run_queue.len();
+                    if batch_len > 0 {
+                        self.worker
+                            .handle
+                            .push_batch(std::iter::from_fn(|| core.run_queue.pop()));
+                        core.stats.incr_steal_back_count(batch_len as u16);
+                    }
+                    *self.core.borrow_mut() = Some(core);
+                }
+            }
+
             let mut lifo_polls = 0;
+            let max_lifo_duration = self.worker.handle.shared.config.max_lifo_duration;
+            let lifo_start = max_lifo_duration.map(|_| Instant::now());
 
             // As long as there is budget remaining and a task exists in the
             // `lifo_slot`, then keep running.
@@ -611,32 +1096,76 @@ impl Context {
                     }
                 };
 
+                if measure_lifo_polls_individually {
+                    // Close out the measurement for whichever task (the one
+                    // that started this call, or the previous LIFO task)
+                    // just ran, so each task in the loop gets its own
+                    // `start_poll`/`end_poll` pair below instead of sharing
+                    // the outer measurement.
+                    core.stats.end_poll();
+                }
+
                 // Check for a task in the LIFO slot
                 let task = match core.lifo_slot.take() {
                     Some(task) => task,
                     None => {
                         self.reset_lifo_enabled(&mut core);
-                        core.stats.end_poll();
+                        if !measure_lifo_polls_individually {
+                            core.stats.end_poll();
+                        }
+                        core.stats.record_lifo_chain_length(lifo_polls);
                         return Ok(core);
                     }
                 };
 
                 if !coop::has_budget_remaining() {
-                    core.stats.end_poll();
+                    if !measure_lifo_polls_individually {
+                        core.stats.end_poll();
+                    }
 
                     // Not enough budget left to run the LIFO task, push it to
                     // the back of the queue and return.
+                    core.stats.incr_lifo_budget_demotion_count();
                     core.run_queue.push_back_or_overflow(
                         task,
                         &*self.worker.handle,
                         &mut core.stats,
+                        self.worker.handle.shared.config.overflow_policy,
                     );
+                    #[cfg(tokio_unstable)]
+                    self.worker.handle.mark_work_available();
                     // If we hit this point, the LIFO slot should be enabled.
                     // There is no need to reset it.
                     debug_assert!(core.lifo_enabled);
+                    core.stats.record_lifo_chain_length(lifo_polls);
                     return Ok(core);
                 }
 
+                // If the loop has been running longer than the configured
+                // wall-clock cap, break out even though the poll-count and
+                // coop budget caps have not been hit yet. This bounds how
+                // long a worker can go without checking other work when
+                // LIFO tasks have highly variable poll cost.
+                if let Some(lifo_start) = lifo_start {
+                    if lifo_start.elapsed() >= max_lifo_duration.unwrap() {
+                        if !measure_lifo_polls_individually {
+                            core.stats.end_poll();
+                        }
+
+                        core.run_queue.push_back_or_overflow(
+                            task,
+                            &*self.worker.handle,
+                            &mut core.stats,
+                            self.worker.handle.shared.config.overflow_policy,
+                        );
+                        #[cfg(tokio_unstable)]
+                        self.worker.handle.mark_work_available();
+                        debug_assert!(core.lifo_enabled);
+                        core.stats.record_lifo_chain_length(lifo_polls);
+                        return Ok(core);
+                    }
+                }
+
                 // Track that we are about to run a task from the LIFO slot.
                 lifo_polls += 1;
                 super::counters::inc_lifo_schedules();
@@ -648,30 +1177,89 @@ impl Context {
                 // LIFO slot can cause starvation as these two tasks will
                 // repeatedly schedule the other. To mitigate this, we limit the
                 // number of times the LIFO slot is prioritized.
-                if lifo_polls >= MAX_LIFO_POLLS_PER_TICK {
+                if lifo_polls >= self.worker.handle.shared.config.max_lifo_polls {
                     core.lifo_enabled = false;
                     super::counters::inc_lifo_capped();
                 }
 
+                if measure_lifo_polls_individually {
+                    core.stats.start_poll();
+                }
+
                 // Run the LIFO task, then loop
                 *self.core.borrow_mut() = Some(core);
                 let task = self.worker.handle.shared.owned.assert_owner(task);
-                task.run();
+                let outcome = task.run();
+                self.record_poll_outcome(&outcome);
             }
         })
     }
 
+    /// Pops the next task spawned via `Handle::spawn_when_idle`, if any.
+    ///
+    /// Only called once `next_task` and `steal_work` have both come up
+    /// empty, so these tasks never preempt real work.
+    #[cfg(tokio_unstable)]
+    fn next_idle_task(&self) -> Option<Notified> {
+        self.worker.handle.shared.idle_tasks.lock().pop_front()
+    }
+
+    #[cfg(not(tokio_unstable))]
+    fn next_idle_task(&self) -> Option<Notified> {
+        None
+    }
+
+    /// Records whether a just-finished `task.run()` completed the task, for
+    /// the `worker_completed_poll_count`/`worker_pending_poll_count` metrics.
+    /// A no-op if the core was taken from us while the task ran (e.g. by
+    /// `block_in_place`), since there's nowhere to attribute the stat.
+    fn record_poll_outcome(&self, outcome: &crate::runtime::task::PollOutcome) {
+        if let Some(core) = self.core.borrow_mut().as_mut() {
+            if outcome.is_complete {
+                core.stats.incr_completed_poll_count();
+                core.stats.incr_completed_tasks();
+            } else if outcome.is_blocked {
+                core.stats.incr_pending_poll_count();
+            }
+        }
+    }
+
     fn reset_lifo_enabled(&self, core: &mut Core) {
-        core.lifo_enabled = !self.worker.handle.shared.config.disable_lifo_slot;
+        core.lifo_enabled = self.worker.handle.shared.lifo_enabled_all.load(Relaxed);
     }
 
+    // Note: `core.lifo_enabled` is only refreshed at `reset_lifo_enabled` call
+    // sites, so a `Handle::set_lifo_enabled_all` flip that lands between one
+    // of those and this check can legitimately trip this assertion for one
+    // worker until it next calls `reset_lifo_enabled`. This is expected: see
+    // `Shared::lifo_enabled_all`.
     fn assert_lifo_enabled_is_correct(&self, core: &Core) {
         debug_assert_eq!(
             core.lifo_enabled,
-            !self.worker.handle.shared.config.disable_lifo_slot
+            self.worker.handle.shared.lifo_enabled_all.load(Relaxed)
         );
     }
 
+    /// Submits accumulated stats to `WorkerMetrics` on `Config::metrics_submit_interval`,
+    /// independent of the `event_interval`-gated maintenance cadence.
+    fn submit_stats_if_needed(&self, mut core: Box<Core>) -> Box<Core> {
+        if let Some(interval) = self.worker.handle.shared.config.metrics_submit_interval {
+            if core.tick % interval == 0 {
+                core.stats.submit(
+                    &self.worker.handle.shared.worker_metrics[self.worker.index],
+                    core.global_queue_interval,
+                    core.run_queue.remaining_slots(),
+                );
+                #[cfg(tokio_unstable)]
+                if let Some(sink) = &self.worker.handle.shared.config.metrics_sink {
+                    sink(self.worker.index, &core.stats.last_delta());
+                }
+            }
+        }
+
+        core
+    }
+
     fn maintenance(&self, mut core: Box<Core>) -> Box<Core> {
         if core.tick % self.worker.handle.shared.config.event_interval == 0 {
             super::counters::inc_num_maintenance();
@@ -685,6 +1273,10 @@ impl Context {
             // Run regularly scheduled maintenance
             core.maintenance(&self.worker);
 
+            if let Some(on_event_interval) = &self.worker.handle.shared.config.on_event_interval {
+                on_event_interval(self.worker.index);
+            }
+
             core.stats.start_processing_scheduled_tasks();
         }
 
@@ -708,10 +1300,17 @@ impl Context {
         }
 
         if core.transition_to_parked(&self.worker) {
-            while !core.is_shutdown && !core.is_traced {
+            while !core.is_shutdown && !core.is_traced && !core.is_barrier_pending() {
                 core.stats.about_to_park();
-                core.stats
-                    .submit(&self.worker.handle.shared.worker_metrics[self.worker.index]);
+                core.stats.submit(
+                    &self.worker.handle.shared.worker_metrics[self.worker.index],
+                    core.global_queue_interval,
+                    core.run_queue.remaining_slots(),
+                );
+                #[cfg(tokio_unstable)]
+                if let Some(sink) = &self.worker.handle.shared.config.metrics_sink {
+                    sink(self.worker.index, &core.stats.last_delta());
+                }
 
                 core = self.park_timeout(core, None);
 
@@ -748,7 +1347,13 @@ impl Context {
             park.park(&self.worker.handle.driver);
         }
 
-        self.defer.wake();
+        if let Some(f) = &self.worker.handle.shared.config.on_driver_poll {
+            f();
+        }
+
+        let deferred_count = self.defer.wake();
+        self.worker.handle.shared.worker_metrics[self.worker.index]
+            .record_deferred_wake_count(deferred_count as u64);
 
         // Remove `core` from context
         core = self.core.borrow_mut().take().expect("core missing");
@@ -771,6 +1376,46 @@ impl Context {
     pub(crate) fn get_worker_index(&self) -> usize {
         self.worker.index
     }
+
+    cfg_unstable! {
+        /// A hint, callable from within a running task, that this worker's
+        /// queued backlog should be offered to idle peers without giving up
+        /// the core itself.
+        ///
+        /// Unlike `block_in_place`, the current task keeps running on this
+        /// worker; this only pushes the local run queue onto the injection
+        /// queue and wakes a parked worker so it can pick the backlog up. It
+        /// is a best-effort hint: if there is no queued backlog, this does
+        /// nothing. If no worker is idle, the backlog still moves to the
+        /// injection queue but no worker is woken for it, and there is no
+        /// guarantee the backlog actually migrates to another worker.
+        pub(crate) fn yield_core_hint(&self) {
+            let mut lock = self.core.borrow_mut();
+            let core = match lock.as_mut() {
+                Some(core) => core,
+                None => return,
+            };
+
+            if !core.run_queue.has_tasks() {
+                return;
+            }
+
+            self.worker
+                .handle
+                .push_batch(std::iter::from_fn(|| core.run_queue.pop()));
+
+            drop(lock);
+
+            self.worker.handle.notify_parked_local();
+        }
+
+        /// Returns `true` once the injection queue has been closed, which
+        /// happens as part of runtime shutdown.
+        pub(crate) fn shutdown_requested(&self) -> bool {
+            let synced = self.worker.handle.shared.synced.lock();
+            self.worker.inject().is_closed(&synced.inject)
+        }
+    }
 }
 
 impl Core {
@@ -781,22 +1426,57 @@ impl Core {
 
     /// Return the next notified task available to this worker.
     fn next_task(&mut self, worker: &Worker) -> Option<Notified> {
-        if self.tick % self.global_queue_interval == 0 {
+        // `Builder::strict_fifo` forces the local-then-inject path below on
+        // every tick, bypassing both the inject-priority override and the
+        // periodic (timing-tuned) inject-first check, since either would
+        // make draining order depend on something other than what's already
+        // queued.
+        #[cfg(tokio_unstable)]
+        let strict_fifo = worker.handle.shared.config.strict_fifo;
+        #[cfg(not(tokio_unstable))]
+        let strict_fifo = false;
+
+        if !strict_fifo && worker.handle.shared.config.inject_priority_over_local {
+            if self.tick % self.global_queue_interval == 0 {
+                // Update the global queue interval, if needed
+                self.tune_global_queue_interval(worker);
+            }
+
+            if let Some(task) = worker.handle.next_remote_task() {
+                self.stats.incr_global_queue_pull_count();
+                return Some(task);
+            }
+
+            let task = self.next_local_task(&worker.handle);
+            if task.is_some() {
+                self.stats.incr_local_queue_pull_count();
+            }
+            return task;
+        }
+
+        if !strict_fifo && self.tick % self.global_queue_interval == 0 {
             // Update the global queue interval, if needed
             self.tune_global_queue_interval(worker);
 
-            worker
-                .handle
-                .next_remote_task()
-                .or_else(|| self.next_local_task())
+            if let Some(task) = worker.handle.next_remote_task() {
+                self.stats.incr_global_queue_pull_count();
+                return Some(task);
+            }
+
+            let task = self.next_local_task(&worker.handle);
+            if task.is_some() {
+                self.stats.incr_local_queue_pull_count();
+            }
+            task
         } else {
-            let maybe_task = self.next_local_task();
+            let maybe_task = self.next_local_task(&worker.handle);
 
             if maybe_task.is_some() {
+                self.stats.incr_local_queue_pull_count();
                 return maybe_task;
             }
 
-            if worker.inject().is_empty() {
+            if worker.handle.shared.inject_paused.load(Relaxed) || worker.inject().is_empty() {
                 return None;
             }
 
@@ -823,7 +1503,12 @@ impl Core {
 
             let mut synced = worker.handle.shared.synced.lock();
             // safety: passing in the correct `inject::Synced`.
-            let mut tasks = unsafe { worker.inject().pop_n(&mut synced.inject, n) };
+            let tasks = unsafe { worker.inject().pop_n(&mut synced.inject, n) };
+
+            #[cfg(tokio_unstable)]
+            let mut tasks = tasks.inspect(|task| worker.handle.record_inject_queue_wait(task));
+            #[cfg(not(tokio_unstable))]
+            let mut tasks = tasks;
 
             // Pop the first task to return immediately
             let ret = tasks.next();
@@ -831,12 +1516,28 @@ impl Core {
             // Push the rest of the on the run queue
             self.run_queue.push_back(tasks);
 
+            if ret.is_some() {
+                self.stats.incr_global_queue_pull_count();
+            }
+
             ret
         }
     }
 
-    fn next_local_task(&mut self) -> Option<Notified> {
-        self.lifo_slot.take().or_else(|| self.run_queue.pop())
+    fn next_local_task(&mut self, handle: &Handle) -> Option<Notified> {
+        if let Some(task) = handle.shared.remotes[self.index].fast_slot.take() {
+            return Some(*task);
+        }
+
+        if let Some(task) = self.lifo_slot.take().or_else(|| self.run_queue.pop()) {
+            return Some(task);
+        }
+
+        // Drain our own per-worker inject queue before falling through to
+        // the global inject queue, so a task sent via
+        // `Handle::inject_to_worker` doesn't wait behind whatever else is
+        // already queued globally.
+        handle.shared.remotes[self.index].inject.pop()
     }
 
     /// Function responsible for stealing tasks from another worker
@@ -845,13 +1546,37 @@ impl Core {
     /// a new worker will actually try to steal. The idea is to make sure not all
     /// workers will be trying to steal at the same time.
     fn steal_work(&mut self, worker: &Worker) -> Option<Notified> {
+        #[cfg(tokio_unstable)]
+        if worker.handle.shared.config.strict_fifo {
+            // `Builder::strict_fifo` disables stealing entirely so that task
+            // ordering only ever depends on each worker's own FIFO queue.
+            return None;
+        }
+
         if !self.transition_to_searching(worker) {
+            self.stats.incr_steal_search_denied_count();
             return None;
         }
 
         let num = worker.handle.shared.remotes.len();
-        // Start from a random worker
-        let start = self.rand.fastrand_n(num as u32) as usize;
+        let start = match worker.handle.shared.config.steal_order {
+            StealOrder::RoundRobin => {
+                let start = self.next_steal_index % num;
+                self.next_steal_index = start + 1;
+                start
+            }
+            StealOrder::LastParked => worker
+                .handle
+                .shared
+                .idle
+                .last_parked_with_backlog()
+                .unwrap_or_else(|| self.random_steal_start(worker, num)),
+            StealOrder::Random => self.random_steal_start(worker, num),
+            StealOrder::LeastLoaded => self.least_loaded_steal_start(worker, num),
+        };
+
+        let min_victim_len = self.min_victim_len(worker);
+        let mut first_candidate = true;
 
         for i in 0..num {
             let i = (start + i) % num;
@@ -862,21 +1587,103 @@ impl Core {
             }
 
             let target = &worker.handle.shared.remotes[i];
-            if let Some(task) = target
-                .steal
-                .steal_into(&mut self.run_queue, &mut self.stats)
-            {
+
+            // With `Config::locality_bias` set, a shallow victim queue is
+            // left alone rather than migrating its tasks to this worker.
+            if target.steal.len() < min_victim_len {
+                first_candidate = false;
+                continue;
+            }
+
+            // Also try the target's per-worker inject queue: a task sent
+            // there via `Handle::inject_to_worker` is fair game for any
+            // idle peer, not just its intended worker, once nobody has
+            // drained it yet. `steal_into` already accounts for what it
+            // steals, so only tasks that come from the inject fallback need
+            // to be counted here.
+            let max = worker.handle.shared.config.steal_batch;
+            let task = match target.steal.steal_into(&mut self.run_queue, &mut self.stats, max) {
+                Some(task) => Some(task),
+                None => match target.inject.pop() {
+                    Some(task) => {
+                        self.stats.incr_steal_count(1);
+                        self.stats.incr_steal_operations();
+                        Some(task)
+                    }
+                    None => None,
+                },
+            };
+
+            if let Some(task) = task {
+                #[cfg(all(tokio_unstable, target_has_atomic = "64"))]
+                worker.handle.shared.steal_matrix[worker.index * num + i].add(1, Relaxed);
+
+                if first_candidate {
+                    self.stats.incr_steal_first_try_success();
+                }
+
                 return Some(task);
             }
+
+            first_candidate = false;
         }
 
         // Fallback on checking the global queue
-        worker.handle.next_remote_task()
+        let task = worker.handle.next_remote_task();
+
+        if task.is_some() {
+            self.stats.incr_steal_global_fallback_count();
+        }
+
+        task
+    }
+
+    /// Picks a random starting index to scan for a steal victim from,
+    /// unless a test has installed a hook to override the RNG for a
+    /// reproducible steal order.
+    fn random_steal_start(&mut self, worker: &Worker, num: usize) -> usize {
+        #[cfg(tokio_unstable)]
+        match &worker.handle.shared.config.test_only_rand_hook {
+            Some(hook) => hook(num as u32) as usize,
+            None => self.rand.fastrand_n(num as u32) as usize,
+        }
+        #[cfg(not(tokio_unstable))]
+        {
+            // `worker` is only consulted via `test_only_rand_hook` above.
+            let _ = worker;
+            self.rand.fastrand_n(num as u32) as usize
+        }
+    }
+
+    /// Picks whichever worker currently holds the most tasks in its steal
+    /// queue as the starting point for a scan, so `StealOrder::LeastLoaded`
+    /// probes the fullest victim first instead of an arbitrary one.
+    fn least_loaded_steal_start(&self, worker: &Worker, num: usize) -> usize {
+        (0..num)
+            .max_by_key(|&i| worker.handle.shared.remotes[i].steal.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of tasks a victim's queue must hold before this
+    /// worker will attempt to steal from it, scaled by
+    /// `Config::locality_bias`.
+    ///
+    /// At the default bias of `0.0` this is always `0`, i.e. a victim with
+    /// any tasks at all is a valid steal target, reproducing the
+    /// always-steal-half behavior from before this option existed. At `1.0`
+    /// a victim must be completely full before it will be stolen from.
+    fn min_victim_len(&self, worker: &Worker) -> usize {
+        let bias = worker.handle.shared.config.locality_bias;
+        (bias * self.run_queue.max_capacity() as f64) as usize
     }
 
     fn transition_to_searching(&mut self, worker: &Worker) -> bool {
         if !self.is_searching {
             self.is_searching = worker.handle.shared.idle.transition_worker_to_searching();
+
+            if self.is_searching {
+                worker.handle.shared.worker_metrics[worker.index].set_worker_status_searching();
+            }
         }
 
         self.is_searching
@@ -895,6 +1702,17 @@ impl Core {
         self.lifo_slot.is_some() || self.run_queue.has_tasks()
     }
 
+    /// Returns `true` if a `Handle::barrier` request is pending for this
+    /// worker. Always `false` outside of `tokio_unstable`, since `barrier`
+    /// is not exposed as public API.
+    fn is_barrier_pending(&self) -> bool {
+        #[cfg(tokio_unstable)]
+        return self.is_barrier_requested;
+
+        #[cfg(not(tokio_unstable))]
+        return false;
+    }
+
     fn should_notify_others(&self) -> bool {
         // If there are tasks available to steal, but this worker is not
         // looking for tasks to steal, notify another worker.
@@ -909,7 +1727,21 @@ impl Core {
     /// Returns true if the transition happened, false if there is work to do first.
     fn transition_to_parked(&mut self, worker: &Worker) -> bool {
         // Workers should not park if they have work to do
-        if self.has_tasks() || self.is_traced {
+        if self.has_tasks() || self.is_traced || self.is_barrier_pending() {
+            return false;
+        }
+
+        // If parking this worker would drop the number of unparked workers
+        // below the configured floor, spin instead. Declining to park here
+        // leaves the worker in the normal `run` loop, which immediately
+        // ticks, runs maintenance, and rechecks the local/steal queues
+        // again, i.e. a short spin-recheck loop rather than an actual
+        // park. The extra CPU this costs is already reflected in the
+        // worker's consecutive-idle stat, since each failed steal before
+        // reaching here increments it.
+        let min_active_workers = worker.handle.shared.config.min_active_workers;
+        if min_active_workers > 0 && worker.handle.shared.idle.num_unparked() <= min_active_workers
+        {
             return false;
         }
 
@@ -926,8 +1758,21 @@ impl Core {
         // only.
         self.is_searching = false;
 
+        worker.handle.shared.worker_metrics[worker.index].set_worker_status_parked();
+
         if is_last_searcher {
             worker.handle.notify_if_work_pending();
+
+            if worker.handle.shared.config.lost_wakeup_checks {
+                worker.handle.debug_assert_no_lost_wakeup(worker.index);
+            }
+        }
+
+        #[cfg(tokio_unstable)]
+        if worker.handle.shared.idle.num_unparked() == 0 {
+            if let Some(f) = &worker.handle.shared.config.on_all_idle {
+                f(ON_ALL_IDLE_SUGGESTED_BUDGET);
+            }
         }
 
         true
@@ -947,6 +1792,16 @@ impl Core {
                 .shared
                 .idle
                 .unpark_worker_by_id(&worker.handle.shared, worker.index);
+
+            if self.is_searching {
+                worker.handle.shared.worker_metrics[worker.index].set_worker_status_searching();
+            }
+
+            // This worker woke up already holding a backlog, most likely
+            // because another thread scheduled directly to it while it was
+            // asleep. Remember it as a good steal target.
+            worker.handle.shared.idle.record_parked_with_backlog(worker.index);
+
             return true;
         }
 
@@ -959,26 +1814,97 @@ impl Core {
             return false;
         }
 
+        // The worker was woken but had no task waiting for it locally, so
+        // the notification that woke it was wasted.
+        self.stats.incr_notify_no_work_count();
+
         // When unparked, the worker is in the searching state.
         self.is_searching = true;
+        worker.handle.shared.worker_metrics[worker.index].set_worker_status_searching();
         true
     }
 
     /// Runs maintenance work such as checking the pool's state.
     fn maintenance(&mut self, worker: &Worker) {
-        self.stats
-            .submit(&worker.handle.shared.worker_metrics[worker.index]);
+        let worker_metrics = &worker.handle.shared.worker_metrics[worker.index];
+        self.stats.submit(
+            worker_metrics,
+            self.global_queue_interval,
+            self.run_queue.remaining_slots(),
+        );
+        #[cfg(tokio_unstable)]
+        if let Some(sink) = &worker.handle.shared.config.metrics_sink {
+            sink(worker.index, &self.stats.last_delta());
+        }
+        worker_metrics.set_lifo_slot_occupied(self.lifo_slot.is_some());
 
         if !self.is_shutdown {
             // Check if the scheduler has been shutdown
             let synced = worker.handle.shared.synced.lock();
             self.is_shutdown = worker.inject().is_closed(&synced.inject);
+
+            #[cfg(tokio_unstable)]
+            if self.is_shutdown {
+                if let Some(shutdown_started_at) = synced.shutdown_started_at {
+                    worker_metrics.set_shutdown_observed_after(shutdown_started_at.elapsed());
+                }
+            }
         }
 
         if !self.is_traced {
             // Check if the worker should be tracing.
             self.is_traced = worker.handle.shared.trace_status.trace_requested();
         }
+
+        #[cfg(tokio_unstable)]
+        if !self.is_barrier_requested {
+            // Check if a `Handle::barrier` request is pending.
+            self.is_barrier_requested = worker.handle.shared.barrier_status.requested();
+        }
+
+        self.maybe_rebalance(worker);
+    }
+
+    /// When `Config::rebalance_threshold` is set and this worker's local
+    /// queue has grown past that multiple of the average queue depth across
+    /// all workers, proactively pushes some of its tasks to the injection
+    /// queue and wakes an idle worker, rather than waiting for a steal.
+    fn maybe_rebalance(&mut self, worker: &Worker) {
+        let Some(threshold) = worker.handle.shared.config.rebalance_threshold else {
+            return;
+        };
+
+        let remotes = &worker.handle.shared.remotes[..];
+        if remotes.len() <= 1 {
+            return;
+        }
+
+        let total_len: usize = remotes.iter().map(|remote| remote.steal.len()).sum();
+        let average_len = total_len as f64 / remotes.len() as f64;
+
+        let my_len = self.run_queue.len();
+        let overloaded_at = average_len * threshold;
+
+        if average_len == 0.0 || (my_len as f64) <= overloaded_at {
+            return;
+        }
+
+        let num_to_move = my_len - (overloaded_at as usize).max(1);
+        let mut moved = 0;
+
+        for _ in 0..num_to_move {
+            match self.run_queue.pop() {
+                Some(task) => {
+                    worker.handle.push_remote_task(task);
+                    moved += 1;
+                }
+                None => break,
+            }
+        }
+
+        if moved > 0 {
+            worker.handle.notify_parked_remote();
+        }
     }
 
     /// Signals all tasks to shut down, and waits for them to complete. Must run
@@ -995,18 +1921,31 @@ impl Core {
             .owned
             .close_and_shutdown_all(start as usize);
 
-        self.stats
-            .submit(&worker.handle.shared.worker_metrics[worker.index]);
+        self.stats.submit(
+            &worker.handle.shared.worker_metrics[worker.index],
+            self.global_queue_interval,
+            self.run_queue.remaining_slots(),
+        );
+        #[cfg(tokio_unstable)]
+        if let Some(sink) = &worker.handle.shared.config.metrics_sink {
+            sink(worker.index, &self.stats.last_delta());
+        }
     }
 
-    /// Shuts down the core.
-    fn shutdown(&mut self, handle: &Handle) {
-        // Take the core
-        let mut park = self.park.take().expect("park missing");
-
-        // Drain the queue
-        while self.next_local_task().is_some() {}
+    /// Drains this core's local run queue, LIFO slot, fast-path mailbox, and
+    /// per-worker inject queue.
+    fn drain_local_queue(&mut self, handle: &Handle) {
+        while self.next_local_task(handle).is_some() {}
+    }
 
+    /// Shuts down the driver owned by this core's parker.
+    ///
+    /// Normally the local queue must already be drained (see
+    /// [`Core::drain_local_queue`]) before calling this; the only exception
+    /// is `ShutdownOrder::DriverFirst`, which by design shuts the driver
+    /// down before draining.
+    fn shutdown_driver(&mut self, handle: &Handle) {
+        let mut park = self.park.take().expect("park missing");
         park.shutdown(&handle.driver);
     }
 
@@ -1048,14 +1987,34 @@ impl task::Schedule for Arc<Handle> {
     fn yield_now(&self, task: Notified) {
         self.schedule_task(task, true);
     }
+
+    fn task_pooling_enabled(&self) -> bool {
+        self.shared.config.task_pooling
+    }
 }
 
 impl Handle {
     pub(super) fn schedule_task(&self, task: Notified, is_yield: bool) {
+        #[cfg(tokio_unstable)]
+        if task.is_when_idle() {
+            self.shared.idle_tasks.lock().push_back(task);
+            return;
+        }
+
         with_current(|maybe_cx| {
             if let Some(cx) = maybe_cx {
                 // Make sure the task is part of the **current** scheduler.
                 if self.ptr_eq(&cx.worker.handle) {
+                    // `is_yield` is only ever `true` when this call came from
+                    // `yield_now`, which is exactly the path `Harness::poll`
+                    // takes when a task's own poll notified its own waker:
+                    // the task can't be resubmitted mid-poll, so it's queued
+                    // for another go once the current poll returns.
+                    #[cfg(tokio_unstable)]
+                    if is_yield && self.shared.config.track_self_wake_count {
+                        self.shared.worker_metrics[cx.worker.index].incr_self_wake_count();
+                    }
+
                     // And the current thread still holds a core
                     if let Some(core) = cx.core.borrow_mut().as_mut() {
                         self.schedule_local(core, task, is_yield);
@@ -1070,22 +2029,228 @@ impl Handle {
         });
     }
 
-    pub(super) fn schedule_option_task_without_yield(&self, task: Option<Notified>) {
+    /// While `Config::startup_distribution` is `RoundRobinLocal` and the
+    /// startup budget hasn't run out yet, round-robins `task` directly to a
+    /// worker instead of leaving it to the default placement. Returns
+    /// `Some(task)` unchanged if the task should still go through the
+    /// default placement, either because startup distribution isn't enabled
+    /// or the budget has already been spent.
+    fn try_schedule_startup(&self, task: Notified) -> Option<Notified> {
+        if self.shared.config.startup_distribution != StartupDistribution::RoundRobinLocal {
+            return Some(task);
+        }
+
+        // Claim one unit of startup budget, if any is left. `fetch_update`
+        // rather than a plain `fetch_sub` so concurrent callers past the
+        // budget never wrap `startup_tasks_remaining` around to `usize::MAX`.
+        let claimed = self
+            .shared
+            .startup_tasks_remaining
+            .fetch_update(Relaxed, Relaxed, |remaining| remaining.checked_sub(1))
+            .is_ok();
+
+        if !claimed {
+            return Some(task);
+        }
+
+        let index = self.shared.startup_rr_index.fetch_add(1, Relaxed) % self.shared.remotes.len();
+        self.push_remote_task(task);
+        self.notify_worker_by_id(index);
+        None
+    }
+
+    /// Schedules a freshly spawned task, consulting `Config::placement` (if
+    /// configured) exactly once to decide where it should land.
+    #[cfg(tokio_unstable)]
+    pub(super) fn schedule_new_task(&self, task: Option<Notified>, meta: &TaskMeta<'_>) {
+        let task = match task {
+            Some(task) => task,
+            None => return,
+        };
+
+        let task = match self.try_schedule_startup(task) {
+            Some(task) => task,
+            None => return,
+        };
+
+        let placement = self
+            .shared
+            .config
+            .placement
+            .as_ref()
+            .map_or(Placement::Auto, |f| f(meta));
+
+        match placement {
+            Placement::Auto => self.schedule_task(task, false),
+            Placement::Inject => {
+                self.push_remote_task(task);
+                self.notify_parked_remote();
+            }
+            Placement::Worker(index) if index < self.shared.remotes.len() => {
+                self.push_remote_task(task);
+                self.notify_worker_by_id(index);
+            }
+            // Out-of-range index: fall back to the default placement rather
+            // than silently dropping the task.
+            Placement::Worker(_) => self.schedule_task(task, false),
+        }
+    }
+
+    /// Schedules a freshly spawned task using the scheduler's default
+    /// placement.
+    #[cfg(not(tokio_unstable))]
+    pub(super) fn schedule_new_task(&self, task: Option<Notified>, _meta: &TaskMeta<'_>) {
         if let Some(task) = task {
-            self.schedule_task(task, false);
+            if let Some(task) = self.try_schedule_startup(task) {
+                self.schedule_task(task, false);
+            }
+        }
+    }
+
+    cfg_unstable! {
+        /// Tries to poll a freshly spawned task once, inline on the current
+        /// worker, instead of handing it off through the normal scheduling
+        /// path.
+        ///
+        /// Returns `Some(task)` when the task still needs to go through
+        /// normal scheduling: the calling thread isn't a worker for this
+        /// scheduler, isn't currently holding a core (e.g. it's between
+        /// tasks, or it's the thread driving `block_on`), or has no
+        /// cooperative budget left to spend on an extra poll. Returns `None`
+        /// once the inline poll has run, regardless of whether it completed
+        /// the task or left it `Pending` — either way there is nothing left
+        /// for the caller to schedule, since a task that returns `Pending`
+        /// is already relying on its waker (which may have already fired,
+        /// in which case it has been rescheduled through the normal
+        /// `Schedule::schedule` path by the time this returns).
+        ///
+        /// The inline poll is charged against whatever budget is already
+        /// active on this thread rather than a fresh one, so a task that
+        /// inline-spawns another task (directly, or transitively through
+        /// further inline spawns) can't dodge cooperative scheduling: the
+        /// nested poll eats into the same budget as the poll that spawned
+        /// it, and once that budget runs out this falls back to normal
+        /// scheduling like any other task.
+        pub(super) fn try_spawn_inline(&self, task: Option<Notified>) -> Option<Notified> {
+            let task = task?;
+
+            with_current(|maybe_cx| {
+                let cx = match maybe_cx {
+                    Some(cx) if self.ptr_eq(&cx.worker.handle) => cx,
+                    _ => return Some(task),
+                };
+
+                if cx.core.borrow().is_none() || !coop::has_budget_remaining() {
+                    return Some(task);
+                }
+
+                let task = self.shared.owned.assert_owner(task);
+
+                if let Some(core) = cx.core.borrow_mut().as_mut() {
+                    core.stats.start_poll();
+                }
+
+                let outcome = task.run();
+                cx.record_poll_outcome(&outcome);
+
+                if let Some(core) = cx.core.borrow_mut().as_mut() {
+                    core.stats.end_poll();
+                }
+
+                None
+            })
+        }
+
+        /// Tries to drive the resource (I/O, timer, ...) driver once, with a
+        /// zero timeout, from the current worker.
+        ///
+        /// Returns `true` if the driver was polled, `false` if the calling
+        /// thread isn't a worker for this scheduler or isn't currently
+        /// holding a core, in which case there is nothing to drive from here.
+        ///
+        /// The driver itself is guarded by a `TryLock` shared across all
+        /// workers, so only one worker at a time can actually be the one to
+        /// poll it; if another worker already has it locked (e.g. because
+        /// it's parked on it), this still returns `true`, but the poll below
+        /// is a cheap no-op rather than a real drive. This mirrors the
+        /// zero-timeout park that regular worker maintenance already does to
+        /// let the driver dispatch ready events without blocking the thread.
+        pub(crate) fn drive_once(&self) -> bool {
+            with_current(|maybe_cx| {
+                let cx = match maybe_cx {
+                    Some(cx) if self.ptr_eq(&cx.worker.handle) => cx,
+                    _ => return false,
+                };
+
+                let core = match cx.core.borrow_mut().take() {
+                    Some(core) => core,
+                    None => return false,
+                };
+
+                let core = cx.park_timeout(core, Some(Duration::from_millis(0)));
+
+                *cx.core.borrow_mut() = Some(core);
+
+                true
+            })
         }
     }
 
+    /// Wakes the worker at `index` if it is currently parked. Used to give a
+    /// specific worker first chance at a task that was just pushed to the
+    /// injection queue, either because `Placement::Worker` requested it or
+    /// because `Config::startup_distribution` is round-robining startup
+    /// tasks across workers.
+    fn notify_worker_by_id(&self, index: usize) {
+        self.shared.idle.unpark_worker_by_id(&self.shared, index);
+        self.shared.remotes[index].unpark.unpark(&self.driver);
+    }
+
     fn schedule_local(&self, core: &mut Core, task: Notified, is_yield: bool) {
         core.stats.inc_local_schedule_count();
 
+        // `core.park` is only ever taken out for the duration of polling the
+        // resource driver (see `park_timeout`), so a schedule landing here
+        // while it's `None` was triggered by a driver-observed readiness
+        // event (or the deferred-wake flush right after), rather than by a
+        // task waking itself or another task.
+        if core.park.is_none() {
+            self.shared.worker_metrics[core.index].incr_driver_scheduled_task_count();
+        }
+
+        // A `High` priority task tries for the LIFO slot even in cases that
+        // would otherwise send it to the back of the queue, short of an
+        // actual yield (see below) or a disabled LIFO slot. A `Low` priority
+        // task always goes to
+        // the back, overriding everything else. `Normal`, the default,
+        // leaves the checks below untouched.
+        #[cfg(tokio_unstable)]
+        let force_front = core.lifo_enabled && task.priority() == TaskPriority::High && !is_yield;
+        #[cfg(not(tokio_unstable))]
+        let force_front = false;
+        #[cfg(tokio_unstable)]
+        let force_back = task.priority() == TaskPriority::Low;
+        #[cfg(not(tokio_unstable))]
+        let force_back = false;
+
         // Spawning from the worker thread. If scheduling a "yield" then the
         // task must always be pushed to the back of the queue, enabling other
         // tasks to be executed. If **not** a yield, then there is more
         // flexibility and the task may go to the front of the queue.
-        let should_notify = if is_yield || !core.lifo_enabled {
-            core.run_queue
-                .push_back_or_overflow(task, self, &mut core.stats);
+        //
+        // Tasks that were ever stolen into this worker's queue are also
+        // always pushed to the back, so that a run of locally-generated
+        // self-wake ping-pong can't monopolize the LIFO slot and starve
+        // them.
+        let should_notify = if force_back || (!force_front && (is_yield || !core.lifo_enabled || task.is_stolen())) {
+            core.run_queue.push_back_or_overflow(
+                task,
+                self,
+                &mut core.stats,
+                self.shared.config.overflow_policy,
+            );
+            #[cfg(tokio_unstable)]
+            self.mark_work_available();
             true
         } else {
             // Push to the LIFO slot
@@ -1093,8 +2258,15 @@ impl Handle {
             let ret = prev.is_some();
 
             if let Some(prev) = prev {
-                core.run_queue
-                    .push_back_or_overflow(prev, self, &mut core.stats);
+                core.stats.incr_lifo_eviction_count();
+                core.run_queue.push_back_or_overflow(
+                    prev,
+                    self,
+                    &mut core.stats,
+                    self.shared.config.overflow_policy,
+                );
+                #[cfg(tokio_unstable)]
+                self.mark_work_available();
             }
 
             core.lifo_slot = Some(task);
@@ -1111,36 +2283,136 @@ impl Handle {
     }
 
     fn next_remote_task(&self) -> Option<Notified> {
+        if self.shared.inject_paused.load(Relaxed) {
+            return None;
+        }
+
         if self.shared.inject.is_empty() {
             return None;
         }
 
         let mut synced = self.shared.synced.lock();
         // safety: passing in correct `idle::Synced`
-        unsafe { self.shared.inject.pop(&mut synced.inject) }
+        let task = unsafe { self.shared.inject.pop(&mut synced.inject) };
+
+        #[cfg(tokio_unstable)]
+        if let Some(task) = &task {
+            self.record_inject_queue_wait(task);
+        }
+
+        task
+    }
+
+    /// Feeds `SchedulerMetrics::mean_inject_queue_wait` with the time a
+    /// just-popped task spent sitting in the injection queue, if it came
+    /// from there.
+    #[cfg(tokio_unstable)]
+    fn record_inject_queue_wait(&self, task: &Notified) {
+        if let Some(enqueued_at) = task.take_inject_enqueued_at() {
+            self.shared
+                .scheduler_metrics
+                .record_inject_queue_wait(enqueued_at.elapsed());
+        }
     }
 
     fn push_remote_task(&self, task: Notified) {
         self.shared.scheduler_metrics.inc_remote_schedule_count();
 
-        let mut synced = self.shared.synced.lock();
-        // safety: passing in correct `idle::Synced`
-        unsafe {
-            self.shared.inject.push(&mut synced.inject, task);
+        let became_nonempty = {
+            let mut synced = self.shared.synced.lock();
+            // safety: passing in correct `idle::Synced`
+            unsafe { self.shared.inject.push(&mut synced.inject, task) }
+        };
+
+        #[cfg(tokio_unstable)]
+        self.mark_work_available();
+
+        if became_nonempty {
+            if let Some(f) = &self.shared.config.on_inject_nonempty {
+                f();
+            }
+        }
+    }
+
+    cfg_unstable! {
+        /// Best-effort delivery of `task` straight to `remotes[index]`'s
+        /// fast-path mailbox, bypassing the `synced` mutex entirely. Meant
+        /// for a single dedicated producer per worker (e.g. an I/O
+        /// completion thread); see `Remote::fast_slot`.
+        ///
+        /// If the mailbox is already occupied, or the runtime is shutting
+        /// down, `task` falls back to the inject queue instead.
+        pub(super) fn push_fast(&self, index: usize, task: Notified) {
+            let index = index % self.shared.remotes.len();
+            let slot = &self.shared.remotes[index].fast_slot;
+
+            // Best-effort guard: once `OwnedTasks` is closed, nothing will
+            // ever drain this mailbox again, so route through the inject
+            // queue (which safely drops the task once closed) rather than
+            // stranding it here.
+            if self.shared.owned.is_closed() {
+                self.push_remote_task(task);
+                self.notify_worker_by_id(index);
+                return;
+            }
+
+            match slot.take() {
+                None => {
+                    slot.set(Box::new(task));
+                    self.notify_worker_by_id(index);
+                }
+                Some(occupant) => {
+                    // Someone is already waiting to be drained; put it back
+                    // and fall back to the inject queue for the new task
+                    // rather than overwrite it.
+                    slot.set(occupant);
+                    self.push_remote_task(task);
+                    self.notify_worker_by_id(index);
+                }
+            }
+        }
+
+        /// Pushes `task` onto `remotes[index]`'s per-worker inject queue and
+        /// wakes that worker.
+        ///
+        /// Unlike `push_fast`, there's no single slot to contend over: the
+        /// per-worker queue is unbounded, so the task only falls back to the
+        /// inject queue once the runtime is shutting down. The owning worker
+        /// drains it in `Core::next_local_task`, and idle peers may steal
+        /// from it too (see `Core::steal_work`), so a slow owner doesn't
+        /// strand it.
+        pub(super) fn push_to_worker(&self, index: usize, task: Notified) {
+            let index = index % self.shared.remotes.len();
+
+            if self.shared.owned.is_closed() {
+                self.push_remote_task(task);
+                self.notify_worker_by_id(index);
+                return;
+            }
+
+            self.shared.remotes[index].inject.push(task);
+            self.mark_work_available();
+            self.notify_worker_by_id(index);
         }
     }
 
     pub(super) fn close(&self) {
-        if self
-            .shared
-            .inject
-            .close(&mut self.shared.synced.lock().inject)
-        {
+        let mut synced = self.shared.synced.lock();
+
+        #[cfg(tokio_unstable)]
+        if synced.shutdown_started_at.is_none() {
+            synced.shutdown_started_at = Some(Instant::now());
+        }
+
+        let should_notify = self.shared.inject.close(&mut synced.inject);
+        drop(synced);
+
+        if should_notify {
             self.notify_all();
         }
     }
 
-    fn notify_parked_local(&self) {
+    pub(super) fn notify_parked_local(&self) {
         super::counters::inc_num_inc_notify_local();
 
         if let Some(index) = self.shared.idle.worker_to_notify(&self.shared) {
@@ -1149,6 +2421,15 @@ impl Handle {
         }
     }
 
+    /// If a worker is idle, wakes it so it picks up the task that was just
+    /// pushed to the inject queue.
+    ///
+    /// Finding no worker to notify is fine even if none has started running
+    /// yet: at construction, `Idle` counts every worker as unparked (i.e.
+    /// busy) rather than sleeping, so this is a no-op until the first one
+    /// actually parks. A worker checks the inject queue itself before ever
+    /// parking, so a task scheduled here before any worker is running just
+    /// waits in the queue and is picked up as soon as one starts.
     fn notify_parked_remote(&self) {
         if let Some(index) = self.shared.idle.worker_to_notify(&self.shared) {
             self.shared.remotes[index].unpark.unpark(&self.driver);
@@ -1161,7 +2442,35 @@ impl Handle {
         }
     }
 
+    /// Records that a task was just made visible to stealers, for the
+    /// benefit of the last searching worker's cached recheck. See
+    /// `has_work_hint` and `notify_if_work_pending`.
+    #[cfg(tokio_unstable)]
+    fn mark_work_available(&self) {
+        if self.shared.config.cached_idle_recheck {
+            self.shared.has_work_hint.store(true, Relaxed);
+        }
+    }
+
     fn notify_if_work_pending(&self) {
+        #[cfg(tokio_unstable)]
+        if self.shared.config.cached_idle_recheck {
+            // Instead of scanning every remote's queue plus the injection
+            // queue, consult the single cached bit that the push/overflow
+            // paths maintain. Any task made visible to stealers after this
+            // worker registered itself as idle (see
+            // `Core::transition_to_parked`) sets the bit, so a swap-and-clear
+            // here can't miss work: either the bit is already set from a
+            // race that happened before we looked, or the racing push itself
+            // observes this worker as idle and notifies it directly. The bit
+            // may occasionally be set with nothing left to steal, costing a
+            // redundant wakeup, but it is never cleared while work remains.
+            if self.shared.has_work_hint.swap(false, Relaxed) {
+                self.notify_parked_local();
+            }
+            return;
+        }
+
         for remote in &self.shared.remotes[..] {
             if !remote.steal.is_empty() {
                 self.notify_parked_local();
@@ -1174,6 +2483,35 @@ impl Handle {
         }
     }
 
+    /// Development-time assertion for `Builder::lost_wakeup_checks`: panics
+    /// if `worker_index`, having just been the last searching worker to
+    /// park, is leaving runnable work behind with no other worker awake to
+    /// claim it. `notify_if_work_pending` was just given the chance to
+    /// notify a peer in this exact situation, so seeing this fire means
+    /// some notify path has a bug.
+    fn debug_assert_no_lost_wakeup(&self, worker_index: usize) {
+        if self.shared.idle.num_unparked() != 0 {
+            // Some other worker is awake and can claim any pending work
+            // itself.
+            return;
+        }
+
+        let inject_len = self.shared.inject.len();
+        let steal_lens: Vec<usize> = self.shared.remotes.iter().map(|r| r.steal.len()).collect();
+
+        if inject_len == 0 && steal_lens.iter().all(|&len| len == 0) {
+            return;
+        }
+
+        panic!(
+            "lost wakeup detected: worker {worker_index} parked with every \
+             worker asleep, but the injection queue has {inject_len} task(s) \
+             and per-worker local queues have {steal_lens:?} task(s) \
+             pending. Some path that should have notified a parked worker \
+             did not."
+        );
+    }
+
     fn transition_worker_from_searching(&self) {
         if self.shared.idle.transition_worker_from_searching() {
             // We are the final searching worker. Because work was found, we
@@ -1186,31 +2524,169 @@ impl Handle {
     /// its core back into its handle.
     ///
     /// If all workers have reached this point, the final cleanup is performed.
-    fn shutdown_core(&self, core: Box<Core>) {
-        let mut cores = self.shared.shutdown_cores.lock();
+    fn shutdown_core(handle: &Arc<Handle>, core: Box<Core>) {
+        let mut cores = handle.shared.shutdown_cores.lock();
         cores.push(core);
 
-        if cores.len() != self.shared.remotes.len() {
+        if cores.len() != handle.shared.remotes.len() {
             return;
         }
 
-        debug_assert!(self.shared.owned.is_empty());
+        debug_assert!(handle.shared.owned.is_empty());
+
+        let mut cores: Vec<_> = cores.drain(..).collect();
 
-        for mut core in cores.drain(..) {
-            core.shutdown(self);
+        #[cfg(tokio_unstable)]
+        let driver_first = matches!(
+            handle.shared.config.shutdown_order,
+            crate::runtime::ShutdownOrder::DriverFirst
+        );
+        #[cfg(not(tokio_unstable))]
+        let driver_first = false;
+
+        if driver_first {
+            // `ShutdownOrder::DriverFirst`: shut down the driver (and the
+            // I/O resources it owns) before dropping any queued task, so a
+            // task's `Drop` implementation never observes a driver that is
+            // still alive. See `Builder::shutdown_order` for the tradeoffs
+            // this implies.
+            if handle.shared.config.offload_driver_shutdown_to_dedicated_thread {
+                let handle = handle.clone();
+                let _ = thread::Builder::new()
+                    .name("tokio-driver-shutdown".into())
+                    .spawn(move || {
+                        Self::time_shutdown_driver(&handle, &mut cores);
+                        Self::time_shutdown_task_drain(&handle, &mut cores);
+                        Self::time_shutdown_inject_drain(&handle);
+                        #[cfg(any(debug_assertions, tokio_unstable))]
+                        Self::assert_shutdown_complete(&handle, &cores);
+                    });
+            } else {
+                Self::time_shutdown_driver(handle, &mut cores);
+                Self::time_shutdown_task_drain(handle, &mut cores);
+                Self::time_shutdown_inject_drain(handle);
+                #[cfg(any(debug_assertions, tokio_unstable))]
+                Self::assert_shutdown_complete(handle, &cores);
+            }
+        } else {
+            // Drain each core's local run queue before dropping the driver. This
+            // must always happen synchronously, regardless of
+            // `offload_driver_shutdown_to_dedicated_thread`, so that by the time
+            // this function returns, no tasks remain anywhere in the scheduler.
+            Self::time_shutdown_task_drain(handle, &mut cores);
+
+            // Drain the injection queue
+            //
+            // We already shut down every task, so we can simply drop the tasks.
+            Self::time_shutdown_inject_drain(handle);
+
+            // Now that every collection has been closed then emptied, none of
+            // them should be able to contain a task, per the invariant documented
+            // at the top of this file. Verify that eagerly here so that a
+            // shutdown-ordering regression is caught at the point it happens
+            // rather than surfacing later as a leak.
+            #[cfg(any(debug_assertions, tokio_unstable))]
+            Self::assert_shutdown_complete(handle, &cores);
+
+            if handle.shared.config.offload_driver_shutdown_to_dedicated_thread {
+                // Move the (now task-free) cores, including the driver, to a
+                // dedicated thread so this worker thread can return promptly.
+                let handle = handle.clone();
+                let _ = thread::Builder::new()
+                    .name("tokio-driver-shutdown".into())
+                    .spawn(move || {
+                        let mut cores = cores;
+                        Self::time_shutdown_driver(&handle, &mut cores);
+                    });
+            } else {
+                Self::time_shutdown_driver(handle, &mut cores);
+            }
         }
+    }
 
-        // Drain the injection queue
-        //
-        // We already shut down every task, so we can simply drop the tasks.
-        while let Some(task) = self.next_remote_task() {
+    /// Drains every core's local run queue, recording how long it took in
+    /// [`SchedulerMetrics::shutdown_task_drain_duration`].
+    fn time_shutdown_task_drain(handle: &Handle, cores: &mut [Box<Core>]) {
+        #[cfg(tokio_unstable)]
+        let started = Instant::now();
+
+        for core in cores {
+            core.drain_local_queue(handle);
+        }
+
+        #[cfg(tokio_unstable)]
+        handle
+            .shared
+            .scheduler_metrics
+            .set_shutdown_task_drain_duration(started.elapsed());
+    }
+
+    /// Shuts down every core's driver, recording how long it took in
+    /// [`SchedulerMetrics::shutdown_driver_duration`].
+    fn time_shutdown_driver(handle: &Handle, cores: &mut [Box<Core>]) {
+        #[cfg(tokio_unstable)]
+        let started = Instant::now();
+
+        for core in cores {
+            core.shutdown_driver(handle);
+        }
+
+        #[cfg(tokio_unstable)]
+        handle
+            .shared
+            .scheduler_metrics
+            .set_shutdown_driver_duration(started.elapsed());
+    }
+
+    /// Drains the injection queue, recording how long it took in
+    /// [`SchedulerMetrics::shutdown_inject_drain_duration`].
+    fn time_shutdown_inject_drain(handle: &Handle) {
+        #[cfg(tokio_unstable)]
+        let started = Instant::now();
+
+        while let Some(task) = handle.next_remote_task() {
             drop(task);
         }
+
+        #[cfg(tokio_unstable)]
+        handle
+            .shared
+            .scheduler_metrics
+            .set_shutdown_inject_drain_duration(started.elapsed());
     }
 
     fn ptr_eq(&self, other: &Handle) -> bool {
         std::ptr::eq(self, other)
     }
+
+    /// Panics if any collection that participates in shutdown still holds a
+    /// task, with a message identifying which one leaked.
+    ///
+    /// Must only be called after step 6 of the shutdown procedure documented
+    /// at the top of this file, i.e. after every core's local run queue and
+    /// the inject queue have been drained.
+    #[cfg(any(debug_assertions, tokio_unstable))]
+    fn assert_shutdown_complete(handle: &Handle, cores: &[Box<Core>]) {
+        assert!(
+            handle.shared.owned.is_empty(),
+            "OwnedTasks is not empty after shutdown drained it; a task was \
+             spawned after step 3 of shutdown without observing the close bit"
+        );
+
+        for core in cores {
+            assert!(
+                !core.has_tasks(),
+                "a worker's local run queue is not empty after shutdown \
+                 drained it"
+            );
+        }
+
+        assert!(
+            handle.shared.inject.is_empty(),
+            "the inject queue is not empty after shutdown drained it; a task \
+             was pushed to it after step 6 of shutdown"
+        );
+    }
 }
 
 impl Overflow<Arc<Handle>> for Handle {
@@ -0,0 +1,36 @@
+use super::{Core, Handle};
+
+use std::time::Duration;
+
+impl Handle {
+    pub(super) fn run_barrier(&self, mut core: Box<Core>) -> Box<Core> {
+        core.is_barrier_requested = false;
+
+        if core.is_shutdown {
+            return core;
+        }
+
+        // Wait for every other worker to reach this point, or give up
+        // without running the callback if one hasn't shown up in time (e.g.
+        // it's stuck in a long blocking task). `Handle::barrier` notices
+        // nothing happened and retries.
+        let timeout = Duration::from_millis(250);
+        let barrier = match self.shared.barrier_status.barrier_start.wait_timeout(timeout) {
+            Some(barrier) => barrier,
+            None => return core,
+        };
+
+        if let Some(f) = self.shared.barrier_status.callback() {
+            f();
+        }
+
+        self.shared.barrier_status.barrier_end.wait();
+
+        if barrier.is_leader() {
+            *self.shared.barrier_status.callback.lock() = None;
+            self.shared.barrier_status.completed.notify_one();
+        }
+
+        core
+    }
+}
@@ -11,5 +11,27 @@ cfg_unstable_metrics! {
         pub(crate) fn worker_local_queue_depth(&self, worker: usize) -> usize {
             self.remotes[worker].steal.len()
         }
+
+        /// Best-effort snapshot of how many additional tasks the given
+        /// worker's local run queue could accept as of its last stats
+        /// submission, before it would overflow to the injection queue.
+        pub(crate) fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+            self.worker_metrics[worker].run_queue_remaining()
+        }
+
+        /// Best-effort snapshot of the total number of tasks pending across
+        /// every worker's local queue, the LIFO slots, and the injection
+        /// queue. Because this reads several independent atomics without a
+        /// global lock, the value may be momentarily inconsistent.
+        pub(crate) fn total_pending_tasks(&self) -> usize {
+            let local: usize = (0..self.remotes.len())
+                .map(|i| {
+                    self.worker_local_queue_depth(i)
+                        + self.worker_metrics[i].lifo_slot_occupied() as usize
+                })
+                .sum();
+
+            local + self.injection_queue_depth()
+        }
     }
 }
@@ -42,7 +42,7 @@ impl Handle {
         // was created with.
         let traces = unsafe { trace_multi_thread(owned, &mut local, synced, injection) }
             .into_iter()
-            .map(|(id, trace)| dump::Task::new(id, trace))
+            .map(|(id, migration_count, trace)| dump::Task::new(id, migration_count, trace))
             .collect();
 
         let result = dump::Dump::new(traces);
@@ -68,7 +68,7 @@ impl Shared {
         for remote in self.remotes.iter() {
             let steal = &remote.steal;
             while !steal.is_empty() {
-                if let Some(task) = steal.steal_into(&mut local, &mut stats) {
+                if let Some(task) = steal.steal_into(&mut local, &mut stats, None) {
                     local.push_back([task].into_iter());
                 }
             }
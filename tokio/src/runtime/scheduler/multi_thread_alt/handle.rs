@@ -47,22 +47,32 @@ impl Handle {
         self.driver.unpark();
     }
 
-    pub(super) fn bind_new_task<T>(me: &Arc<Self>, future: T, id: task::Id) -> JoinHandle<T::Output>
+    pub(super) fn bind_new_task<T>(
+        me: &Arc<Self>,
+        future: T,
+        id: task::Id,
+    ) -> JoinHandle<T::Output>
     where
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        let (handle, notified) = me.shared.owned.bind(future, me.clone(), id);
+        let (handle, notified) = me.shared.owned.bind(
+            future,
+            me.clone(),
+            id,
+            false,
+            task::TaskPriority::Normal,
+        );
 
-        me.task_hooks.spawn(&TaskMeta {
+        let meta = TaskMeta {
             #[cfg(tokio_unstable)]
             id,
             _phantom: Default::default(),
-        });
+        };
 
-        if let Some(notified) = notified {
-            me.shared.schedule_task(notified, false);
-        }
+        me.task_hooks.spawn(&meta);
+
+        me.shared.schedule_new_task(notified, &meta);
 
         handle
     }
@@ -75,6 +85,38 @@ cfg_unstable! {
         pub(crate) fn owned_id(&self) -> NonZeroU64 {
             self.shared.owned.id
         }
+
+        pub(crate) fn worker_rng_seeds(&self) -> Vec<u64> {
+            self.shared.worker_rng_seeds.to_vec()
+        }
+
+        pub(crate) fn worker_label(&self, worker: usize) -> &str {
+            &self.shared.worker_labels[worker]
+        }
+
+        pub(crate) fn set_lifo_enabled_all(&self, enabled: bool) {
+            self.shared
+                .lifo_enabled_all
+                .store(enabled, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        pub(crate) fn set_steal_back_enabled(&self, enabled: bool) {
+            self.shared
+                .steal_back_enabled
+                .store(enabled, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        pub(crate) fn pause_inject(&self) {
+            self.shared
+                .inject_paused
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        pub(crate) fn resume_inject(&self) {
+            self.shared
+                .inject_paused
+                .store(false, std::sync::atomic::Ordering::Relaxed);
+        }
     }
 }
 
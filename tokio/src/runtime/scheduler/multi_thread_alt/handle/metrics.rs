@@ -22,6 +22,11 @@ impl Handle {
         self.shared.owned.num_alive_tasks()
     }
 
+    #[cfg(tokio_unstable)]
+    pub(crate) fn max_live_tasks(&self) -> Option<usize> {
+        self.shared.config.max_live_tasks()
+    }
+
     cfg_64bit_metrics! {
         pub(crate) fn spawned_tasks_count(&self) -> u64 {
             self.shared.owned.spawned_tasks_count()
@@ -44,7 +49,57 @@ impl Handle {
         self.shared.worker_local_queue_depth(worker)
     }
 
+    pub(crate) fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+        self.shared.worker_run_queue_remaining(worker)
+    }
+
     pub(crate) fn blocking_queue_depth(&self) -> usize {
         self.blocking_spawner.queue_depth()
     }
+
+    pub(crate) fn live_worker_thread_count(&self) -> usize {
+        self.shared.scheduler_metrics.live_worker_thread_count()
+    }
+
+    pub(crate) fn outstanding_block_in_place_count(&self) -> usize {
+        self.shared
+            .scheduler_metrics
+            .outstanding_block_in_place_count()
+    }
+
+    pub(crate) fn worker_global_queue_intervals(&self) -> Vec<u32> {
+        self.shared
+            .worker_metrics
+            .iter()
+            .map(|w| w.global_queue_interval())
+            .collect()
+    }
+
+    /// Best-effort snapshot of the total number of pending tasks across
+    /// every worker's local queue and the injection queue.
+    pub(crate) fn total_pending_tasks(&self) -> usize {
+        let local: usize = (0..self.shared.worker_metrics.len())
+            .map(|i| self.worker_local_queue_depth(i))
+            .sum();
+
+        local + self.injection_queue_depth()
+    }
+
+    pub(crate) fn peak_searching_workers(&self) -> usize {
+        self.shared.idle.peak_searching_workers()
+    }
+
+    pub(crate) fn reset_peak_searching_workers(&self) {
+        self.shared.idle.reset_peak_searching_workers()
+    }
+
+    cfg_64bit_metrics! {
+        /// The alternate multi-threaded scheduler does not currently track
+        /// per-victim steal counts, so this always returns a zeroed matrix
+        /// sized to the number of workers.
+        pub(crate) fn steal_matrix(&self) -> Vec<Vec<u64>> {
+            let num_workers = self.num_workers();
+            vec![vec![0; num_workers]; num_workers]
+        }
+    }
 }
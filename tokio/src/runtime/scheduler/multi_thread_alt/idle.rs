@@ -12,6 +12,10 @@ pub(super) struct Idle {
     /// Number of searching cores
     num_searching: AtomicUsize,
 
+    /// Highest number of cores observed searching at the same time, updated
+    /// every time a core transitions into the searching state.
+    max_searching: AtomicUsize,
+
     /// Number of idle cores
     num_idle: AtomicUsize,
 
@@ -46,6 +50,7 @@ impl Idle {
     pub(super) fn new(cores: Vec<Box<Core>>, num_workers: usize) -> (Idle, Synced) {
         let idle = Idle {
             num_searching: AtomicUsize::new(0),
+            max_searching: AtomicUsize::new(0),
             num_idle: AtomicUsize::new(cores.len()),
             idle_map: IdleMap::new(&cores),
             needs_searching: AtomicBool::new(false),
@@ -159,7 +164,7 @@ impl Idle {
                 super::counters::inc_num_unparks_remote();
 
                 // Notify the worker
-                shared.condvars[worker].notify_one();
+                notify_core_available(shared, worker);
                 return;
             } else {
                 synced.idle.sleepers.push(worker);
@@ -230,7 +235,7 @@ impl Idle {
             let core = self.try_acquire_available_core(&mut synced.idle).unwrap();
 
             synced.assigned_cores[worker] = Some(core);
-            shared.condvars[worker].notify_one();
+            notify_core_available(shared, worker);
         }
 
         debug_assert!(self.idle_map.matches(&synced.idle.available_cores));
@@ -315,10 +320,24 @@ impl Idle {
 
     pub(super) fn transition_worker_to_searching(&self, core: &mut Core) {
         core.is_searching = true;
-        self.num_searching.fetch_add(1, AcqRel);
+        let prev = self.num_searching.fetch_add(1, AcqRel);
+        self.max_searching.fetch_max(prev + 1, AcqRel);
         self.needs_searching.store(false, Release);
     }
 
+    /// Returns the highest number of cores observed searching at the same
+    /// time since the last reset.
+    pub(super) fn peak_searching_workers(&self) -> usize {
+        self.max_searching.load(Acquire)
+    }
+
+    /// Resets the high-water mark returned by `peak_searching_workers` back
+    /// to the number of cores currently searching.
+    pub(super) fn reset_peak_searching_workers(&self) {
+        self.max_searching
+            .store(self.num_searching.load(Acquire), Release);
+    }
+
     /// A lightweight transition from searching -> running.
     ///
     /// Returns `true` if this is the final searching worker. The caller
@@ -407,6 +426,21 @@ impl Snapshot {
     }
 }
 
+/// Wakes `worker` after a core has just been assigned to it in
+/// `synced.assigned_cores[worker]`, using `notify_all` instead of
+/// `notify_one` if `Config::core_notify_broadcast` is set.
+///
+/// Each worker waits on its own dedicated condvar, so at most one thread is
+/// ever parked on it and the two calls are equivalent in practice; the
+/// config flag exists to make the choice explicit rather than implicit.
+pub(super) fn notify_core_available(shared: &Shared, worker: usize) {
+    if shared.config.core_notify_broadcast {
+        shared.condvars[worker].notify_all();
+    } else {
+        shared.condvars[worker].notify_one();
+    }
+}
+
 fn num_chunks(max_cores: usize) -> usize {
     (max_cores / BITS) + 1
 }
@@ -418,6 +452,6 @@ fn index_to_mask(index: usize) -> (usize, usize) {
     (chunk, mask)
 }
 
-fn num_active_workers(synced: &Synced) -> usize {
+pub(super) fn num_active_workers(synced: &Synced) -> usize {
     synced.available_cores.capacity() - synced.available_cores.len()
 }
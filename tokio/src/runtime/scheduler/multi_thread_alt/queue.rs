@@ -90,6 +90,11 @@ pub(crate) fn local<T: 'static>(capacity: usize) -> (Steal<T>, Local<T>) {
 }
 
 impl<T> Local<T> {
+    /// Returns the number of entries in the queue
+    pub(crate) fn len(&self) -> usize {
+        self.inner.len() as usize
+    }
+
     /// How many tasks can be pushed into the queue
     pub(crate) fn remaining_slots(&self) -> usize {
         self.inner.remaining_slots()
@@ -503,6 +508,9 @@ impl<T> Steal<T> {
             // safety: We acquired the task with the atomic exchange above.
             let task = self.0.buffer[src_idx].with(|ptr| unsafe { ptr::read((*ptr).as_ptr()) });
 
+            task.incr_migration_count();
+            task.set_stolen();
+
             // Write the task to the new slot
             //
             // safety: `dst` queue is empty and we are the only producer to
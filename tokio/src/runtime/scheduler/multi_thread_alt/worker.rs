@@ -56,6 +56,7 @@
 //! the inject queue indefinitely. This would be a ref-count cycle and a memory
 //! leak.
 
+use crate::loom::sync::atomic::AtomicBool;
 use crate::loom::sync::{Arc, Condvar, Mutex, MutexGuard};
 use crate::runtime;
 use crate::runtime::driver::Driver;
@@ -64,14 +65,17 @@ use crate::runtime::scheduler::multi_thread_alt::{
 };
 use crate::runtime::scheduler::{self, inject, Lock};
 use crate::runtime::task::{OwnedTasks, TaskHarnessScheduleHooks};
-use crate::runtime::{blocking, coop, driver, task, Config, SchedulerMetrics, WorkerMetrics};
+#[cfg(tokio_unstable)]
+use crate::runtime::Placement;
+use crate::runtime::{blocking, coop, driver, task, Config, SchedulerMetrics, TaskMeta, WorkerMetrics};
 use crate::runtime::{context, TaskHooks};
 use crate::util::atomic_cell::AtomicCell;
 use crate::util::rand::{FastRand, RngSeedGenerator};
 
 use std::cell::{Cell, RefCell};
+use std::sync::atomic::Ordering::Relaxed;
 use std::task::Waker;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{cmp, thread};
 
 cfg_unstable_metrics! {
@@ -106,6 +110,18 @@ pub(super) struct Worker {
     /// Snapshot of idle core list. This helps speedup stealing
     idle_snapshot: idle::Snapshot,
 
+    /// True if the task returned by the most recent `next_task()` call was
+    /// stolen from another worker, rather than pulled from a local or
+    /// injection queue.
+    just_stole_task: bool,
+
+    /// Current park timeout, when `Config::park_backoff` is set. Starts at
+    /// `park_backoff.initial` and doubles (up to `park_backoff.max`) each
+    /// time this worker's timed park runs out with no core assigned to it;
+    /// being woken by a notification before the timeout does not double it.
+    /// Reset back to `park_backoff.initial` once a core is assigned.
+    park_backoff: Duration,
+
     stats: stats::Ephemeral,
 }
 
@@ -144,7 +160,7 @@ pub(crate) struct Shared {
     pub(super) inject: inject::Shared<Arc<Handle>>,
 
     /// Coordinates idle workers
-    idle: Idle,
+    pub(super) idle: Idle,
 
     /// Collection of all active tasks spawned onto this executor.
     pub(super) owned: OwnedTasks<Arc<Handle>>,
@@ -164,13 +180,46 @@ pub(crate) struct Shared {
     pub(super) trace_status: TraceStatus,
 
     /// Scheduler configuration options
-    config: Config,
+    pub(super) config: Config,
+
+    /// Whether the LIFO slot is enabled, dynamically overriding
+    /// `config.disable_lifo_slot` at runtime. Initialized from the static
+    /// config and flipped by `Handle::set_lifo_enabled_all`.
+    ///
+    /// Workers only pick this up when they next reset their own
+    /// `lifo_enabled` flag (via `reset_lifo_enabled`, e.g. after running a
+    /// task or reacquiring a core), so there is a brief window after a flip
+    /// where some workers still observe the old setting.
+    pub(super) lifo_enabled_all: AtomicBool,
+
+    /// Whether steal-back is enabled, dynamically overriding
+    /// `config.steal_back` at runtime. Initialized from the static config
+    /// and flipped by `Handle::update_config`.
+    pub(super) steal_back_enabled: AtomicBool,
+
+    /// Whether workers are currently allowed to pull tasks from the
+    /// injection queue, flipped by `Handle::pause_inject`/`resume_inject`.
+    /// Tasks submitted remotely while paused simply accumulate in the
+    /// injection queue instead of being lost; workers keep running their
+    /// local queues as normal and only stop reaching into the shared queue.
+    pub(super) inject_paused: AtomicBool,
 
     /// Collects metrics from the runtime.
     pub(super) scheduler_metrics: SchedulerMetrics,
 
     pub(super) worker_metrics: Box<[WorkerMetrics]>,
 
+    /// The seed used to initialize each core's `rand`, captured at creation
+    /// time so a failure can be reproduced later by replaying the same
+    /// seeds via `Builder::rng_seed`.
+    #[cfg(tokio_unstable)]
+    pub(super) worker_rng_seeds: Box<[u64]>,
+
+    /// Human-readable label for each worker, indexed by worker index. Purely
+    /// diagnostic; see `Config::worker_labels`.
+    #[cfg(tokio_unstable)]
+    pub(super) worker_labels: Box<[Box<str>]>,
+
     /// Only held to trigger some code on drop. This is used to get internal
     /// runtime metrics that can be useful when doing performance
     /// investigations. This does nothing (empty struct, no drop impl) unless
@@ -247,12 +296,6 @@ type Task = task::Task<Arc<Handle>>;
 /// A notified task handle
 type Notified = task::Notified<Arc<Handle>>;
 
-/// Value picked out of thin-air. Running the LIFO slot a handful of times
-/// seems sufficient to benefit from locality. More than 3 times probably is
-/// overweighing. The value can be tuned in the future with data that shows
-/// improvements.
-const MAX_LIFO_POLLS_PER_TICK: usize = 3;
-
 pub(super) fn create(
     num_cores: usize,
     driver: Driver,
@@ -273,6 +316,10 @@ pub(super) fn create(
     let mut remotes = Vec::with_capacity(num_cores);
     // Worker metrics are actually core based
     let mut worker_metrics = Vec::with_capacity(num_cores);
+    #[cfg(tokio_unstable)]
+    let mut worker_rng_seeds = Vec::with_capacity(num_cores);
+    #[cfg(tokio_unstable)]
+    let mut worker_labels = Vec::with_capacity(num_cores);
 
     // Create the local queues
     for i in 0..num_cores {
@@ -280,6 +327,20 @@ pub(super) fn create(
 
         let metrics = WorkerMetrics::from_config(&config);
         let stats = Stats::new(&metrics);
+        let seed = config.seed_generator.next_seed();
+
+        #[cfg(tokio_unstable)]
+        worker_rng_seeds.push(seed.as_u64());
+
+        #[cfg(tokio_unstable)]
+        worker_labels.push(
+            config
+                .worker_labels
+                .get(i)
+                .cloned()
+                .unwrap_or_else(|| format!("worker-{i}"))
+                .into_boxed_str(),
+        );
 
         cores.push(Box::new(Core {
             index: i,
@@ -287,7 +348,7 @@ pub(super) fn create(
             run_queue,
             is_searching: false,
             stats,
-            rand: FastRand::from_seed(config.seed_generator.next_seed()),
+            rand: FastRand::from_seed(seed),
         }));
 
         remotes.push(Remote {
@@ -302,6 +363,13 @@ pub(super) fn create(
     let (idle, idle_synced) = Idle::new(cores, num_workers);
     let (inject, inject_synced) = inject::Shared::new();
 
+    let maintenance_interval = config.maintenance_interval;
+    let lifo_enabled_all = AtomicBool::new(
+        !(config.disable_lifo_slot || config.strict_fifo || config.max_lifo_polls == 0),
+    );
+    let steal_back_enabled = AtomicBool::new(config.steal_back);
+    let inject_paused = AtomicBool::new(false);
+
     let handle = Arc::new(Handle {
         task_hooks: TaskHooks {
             task_spawn_callback: config.before_spawn.clone(),
@@ -311,7 +379,7 @@ pub(super) fn create(
             remotes: remotes.into_boxed_slice(),
             inject,
             idle,
-            owned: OwnedTasks::new(num_cores),
+            owned: OwnedTasks::new(num_cores, config.max_live_tasks()),
             synced: Mutex::new(Synced {
                 assigned_cores: (0..num_workers).map(|_| None).collect(),
                 shutdown_cores: Vec::with_capacity(num_cores),
@@ -323,8 +391,15 @@ pub(super) fn create(
             condvars: (0..num_workers).map(|_| Condvar::new()).collect(),
             trace_status: TraceStatus::new(num_cores),
             config,
+            lifo_enabled_all,
+            steal_back_enabled,
+            inject_paused,
             scheduler_metrics: SchedulerMetrics::new(),
             worker_metrics: worker_metrics.into_boxed_slice(),
+            #[cfg(tokio_unstable)]
+            worker_rng_seeds: worker_rng_seeds.into_boxed_slice(),
+            #[cfg(tokio_unstable)]
+            worker_labels: worker_labels.into_boxed_slice(),
             _counters: Counters,
         },
         driver: driver_handle,
@@ -336,6 +411,10 @@ pub(super) fn create(
         inner: scheduler::Handle::MultiThreadAlt(handle),
     };
 
+    if let Some(interval) = maintenance_interval {
+        spawn_maintenance_monitor(rt_handle.inner.expect_multi_thread_alt(), interval);
+    }
+
     // Eagerly start worker threads
     for index in 0..num_workers {
         let handle = rt_handle.inner.expect_multi_thread_alt();
@@ -350,6 +429,42 @@ pub(super) fn create(
     rt_handle
 }
 
+/// Spawns a dedicated thread that periodically wakes every worker by
+/// notifying its condvar, so that maintenance is reconsidered at a
+/// wall-clock cadence rather than solely whenever a worker's tick counter
+/// crosses `event_interval`.
+///
+/// Shutdown is driven explicitly by `Handle::shutdown` closing the
+/// injection queue, not by this thread's `Arc<Handle>` clone being
+/// dropped, so the monitor thread can hold a strong reference: it simply
+/// checks the injection queue's closed flag on each wake and exits once
+/// shutdown has been observed, instead of relying on a weak reference
+/// (which loom's `Arc` doesn't support).
+fn spawn_maintenance_monitor(handle: &Arc<Handle>, interval: Duration) {
+    let handle = handle.clone();
+
+    let res = thread::Builder::new()
+        .name("tokio-maintenance-timer".into())
+        .spawn(move || loop {
+            thread::sleep(interval);
+
+            let synced = handle.shared.synced.lock();
+            if handle.shared.inject.is_closed(&synced.inject) {
+                return;
+            }
+            drop(synced);
+
+            for condvar in &handle.shared.condvars[..] {
+                condvar.notify_one();
+            }
+        });
+
+    // If spawning the monitor thread fails (e.g. the OS is out of
+    // resources), maintenance simply continues to run on the usual
+    // tick-based schedule.
+    drop(res);
+}
+
 #[track_caller]
 pub(crate) fn block_in_place<F, R>(f: F) -> R
 where
@@ -363,10 +478,18 @@ where
             with_current(|maybe_cx| {
                 if let Some(cx) = maybe_cx {
                     let core = cx.handoff_core.take();
+                    let regained = core.is_some();
                     let mut cx_core = cx.core.borrow_mut();
                     assert!(cx_core.is_none());
                     *cx_core = core;
 
+                    if regained {
+                        cx.handle
+                            .shared
+                            .scheduler_metrics
+                            .dec_outstanding_block_in_place_count();
+                    }
+
                     // Reset the task budget as we are re-entering the
                     // runtime.
                     coop::set(self.0);
@@ -427,10 +550,41 @@ where
             None => return Ok(()),
         };
 
+        // If we are already at the configured cap on worker-running
+        // threads, don't spawn another one to take over this core. Run the
+        // blocking closure inline instead, degrading concurrency on this
+        // worker rather than letting the thread count grow unbounded.
+        if let Some(max) = cx.shared().config.max_worker_threads {
+            if cx.handle.shared.scheduler_metrics.live_worker_thread_count() >= max {
+                *cx.core.borrow_mut() = Some(core);
+                return Ok(());
+            }
+        }
+
+        // Likewise, if we are already at the configured cap on outstanding
+        // `block_in_place` handoffs specifically, run inline instead of
+        // adding another one.
+        if let Some(max) = cx.shared().config.max_concurrent_block_in_place {
+            if cx
+                .handle
+                .shared
+                .scheduler_metrics
+                .outstanding_block_in_place_count()
+                >= max
+            {
+                *cx.core.borrow_mut() = Some(core);
+                return Ok(());
+            }
+        }
+
         // In order to block, the core must be sent to another thread for
         // execution.
         //
         // First, move the core back into the worker's shared core slot.
+        cx.handle
+            .shared
+            .scheduler_metrics
+            .inc_outstanding_block_in_place_count();
         cx.handoff_core.set(core);
 
         // Next, clone the worker handle and send it to a new thread for
@@ -482,6 +636,15 @@ fn run(
     #[cfg(debug_assertions)]
     let _abort_on_panic = AbortOnPanic;
 
+    handle.shared.scheduler_metrics.inc_live_worker_thread_count();
+    struct LiveWorkerThreadGuard(Arc<Handle>);
+    impl Drop for LiveWorkerThreadGuard {
+        fn drop(&mut self) {
+            self.0.shared.scheduler_metrics.dec_live_worker_thread_count();
+        }
+    }
+    let _live_worker_thread_guard = LiveWorkerThreadGuard(handle.clone());
+
     let num_workers = handle.shared.condvars.len();
 
     let mut worker = Worker {
@@ -492,6 +655,13 @@ fn run(
         is_traced: false,
         workers_to_notify: Vec::with_capacity(num_workers - 1),
         idle_snapshot: idle::Snapshot::new(&handle.shared.idle),
+        just_stole_task: false,
+        park_backoff: handle
+            .shared
+            .config
+            .park_backoff
+            .as_ref()
+            .map_or(Duration::ZERO, |backoff| backoff.initial),
         stats: stats::Ephemeral::new(),
     };
 
@@ -501,7 +671,9 @@ fn run(
         // Set the worker context.
         let cx = scheduler::Context::MultiThreadAlt(Context {
             index,
-            lifo_enabled: Cell::new(!handle.shared.config.disable_lifo_slot),
+            lifo_enabled: Cell::new(!(handle.shared.config.disable_lifo_slot
+                || handle.shared.config.strict_fifo
+                || handle.shared.config.max_lifo_polls == 0)),
             handle,
             core: RefCell::new(None),
             handoff_core,
@@ -559,7 +731,7 @@ impl Worker {
                     return Err(());
                 }
             } else {
-                let mut synced = cx.shared().synced.lock();
+                let mut synced = cx.shared().lock_synced_timed();
 
                 // First try to acquire an available core
                 if let Some(core) = self.try_acquire_available_core(cx, &mut synced) {
@@ -577,7 +749,7 @@ impl Worker {
         core.stats.start_processing_scheduled_tasks(&mut self.stats);
 
         if let Some(task) = maybe_task {
-            core = self.run_task(cx, core, task)?;
+            core = self.run_task(cx, core, task, false)?;
         }
 
         while !self.is_shutdown {
@@ -585,7 +757,8 @@ impl Worker {
             core = c;
 
             if let Some(task) = maybe_task {
-                core = self.run_task(cx, core, task)?;
+                let just_stolen = self.just_stole_task;
+                core = self.run_task(cx, core, task, just_stolen)?;
             } else {
                 // The only reason to get `None` from `next_task` is we have
                 // entered the shutdown phase.
@@ -630,6 +803,7 @@ impl Worker {
         if cx.shared().idle.needs_searching() {
             if let Some(mut core) = self.try_acquire_available_core(cx, &mut synced) {
                 cx.shared().idle.transition_worker_to_searching(&mut core);
+                cx.shared().worker_metrics[core.index].set_worker_status_searching();
                 return Ok((None, core));
             }
         }
@@ -650,9 +824,30 @@ impl Worker {
                 return Err(());
             }
 
-            synced = cx.shared().condvars[cx.index].wait(synced).unwrap();
+            match &cx.shared().config.park_backoff {
+                Some(backoff) => {
+                    let (guard, timeout_result) = cx.shared().condvars[cx.index]
+                        .wait_timeout(synced, self.park_backoff)
+                        .unwrap();
+                    synced = guard;
+
+                    // Woken by a timeout rather than a real notification:
+                    // still no core assigned, so escalate the wait for next
+                    // time. A real notification (or one that raced with the
+                    // timeout) is handled below by resetting the backoff
+                    // once a core is actually assigned.
+                    if timeout_result.timed_out() {
+                        self.park_backoff = next_park_backoff(self.park_backoff, backoff.max);
+                    }
+                }
+                None => synced = cx.shared().condvars[cx.index].wait(synced).unwrap(),
+            }
         };
 
+        if let Some(backoff) = &cx.shared().config.park_backoff {
+            self.park_backoff = backoff.initial;
+        }
+
         self.reset_acquired_core(cx, &mut synced, &mut core);
 
         if self.is_shutdown {
@@ -660,7 +855,12 @@ impl Worker {
             return Ok((None, core));
         }
 
-        let n = cmp::max(core.run_queue.remaining_slots() / 2, 1);
+        core.stats.incr_core_acquisitions_count();
+
+        let mut n = cmp::max(core.run_queue.remaining_slots() / 2, 1);
+        if let Some(cap) = cx.shared().config.acquire_core_batch_cap {
+            n = cmp::min(n, cap);
+        }
         let maybe_task = self.next_remote_task_batch_synced(cx, &mut synced, &mut core, n);
 
         core.stats.unparked();
@@ -683,12 +883,20 @@ impl Worker {
 
         // Update shutdown state while locked
         self.update_global_flags(cx, synced);
+
+        if let Some(f) = &cx.shared().config.on_core_acquired {
+            f(core.index);
+        }
     }
 
     /// Finds the next task to run, this could be from a queue or stealing. If
     /// none are available, the thread sleeps and tries again.
     fn next_task(&mut self, cx: &Context, mut core: Box<Core>) -> NextTaskResult {
-        self.assert_lifo_enabled_is_correct(cx);
+        self.just_stole_task = false;
+        // Pick up any `Handle::set_lifo_enabled_all` flip promptly, since it
+        // would otherwise only be observed the next time a task is stolen or
+        // `block_in_place` hands the core back.
+        self.reset_lifo_enabled(cx);
 
         if self.is_traced {
             core = cx.handle.trace_core(core);
@@ -724,7 +932,22 @@ impl Worker {
             // again.
             core = try_task_new_batch!(self, self.search_for_work(cx, core));
 
-            debug_assert!(cx.defer.borrow().is_empty());
+            // Stealing came up empty; this worker is idle for another cycle.
+            core.stats.incr_consecutive_idle();
+
+            if cx.handle.shared.config.strict_defer_assertions {
+                // This should never happen: catch the underlying logic error
+                // as soon as possible, before we park with unflushed wakers.
+                debug_assert!(cx.defer.borrow().is_empty());
+            } else if !cx.defer.borrow().is_empty() {
+                // Rather than parking with deferred tasks still pending
+                // (which would delay them until this worker happens to wake
+                // up again), flush them out via a driver poll and retry the
+                // search for work.
+                core = try_task_new_batch!(self, self.park_yield(cx, core));
+                continue;
+            }
+
             core = try_task_new_batch!(self, self.park(cx, core));
         }
 
@@ -737,6 +960,45 @@ impl Worker {
     fn next_notified_task(&mut self, cx: &Context, mut core: Box<Core>) -> NextTaskResult {
         self.num_seq_local_queue_polls += 1;
 
+        // `Builder::strict_fifo` forces the local-then-inject path below on
+        // every poll, bypassing both the inject-priority override and the
+        // periodic (timing-tuned) inject-first check, since either would
+        // make draining order depend on something other than what's already
+        // queued.
+        if cx.handle.shared.config.strict_fifo {
+            if let Some(task) = core.next_local_task() {
+                core.stats.incr_local_queue_pull_count();
+                return Ok((Some(task), core));
+            }
+
+            let (maybe_task, mut core) = self.next_remote_task_batch(cx, core)?;
+            if maybe_task.is_some() {
+                core.stats.incr_global_queue_pull_count();
+            }
+            return Ok((maybe_task, core));
+        }
+
+        if cx.handle.shared.config.inject_priority_over_local {
+            if self.num_seq_local_queue_polls % self.global_queue_interval == 0 {
+                self.num_seq_local_queue_polls = 0;
+
+                // Update the global queue interval, if needed
+                self.tune_global_queue_interval(cx, &mut core);
+            }
+
+            if let Some(task) = self.next_remote_task(cx) {
+                core.stats.incr_global_queue_pull_count();
+                return Ok((Some(task), core));
+            }
+
+            if let Some(task) = core.next_local_task() {
+                core.stats.incr_local_queue_pull_count();
+                return Ok((Some(task), core));
+            }
+
+            return Ok((None, core));
+        }
+
         if self.num_seq_local_queue_polls % self.global_queue_interval == 0 {
             super::counters::inc_global_queue_interval();
 
@@ -746,28 +1008,34 @@ impl Worker {
             self.tune_global_queue_interval(cx, &mut core);
 
             if let Some(task) = self.next_remote_task(cx) {
+                core.stats.incr_global_queue_pull_count();
                 return Ok((Some(task), core));
             }
         }
 
         if let Some(task) = core.next_local_task() {
+            core.stats.incr_local_queue_pull_count();
             return Ok((Some(task), core));
         }
 
-        self.next_remote_task_batch(cx, core)
+        let (maybe_task, mut core) = self.next_remote_task_batch(cx, core)?;
+        if maybe_task.is_some() {
+            core.stats.incr_global_queue_pull_count();
+        }
+        Ok((maybe_task, core))
     }
 
     fn next_remote_task(&self, cx: &Context) -> Option<Notified> {
-        if cx.shared().inject.is_empty() {
+        if cx.shared().inject_paused.load(Relaxed) || cx.shared().inject.is_empty() {
             return None;
         }
 
-        let mut synced = cx.shared().synced.lock();
+        let mut synced = cx.shared().lock_synced_timed();
         cx.shared().next_remote_task_synced(&mut synced)
     }
 
     fn next_remote_task_batch(&self, cx: &Context, mut core: Box<Core>) -> NextTaskResult {
-        if cx.shared().inject.is_empty() {
+        if cx.shared().inject_paused.load(Relaxed) || cx.shared().inject.is_empty() {
             return Ok((None, core));
         }
 
@@ -780,7 +1048,7 @@ impl Worker {
             usize::max(core.run_queue.max_capacity() / 2, 1),
         );
 
-        let mut synced = cx.shared().synced.lock();
+        let mut synced = cx.shared().lock_synced_timed();
         let maybe_task = self.next_remote_task_batch_synced(cx, &mut synced, &mut core, cap);
         Ok((maybe_task, core))
     }
@@ -806,7 +1074,12 @@ impl Worker {
         let n = usize::min(n, max) + 1;
 
         // safety: passing in the correct `inject::Synced`.
-        let mut tasks = unsafe { cx.shared().inject.pop_n(&mut synced.inject, n) };
+        let tasks = unsafe { cx.shared().inject.pop_n(&mut synced.inject, n) };
+
+        #[cfg(tokio_unstable)]
+        let mut tasks = tasks.inspect(|task| cx.shared().record_inject_queue_wait(Some(task)));
+        #[cfg(not(tokio_unstable))]
+        let mut tasks = tasks;
 
         // Pop the first task to return immediately
         let ret = tasks.next();
@@ -833,6 +1106,12 @@ impl Worker {
         #[cfg(not(loom))]
         debug_assert!(core.run_queue.is_empty());
 
+        if cx.handle.shared.config.strict_fifo {
+            // `Builder::strict_fifo` disables stealing entirely so that task
+            // ordering only ever depends on each worker's own FIFO queue.
+            return Ok((None, core));
+        }
+
         if !core.run_queue.can_steal() {
             return Ok((None, core));
         }
@@ -853,6 +1132,7 @@ impl Worker {
             let start = core.rand.fastrand_n(num as u32) as usize;
 
             if let Some(task) = self.steal_one_round(cx, &mut core, start) {
+                self.just_stole_task = true;
                 return Ok((Some(task), core));
             }
 
@@ -896,9 +1176,17 @@ impl Worker {
         None
     }
 
-    fn run_task(&mut self, cx: &Context, mut core: Box<Core>, task: Notified) -> RunResult {
+    fn run_task(
+        &mut self,
+        cx: &Context,
+        mut core: Box<Core>,
+        task: Notified,
+        just_stolen: bool,
+    ) -> RunResult {
         let task = cx.shared().owned.assert_owner(task);
 
+        core.stats.reset_consecutive_idle();
+
         // Make sure the worker is not in the **searching** state. This enables
         // another idle worker to try to steal work.
         if self.transition_from_searching(cx, &mut core) {
@@ -906,6 +1194,10 @@ impl Worker {
             cx.shared().notify_parked_local();
         }
 
+        let worker_metrics = &cx.shared().worker_metrics[core.index];
+        worker_metrics.set_worker_status_running();
+        worker_metrics.record_poll_start();
+
         self.assert_lifo_enabled_is_correct(cx);
 
         // Measure the poll start time. Note that we may end up polling other
@@ -914,14 +1206,50 @@ impl Worker {
         // purposes. These tasks inherent the "parent"'s limits.
         core.stats.start_poll(&mut self.stats);
 
+        // If this task was just stolen, the rest of the batch it came with is
+        // sitting in `run_queue`. Remember how big that batch is so we can
+        // give it back if this task blocks immediately below.
+        let stolen_batch_len = if just_stolen { core.run_queue.len() } else { 0 };
+
+        let worker_index = core.index;
+
         // Make the core available to the runtime context
         *cx.core.borrow_mut() = Some(core);
 
         // Run the task
-        coop::budget(|| {
+        let task_budget = cx
+            .handle
+            .shared
+            .config
+            .task_budget
+            .as_ref()
+            .map(|f| f(worker_index));
+        coop::budget_with(task_budget, || {
             super::counters::inc_num_polls();
-            task.run();
+            let outcome = task.run();
+            self.record_poll_outcome(cx, &outcome);
+            let is_blocked = outcome.is_blocked;
+
+            if just_stolen
+                && is_blocked
+                && stolen_batch_len > 0
+                && cx.shared().steal_back_enabled.load(Relaxed)
+            {
+                let taken_core = cx.core.borrow_mut().take();
+                if let Some(mut core) = taken_core {
+                    let batch_len = core.run_queue.len();
+                    if batch_len > 0 {
+                        cx.shared()
+                            .push_batch(std::iter::from_fn(|| core.run_queue.pop()));
+                        core.stats.incr_steal_back_count(batch_len as u16);
+                    }
+                    *cx.core.borrow_mut() = Some(core);
+                }
+            }
+
             let mut lifo_polls = 0;
+            let max_lifo_duration = cx.shared().config.max_lifo_duration;
+            let lifo_start = max_lifo_duration.map(|_| Instant::now());
 
             // As long as there is budget remaining and a task exists in the
             // `lifo_slot`, then keep running.
@@ -944,6 +1272,7 @@ impl Worker {
                     None => {
                         self.reset_lifo_enabled(cx);
                         core.stats.end_poll();
+                        core.stats.record_lifo_chain_length(lifo_polls);
                         return Ok(core);
                     }
                 };
@@ -958,9 +1287,27 @@ impl Worker {
                     // If we hit this point, the LIFO slot should be enabled.
                     // There is no need to reset it.
                     debug_assert!(cx.lifo_enabled.get());
+                    core.stats.record_lifo_chain_length(lifo_polls);
                     return Ok(core);
                 }
 
+                // If the loop has been running longer than the configured
+                // wall-clock cap, break out even though the poll-count and
+                // coop budget caps have not been hit yet. This bounds how
+                // long a worker can go without checking other work when
+                // LIFO tasks have highly variable poll cost.
+                if let Some(lifo_start) = lifo_start {
+                    if lifo_start.elapsed() >= max_lifo_duration.unwrap() {
+                        core.stats.end_poll();
+
+                        core.run_queue
+                            .push_back_or_overflow(task, cx.shared(), &mut core.stats);
+                        debug_assert!(cx.lifo_enabled.get());
+                        core.stats.record_lifo_chain_length(lifo_polls);
+                        return Ok(core);
+                    }
+                }
+
                 // Track that we are about to run a task from the LIFO slot.
                 lifo_polls += 1;
                 super::counters::inc_lifo_schedules();
@@ -972,7 +1319,7 @@ impl Worker {
                 // LIFO slot can cause starvation as these two tasks will
                 // repeatedly schedule the other. To mitigate this, we limit the
                 // number of times the LIFO slot is prioritized.
-                if lifo_polls >= MAX_LIFO_POLLS_PER_TICK {
+                if lifo_polls >= cx.shared().config.max_lifo_polls {
                     cx.lifo_enabled.set(false);
                     super::counters::inc_lifo_capped();
                 }
@@ -981,7 +1328,8 @@ impl Worker {
                 *cx.core.borrow_mut() = Some(core);
                 let task = cx.shared().owned.assert_owner(task);
                 super::counters::inc_num_lifo_polls();
-                task.run();
+                let outcome = task.run();
+                self.record_poll_outcome(cx, &outcome);
             }
         })
     }
@@ -1030,7 +1378,7 @@ impl Worker {
 
         // Notify any workers
         for worker in self.workers_to_notify.drain(..) {
-            cx.shared().condvars[worker].notify_one()
+            super::idle::notify_core_available(cx.shared(), worker);
         }
 
         if !defer.is_empty() {
@@ -1085,7 +1433,15 @@ impl Worker {
     }
 
     fn flush_metrics(&self, cx: &Context, core: &mut Core) {
-        core.stats.submit(&cx.shared().worker_metrics[core.index]);
+        core.stats.submit(
+            &cx.shared().worker_metrics[core.index],
+            self.global_queue_interval,
+            core.run_queue.remaining_slots(),
+        );
+        #[cfg(tokio_unstable)]
+        if let Some(sink) = &cx.shared().config.metrics_sink {
+            sink(core.index, &core.stats.last_delta());
+        }
     }
 
     fn update_global_flags(&mut self, cx: &Context, synced: &mut Synced) {
@@ -1102,7 +1458,18 @@ impl Worker {
         // Call `park` with a 0 timeout. This enables the I/O driver, timer, ...
         // to run without actually putting the thread to sleep.
         if let Some(mut driver) = cx.shared().driver.take() {
-            driver.park_timeout(&cx.handle.driver, Duration::from_millis(0));
+            match &cx.shared().config.driver_park_strategy {
+                Some(strategy) => strategy(
+                    &mut driver,
+                    &cx.handle.driver,
+                    Some(Duration::from_millis(0)),
+                ),
+                None => driver.park_timeout(&cx.handle.driver, Duration::from_millis(0)),
+            }
+
+            if let Some(f) = &cx.shared().config.on_driver_poll {
+                f();
+            }
 
             cx.shared().driver.set(driver);
         }
@@ -1139,7 +1506,7 @@ impl Worker {
             f();
         }
 
-        if self.can_transition_to_parked(&mut core) {
+        if self.can_transition_to_parked(cx, &mut core) {
             debug_assert!(!self.is_shutdown);
             debug_assert!(!self.is_traced);
 
@@ -1157,7 +1524,7 @@ impl Worker {
         let was_searching = core.is_searching;
 
         // Acquire the lock
-        let mut synced = cx.shared().synced.lock();
+        let mut synced = cx.shared().lock_synced_timed();
 
         // The local queue should be empty at this point
         #[cfg(not(loom))]
@@ -1176,6 +1543,7 @@ impl Worker {
                 .transition_worker_to_searching_if_needed(&mut synced.idle, &mut core)
             {
                 // Skip parking, go back to searching
+                cx.shared().worker_metrics[core.index].set_worker_status_searching();
                 return Ok((None, core));
             }
         }
@@ -1194,21 +1562,28 @@ impl Worker {
 
         // Release the core
         core.is_searching = false;
+        let index = core.index;
+        cx.shared().worker_metrics[index].set_worker_status_parked();
         cx.shared().idle.release_core(&mut synced, core);
 
         drop(synced);
 
+        if let Some(f) = &cx.shared().config.on_core_released {
+            f(index);
+        }
+
         if was_searching {
             if cx.shared().idle.transition_worker_from_searching() {
                 // cx.shared().idle.snapshot(&mut self.idle_snapshot);
                 // We were the last searching worker, we need to do one last check
                 for i in 0..cx.shared().remotes.len() {
                     if !cx.shared().remotes[i].steal.is_empty() {
-                        let mut synced = cx.shared().synced.lock();
+                        let mut synced = cx.shared().lock_synced_timed();
 
                         // Try to get a core
                         if let Some(mut core) = self.try_acquire_available_core(cx, &mut synced) {
                             cx.shared().idle.transition_worker_to_searching(&mut core);
+                            cx.shared().worker_metrics[core.index].set_worker_status_searching();
                             return Ok((None, core));
                         } else {
                             // Fall back to the park routine
@@ -1221,9 +1596,16 @@ impl Worker {
 
         if let Some(mut driver) = cx.shared().take_driver() {
             // Wait for driver events
-            driver.park(&cx.handle.driver);
+            match &cx.shared().config.driver_park_strategy {
+                Some(strategy) => strategy(&mut driver, &cx.handle.driver, None),
+                None => driver.park(&cx.handle.driver),
+            }
 
-            synced = cx.shared().synced.lock();
+            if let Some(f) = &cx.shared().config.on_driver_poll {
+                f();
+            }
+
+            synced = cx.shared().lock_synced_timed();
 
             if cx.shared().inject.is_closed(&mut synced.inject) {
                 synced.shutdown_driver = Some(driver);
@@ -1247,7 +1629,7 @@ impl Worker {
                 self.wait_for_core(cx, synced)
             }
         } else {
-            synced = cx.shared().synced.lock();
+            synced = cx.shared().lock_synced_timed();
 
             // Wait for a core to be assigned to us
             self.wait_for_core(cx, synced)
@@ -1257,6 +1639,10 @@ impl Worker {
     fn transition_to_searching(&self, cx: &Context, core: &mut Core) -> bool {
         if !core.is_searching {
             cx.shared().idle.try_transition_worker_to_searching(core);
+
+            if core.is_searching {
+                cx.shared().worker_metrics[core.index].set_worker_status_searching();
+            }
         }
 
         core.is_searching
@@ -1272,23 +1658,59 @@ impl Worker {
         cx.shared().idle.transition_worker_from_searching()
     }
 
-    fn can_transition_to_parked(&self, core: &mut Core) -> bool {
-        !self.has_tasks(core) && !self.is_shutdown && !self.is_traced
+    fn can_transition_to_parked(&self, cx: &Context, core: &mut Core) -> bool {
+        !self.has_tasks(core)
+            && !self.is_shutdown
+            && !self.is_traced
+            && !self.would_drop_below_min_active_workers(cx)
+    }
+
+    /// Returns `true` if releasing this worker's core to park would drop the
+    /// number of active workers below the configured `min_active_workers`
+    /// floor.
+    fn would_drop_below_min_active_workers(&self, cx: &Context) -> bool {
+        let min_active_workers = cx.shared().config.min_active_workers;
+        if min_active_workers == 0 {
+            return false;
+        }
+
+        let synced = cx.shared().synced.lock();
+        idle::num_active_workers(&synced.idle) <= min_active_workers
     }
 
     fn has_tasks(&self, core: &Core) -> bool {
         core.lifo_slot.is_some() || !core.run_queue.is_empty()
     }
 
+    /// Records whether a just-finished `task.run()` completed the task, for
+    /// the `worker_completed_poll_count`/`worker_pending_poll_count` metrics.
+    /// A no-op if the core was taken from us while the task ran (e.g. by
+    /// `block_in_place`), since there's nowhere to attribute the stat.
+    fn record_poll_outcome(&self, cx: &Context, outcome: &crate::runtime::task::PollOutcome) {
+        if let Some(core) = cx.core.borrow_mut().as_mut() {
+            if outcome.is_complete {
+                core.stats.incr_completed_poll_count();
+                core.stats.incr_completed_tasks();
+            } else if outcome.is_blocked {
+                core.stats.incr_pending_poll_count();
+            }
+        }
+    }
+
     fn reset_lifo_enabled(&self, cx: &Context) {
         cx.lifo_enabled
-            .set(!cx.handle.shared.config.disable_lifo_slot);
+            .set(cx.handle.shared.lifo_enabled_all.load(Relaxed));
     }
 
+    // Note: `cx.lifo_enabled` is only refreshed at `reset_lifo_enabled` call
+    // sites, so a `Handle::set_lifo_enabled_all` flip that lands between one
+    // of those and this check can legitimately trip this assertion for one
+    // worker until it next calls `reset_lifo_enabled`. This is expected: see
+    // `Shared::lifo_enabled_all`.
     fn assert_lifo_enabled_is_correct(&self, cx: &Context) {
         debug_assert_eq!(
             cx.lifo_enabled.get(),
-            !cx.handle.shared.config.disable_lifo_slot
+            cx.handle.shared.lifo_enabled_all.load(Relaxed)
         );
     }
 
@@ -1324,6 +1746,37 @@ impl Context {
     pub(crate) fn get_worker_index(&self) -> usize {
         self.index
     }
+
+    cfg_unstable! {
+        /// See `multi_thread::Context::yield_core_hint`. Best-effort hint
+        /// that offers this worker's queued backlog to idle peers without
+        /// giving up the core.
+        pub(crate) fn yield_core_hint(&self) {
+            let mut lock = self.core.borrow_mut();
+            let core = match lock.as_mut() {
+                Some(core) => core,
+                None => return,
+            };
+
+            if core.run_queue.is_empty() {
+                return;
+            }
+
+            self.shared()
+                .push_batch(std::iter::from_fn(|| core.run_queue.pop()));
+
+            drop(lock);
+
+            self.shared().notify_parked_local();
+        }
+
+        /// Returns `true` once the injection queue has been closed, which
+        /// happens as part of runtime shutdown.
+        pub(crate) fn shutdown_requested(&self) -> bool {
+            let synced = self.shared().synced.lock();
+            self.shared().inject.is_closed(&synced.inject)
+        }
+    }
 }
 
 impl Core {
@@ -1339,7 +1792,78 @@ impl Core {
 impl Shared {
     fn next_remote_task_synced(&self, synced: &mut Synced) -> Option<Notified> {
         // safety: we only have access to a valid `Synced` in this file.
-        unsafe { self.inject.pop(&mut synced.inject) }
+        let task = unsafe { self.inject.pop(&mut synced.inject) };
+
+        #[cfg(tokio_unstable)]
+        self.record_inject_queue_wait(task.as_ref());
+
+        task
+    }
+
+    /// Feeds `SchedulerMetrics::mean_inject_queue_wait` with the time a
+    /// just-popped task spent sitting in the injection queue, if it came
+    /// from there.
+    #[cfg(tokio_unstable)]
+    fn record_inject_queue_wait(&self, task: Option<&Notified>) {
+        if let Some(enqueued_at) = task.and_then(|task| task.take_inject_enqueued_at()) {
+            self.scheduler_metrics
+                .record_inject_queue_wait(enqueued_at.elapsed());
+        }
+    }
+
+    /// Acquires `synced`, occasionally timing how long the acquisition took
+    /// and folding the result into
+    /// `SchedulerMetrics::synced_lock_contention_time`.
+    ///
+    /// Used on the hot paths (`next_task`, acquiring a core, `do_park`,
+    /// pushing to the injection queue) where contention on this mutex would
+    /// actually be felt; see `SchedulerMetrics::sample_synced_lock_contention`
+    /// for the sampling rate.
+    #[cfg(tokio_unstable)]
+    fn lock_synced_timed(&self) -> MutexGuard<'_, Synced> {
+        if self.scheduler_metrics.sample_synced_lock_contention() {
+            let start = Instant::now();
+            let synced = self.synced.lock();
+            self.scheduler_metrics
+                .record_synced_lock_contention(start.elapsed());
+            synced
+        } else {
+            self.synced.lock()
+        }
+    }
+
+    /// Schedules a freshly spawned task, consulting `Config::placement` (if
+    /// configured) exactly once to decide where it should land.
+    #[cfg(tokio_unstable)]
+    pub(super) fn schedule_new_task(&self, task: Option<Notified>, meta: &TaskMeta<'_>) {
+        let task = match task {
+            Some(task) => task,
+            None => return,
+        };
+
+        let placement = self
+            .config
+            .placement
+            .as_ref()
+            .map_or(Placement::Auto, |f| f(meta));
+
+        match placement {
+            Placement::Auto => self.schedule_task(task, false),
+            // This scheduler's workers don't own a fixed local queue —
+            // cores are acquired and released dynamically — so there is no
+            // stable per-worker target to push into directly. Treat a
+            // worker preference the same as `Inject`.
+            Placement::Inject | Placement::Worker(_) => self.schedule_remote(task),
+        }
+    }
+
+    /// Schedules a freshly spawned task using the scheduler's default
+    /// placement.
+    #[cfg(not(tokio_unstable))]
+    pub(super) fn schedule_new_task(&self, task: Option<Notified>, _meta: &TaskMeta<'_>) {
+        if let Some(task) = task {
+            self.schedule_task(task, false);
+        }
     }
 
     pub(super) fn schedule_task(&self, task: Notified, is_yield: bool) {
@@ -1349,6 +1873,18 @@ impl Shared {
             if let Some(cx) = maybe_cx {
                 // Make sure the task is part of the **current** scheduler.
                 if ptr::eq(self, &cx.handle.shared) {
+                    // `is_yield` is only ever `true` when this call came from
+                    // `yield_now`, which is exactly the path `Harness::poll`
+                    // takes when a task's own poll notified its own waker:
+                    // the task can't be resubmitted mid-poll, so it's queued
+                    // for another go once the current poll returns.
+                    #[cfg(tokio_unstable)]
+                    if is_yield && self.config.track_self_wake_count {
+                        if let Some(core) = cx.core.borrow().as_ref() {
+                            self.worker_metrics[core.index].incr_self_wake_count();
+                        }
+                    }
+
                     // And the current thread still holds a core
                     if let Some(core) = cx.core.borrow_mut().as_mut() {
                         if is_yield {
@@ -1374,7 +1910,10 @@ impl Shared {
     fn schedule_local(&self, cx: &Context, core: &mut Core, task: Notified) {
         core.stats.inc_local_schedule_count();
 
-        if cx.lifo_enabled.get() {
+        // Tasks that were ever stolen into this worker's queue always go to
+        // the back, so that a run of locally-generated self-wake ping-pong
+        // can't monopolize the LIFO slot and starve them.
+        if cx.lifo_enabled.get() && !task.is_stolen() {
             // Push to the LIFO slot
             let prev = std::mem::replace(&mut core.lifo_slot, Some(task));
             // let prev = cx.shared().remotes[core.index].lifo_slot.swap_local(task);
@@ -1398,17 +1937,21 @@ impl Shared {
         self.idle.notify_local(self);
     }
 
-    fn schedule_remote(&self, task: Notified) {
+    pub(super) fn schedule_remote(&self, task: Notified) {
         super::counters::inc_num_notify_remote();
         self.scheduler_metrics.inc_remote_schedule_count();
 
-        let mut synced = self.synced.lock();
+        let mut synced = self.lock_synced_timed();
         // Push the task in the
-        self.push_remote_task(&mut synced, task);
+        let became_nonempty = self.push_remote_task(&mut synced, task);
 
         // Notify a worker. The mutex is passed in and will be released as part
         // of the method call.
         self.idle.notify_remote(synced, self);
+
+        if became_nonempty {
+            self.notify_inject_nonempty();
+        }
     }
 
     pub(super) fn close(&self, handle: &Handle) {
@@ -1432,10 +1975,18 @@ impl Shared {
         self.idle.shutdown_unassigned_cores(handle, self);
     }
 
-    fn push_remote_task(&self, synced: &mut Synced, task: Notified) {
+    /// Returns `true` if this caused the injection queue to transition from
+    /// empty to non-empty.
+    fn push_remote_task(&self, synced: &mut Synced, task: Notified) -> bool {
         // safety: passing in correct `idle::Synced`
-        unsafe {
-            self.inject.push(&mut synced.inject, task);
+        unsafe { self.inject.push(&mut synced.inject, task) }
+    }
+
+    /// Runs the `on_inject_nonempty` callback, if configured. Must be called
+    /// without holding `self.synced`'s lock.
+    fn notify_inject_nonempty(&self) {
+        if let Some(f) = &self.config.on_inject_nonempty {
+            f();
         }
     }
 
@@ -1474,7 +2025,20 @@ impl Shared {
         let start = core.rand.fastrand_n(self.owned.get_shard_size() as u32);
         self.owned.close_and_shutdown_all(start as usize);
 
-        core.stats.submit(&self.worker_metrics[core.index]);
+        // There is no live `Worker` here to read the current
+        // `global_queue_interval` from, so just republish whatever was last
+        // recorded for this worker.
+        let global_queue_interval = self.worker_metrics[core.index].global_queue_interval();
+        let run_queue_remaining = core.run_queue.remaining_slots();
+        core.stats.submit(
+            &self.worker_metrics[core.index],
+            global_queue_interval,
+            run_queue_remaining,
+        );
+        #[cfg(tokio_unstable)]
+        if let Some(sink) = &self.config.metrics_sink {
+            sink(core.index, &core.stats.last_delta());
+        }
 
         let mut synced = self.synced.lock();
         synced.shutdown_cores.push(core);
@@ -1496,9 +2060,22 @@ impl Shared {
 
         debug_assert!(self.owned.is_empty());
 
-        for mut core in synced.shutdown_cores.drain(..) {
+        let cores: Vec<_> = synced.shutdown_cores.drain(..).collect();
+
+        for mut core in cores {
             // Drain tasks from the local queue
             while core.next_local_task().is_some() {}
+
+            // Now that every collection has been closed then emptied, none
+            // of them should be able to contain a task. Verify that eagerly
+            // here so that a shutdown-ordering regression is caught at the
+            // point it happens rather than surfacing later as a leak.
+            #[cfg(any(debug_assertions, tokio_unstable))]
+            assert!(
+                core.lifo_slot.is_none() && core.run_queue.is_empty(),
+                "a worker's local run queue is not empty after shutdown \
+                 drained it"
+            );
         }
 
         // Shutdown the driver
@@ -1515,12 +2092,30 @@ impl Shared {
         while let Some(task) = self.next_remote_task_synced(synced) {
             drop(task);
         }
+
+        #[cfg(any(debug_assertions, tokio_unstable))]
+        assert!(
+            self.owned.is_empty(),
+            "OwnedTasks is not empty after shutdown drained it; a task was \
+             spawned after shutdown closed it without observing the close bit"
+        );
+
+        #[cfg(any(debug_assertions, tokio_unstable))]
+        assert!(
+            self.inject.is_empty(),
+            "the inject queue is not empty after shutdown drained it; a task \
+             was pushed to it after shutdown drained it"
+        );
     }
 }
 
 impl Overflow<Arc<Handle>> for Shared {
     fn push(&self, task: task::Notified<Arc<Handle>>) {
-        self.push_remote_task(&mut self.synced.lock(), task);
+        let became_nonempty = self.push_remote_task(&mut self.lock_synced_timed(), task);
+
+        if became_nonempty {
+            self.notify_inject_nonempty();
+        }
     }
 
     fn push_batch<I>(&self, iter: I)
@@ -1569,6 +2164,10 @@ impl task::Schedule for Arc<Handle> {
     fn yield_now(&self, task: Notified) {
         self.shared.schedule_task(task, true);
     }
+
+    fn task_pooling_enabled(&self) -> bool {
+        self.shared.config.task_pooling
+    }
 }
 
 impl AsMut<Synced> for Synced {
@@ -1602,3 +2201,27 @@ fn with_current<R>(f: impl FnOnce(Option<&Context>) -> R) -> R {
         _ => f(None),
     })
 }
+
+/// Doubles `current`, capped at `max`. Used to escalate a worker's park
+/// timeout each time its timed park runs out with no core assigned to it.
+fn next_park_backoff(current: Duration, max: Duration) -> Duration {
+    (current * 2).min(max)
+}
+
+#[test]
+fn next_park_backoff_doubles_and_caps_at_max() {
+    let max = Duration::from_secs(1);
+
+    let mut backoff = Duration::from_millis(10);
+    backoff = next_park_backoff(backoff, max);
+    assert_eq!(backoff, Duration::from_millis(20));
+
+    backoff = next_park_backoff(backoff, max);
+    assert_eq!(backoff, Duration::from_millis(40));
+
+    // Keep doubling well past `max`; it should never exceed it.
+    for _ in 0..10 {
+        backoff = next_park_backoff(backoff, max);
+    }
+    assert_eq!(backoff, max);
+}
@@ -8,4 +8,11 @@ impl Shared {
     pub(crate) fn worker_local_queue_depth(&self, worker: usize) -> usize {
         self.remotes[worker].steal.len()
     }
+
+    /// Best-effort snapshot of how many additional tasks the given worker's
+    /// local run queue could accept as of its last stats submission, before
+    /// it would overflow to the injection queue.
+    pub(crate) fn worker_run_queue_remaining(&self, worker: usize) -> usize {
+        self.worker_metrics[worker].run_queue_remaining()
+    }
 }
@@ -14,7 +14,7 @@ use crate::loom::cell::UnsafeCell;
 use crate::runtime::context;
 use crate::runtime::task::raw::{self, Vtable};
 use crate::runtime::task::state::State;
-use crate::runtime::task::{Id, Schedule, TaskHarnessScheduleHooks};
+use crate::runtime::task::{Id, Schedule, TaskHarnessScheduleHooks, TaskPriority};
 use crate::util::linked_list;
 
 use std::num::NonZeroU64;
@@ -173,6 +173,42 @@ pub(crate) struct Header {
     /// The tracing ID for this instrumented task.
     #[cfg(all(tokio_unstable, feature = "tracing"))]
     pub(super) tracing_id: Option<tracing::Id>,
+
+    /// When the task was last pushed onto an injection queue, used to
+    /// compute `SchedulerMetrics::mean_inject_queue_wait`. `None` if the
+    /// task has never been on an injection queue. Like `queue_next`, only
+    /// ever accessed while a `Notified` handle grants exclusive access to
+    /// the task's injection-queue bookkeeping.
+    #[cfg(tokio_unstable)]
+    pub(super) inject_enqueued_at: UnsafeCell<Option<std::time::Instant>>,
+
+    /// The number of times this task has been moved from one worker's local
+    /// queue to another's by the work-stealing queue.
+    ///
+    /// This adds a single `u32` field to every task, regardless of whether
+    /// it is ever stolen.
+    #[cfg(tokio_unstable)]
+    pub(super) migration_count: UnsafeCell<u32>,
+
+    /// Set the first time this task is moved into a worker's local queue by
+    /// the work-stealing queue, and never cleared afterwards. Only ever
+    /// accessed while a `Notified` handle grants exclusive access to the
+    /// task, like `queue_next`. Used by the multi-threaded scheduler to keep
+    /// such tasks off the LIFO fast path, so that a run of locally-generated
+    /// self-wake ping-pong can't starve them indefinitely.
+    pub(super) stolen: UnsafeCell<bool>,
+
+    /// Set at spawn time and never changed afterwards. When set, the
+    /// multi-threaded scheduler never places this task on a worker's local
+    /// queue or on the injection queue: it lives in `Shared::idle_tasks`
+    /// instead, and is only polled once a worker's `steal_work` comes up
+    /// empty. See `Handle::spawn_when_idle`.
+    pub(super) when_idle: bool,
+
+    /// Set at spawn time and never changed afterwards. Consulted by the
+    /// multi-threaded scheduler's local-queue placement and work-stealing
+    /// order. See `Handle::spawn_with_priority`.
+    pub(super) priority: TaskPriority,
 }
 
 unsafe impl Send for Header {}
@@ -205,15 +241,63 @@ pub(super) enum Stage<T: Future> {
     Consumed,
 }
 
-impl<T: Future, S: Schedule> Cell<T, S> {
+impl<T: Future + 'static, S: Schedule> Cell<T, S> {
     /// Allocates a new task cell, containing the header, trailer, and core
     /// structures.
-    pub(super) fn new(future: T, scheduler: S, state: State, task_id: Id) -> Box<Cell<T, S>> {
+    pub(super) fn new(
+        future: T,
+        scheduler: S,
+        state: State,
+        task_id: Id,
+        when_idle: bool,
+        priority: TaskPriority,
+    ) -> Box<Cell<T, S>> {
+        let result = Box::new(Self::build(
+            future, scheduler, state, task_id, when_idle, priority,
+        ));
+
+        #[cfg(debug_assertions)]
+        Self::debug_check_offsets(&result);
+
+        result
+    }
+
+    /// Overwrites a previously allocated, now-vacated `Cell` in place with a
+    /// fresh task, reusing its allocation instead of freeing it and
+    /// allocating a new one. Used to implement `Config::task_pooling`.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn recycle(
+        mut existing: Box<Cell<T, S>>,
+        future: T,
+        scheduler: S,
+        state: State,
+        task_id: Id,
+        when_idle: bool,
+        priority: TaskPriority,
+    ) -> Box<Cell<T, S>> {
+        *existing = Self::build(future, scheduler, state, task_id, when_idle, priority);
+
+        #[cfg(debug_assertions)]
+        Self::debug_check_offsets(&existing);
+
+        existing
+    }
+
+    fn build(
+        future: T,
+        scheduler: S,
+        state: State,
+        task_id: Id,
+        when_idle: bool,
+        priority: TaskPriority,
+    ) -> Cell<T, S> {
         // Separated into a non-generic function to reduce LLVM codegen
         fn new_header(
             state: State,
             vtable: &'static Vtable,
             #[cfg(all(tokio_unstable, feature = "tracing"))] tracing_id: Option<tracing::Id>,
+            when_idle: bool,
+            priority: TaskPriority,
         ) -> Header {
             Header {
                 state,
@@ -222,19 +306,28 @@ impl<T: Future, S: Schedule> Cell<T, S> {
                 owner_id: UnsafeCell::new(None),
                 #[cfg(all(tokio_unstable, feature = "tracing"))]
                 tracing_id,
+                #[cfg(tokio_unstable)]
+                inject_enqueued_at: UnsafeCell::new(None),
+                #[cfg(tokio_unstable)]
+                migration_count: UnsafeCell::new(0),
+                stolen: UnsafeCell::new(false),
+                when_idle,
+                priority,
             }
         }
 
         #[cfg(all(tokio_unstable, feature = "tracing"))]
         let tracing_id = future.id();
         let vtable = raw::vtable::<T, S>();
-        let result = Box::new(Cell {
+        Cell {
             trailer: Trailer::new(scheduler.hooks()),
             header: new_header(
                 state,
                 vtable,
                 #[cfg(all(tokio_unstable, feature = "tracing"))]
                 tracing_id,
+                when_idle,
+                priority,
             ),
             core: Core {
                 scheduler,
@@ -243,35 +336,33 @@ impl<T: Future, S: Schedule> Cell<T, S> {
                 },
                 task_id,
             },
-        });
-
-        #[cfg(debug_assertions)]
-        {
-            // Using a separate function for this code avoids instantiating it separately for every `T`.
-            unsafe fn check<S>(header: &Header, trailer: &Trailer, scheduler: &S, task_id: &Id) {
-                let trailer_addr = trailer as *const Trailer as usize;
-                let trailer_ptr = unsafe { Header::get_trailer(NonNull::from(header)) };
-                assert_eq!(trailer_addr, trailer_ptr.as_ptr() as usize);
-
-                let scheduler_addr = scheduler as *const S as usize;
-                let scheduler_ptr = unsafe { Header::get_scheduler::<S>(NonNull::from(header)) };
-                assert_eq!(scheduler_addr, scheduler_ptr.as_ptr() as usize);
-
-                let id_addr = task_id as *const Id as usize;
-                let id_ptr = unsafe { Header::get_id_ptr(NonNull::from(header)) };
-                assert_eq!(id_addr, id_ptr.as_ptr() as usize);
-            }
-            unsafe {
-                check(
-                    &result.header,
-                    &result.trailer,
-                    &result.core.scheduler,
-                    &result.core.task_id,
-                );
-            }
         }
+    }
 
-        result
+    #[cfg(debug_assertions)]
+    fn debug_check_offsets(result: &Cell<T, S>) {
+        // Using a separate function for this code avoids instantiating it separately for every `T`.
+        unsafe fn check<S>(header: &Header, trailer: &Trailer, scheduler: &S, task_id: &Id) {
+            let trailer_addr = trailer as *const Trailer as usize;
+            let trailer_ptr = unsafe { Header::get_trailer(NonNull::from(header)) };
+            assert_eq!(trailer_addr, trailer_ptr.as_ptr() as usize);
+
+            let scheduler_addr = scheduler as *const S as usize;
+            let scheduler_ptr = unsafe { Header::get_scheduler::<S>(NonNull::from(header)) };
+            assert_eq!(scheduler_addr, scheduler_ptr.as_ptr() as usize);
+
+            let id_addr = task_id as *const Id as usize;
+            let id_ptr = unsafe { Header::get_id_ptr(NonNull::from(header)) };
+            assert_eq!(id_addr, id_ptr.as_ptr() as usize);
+        }
+        unsafe {
+            check(
+                &result.header,
+                &result.trailer,
+                &result.core.scheduler,
+                &result.core.task_id,
+            );
+        }
     }
 }
 
@@ -404,6 +495,34 @@ impl Header {
         unsafe { self.owner_id.with(|ptr| *ptr) }
     }
 
+    /// Returns `true` if this task was spawned via `Handle::spawn_when_idle`
+    /// and must bypass the local and injection queues. Fixed at spawn time.
+    pub(super) fn is_when_idle(&self) -> bool {
+        self.when_idle
+    }
+
+    /// Returns this task's scheduling priority. Fixed at spawn time.
+    pub(super) fn priority(&self) -> TaskPriority {
+        self.priority
+    }
+
+    // safety: The caller must guarantee exclusive access to this task, e.g.
+    // by holding the `Notified` handle that grants it, like `queue_next`.
+    pub(super) unsafe fn set_stolen(&self) {
+        self.stolen.with_mut(|ptr| *ptr = true);
+    }
+
+    /// Returns `true` if this task has ever been moved into a worker's local
+    /// queue by the work-stealing queue.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee exclusive access to this task, e.g. by
+    /// holding the `Notified` handle that grants it, like `queue_next`.
+    pub(super) unsafe fn is_stolen(&self) -> bool {
+        self.stolen.with(|ptr| *ptr)
+    }
+
     /// Gets a pointer to the `Trailer` of the task containing this `Header`.
     ///
     /// # Safety
@@ -184,6 +184,36 @@ impl From<JoinError> for io::Error {
     }
 }
 
+cfg_unstable! {
+    /// Task was not spawned because [`Builder::max_live_tasks`] was reached.
+    ///
+    /// Returned by [`Handle::try_spawn`] instead of a [`JoinHandle`], so the
+    /// caller finds out about the rejection immediately instead of having to
+    /// await a handle that was doomed from the start.
+    ///
+    /// [`Builder::max_live_tasks`]: crate::runtime::Builder::max_live_tasks
+    /// [`Handle::try_spawn`]: crate::runtime::Handle::try_spawn
+    /// [`JoinHandle`]: crate::task::JoinHandle
+    #[derive(Debug)]
+    pub struct TrySpawnError {
+        _private: (),
+    }
+
+    impl TrySpawnError {
+        pub(crate) fn at_capacity() -> TrySpawnError {
+            TrySpawnError { _private: () }
+        }
+    }
+
+    impl fmt::Display for TrySpawnError {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(fmt, "runtime is at its configured max_live_tasks")
+        }
+    }
+
+    impl std::error::Error for TrySpawnError {}
+}
+
 fn panic_payload_as_str(payload: &SyncWrapper<Box<dyn Any + Send>>) -> Option<&str> {
     // Panic payloads are almost always `String` (if invoked with formatting arguments)
     // or `&'static str` (if invoked with a string literal).
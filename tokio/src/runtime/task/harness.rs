@@ -2,6 +2,7 @@ use crate::future::Future;
 use crate::runtime::task::core::{Cell, Core, Header, Trailer};
 use crate::runtime::task::state::{Snapshot, State};
 use crate::runtime::task::waker::waker_ref;
+use crate::runtime::task::raw::PollOutcome;
 use crate::runtime::task::{Id, JoinError, Notified, RawTask, Schedule, Task};
 
 use crate::runtime::TaskMeta;
@@ -136,7 +137,7 @@ impl RawTask {
 
 impl<T, S> Harness<T, S>
 where
-    T: Future,
+    T: Future + 'static,
     S: Schedule,
 {
     pub(super) fn drop_reference(self) {
@@ -149,7 +150,11 @@ where
     ///
     /// All necessary state checks and transitions are performed.
     /// Panics raised while polling the future are handled.
-    pub(super) fn poll(self) {
+    ///
+    /// See [`PollOutcome`] for the meaning of the returned value. `is_blocked`
+    /// is the "the task blocked" signal used by schedulers to decide whether
+    /// running it was worthwhile.
+    pub(super) fn poll(self) -> PollOutcome {
         // We pass our ref-count to `poll_inner`.
         match self.poll_inner() {
             PollFuture::Notified => {
@@ -164,14 +169,29 @@ where
                 // call drops the provided task, the task isn't deallocated
                 // before after `yield_now` returns.
                 self.drop_reference();
+                PollOutcome {
+                    is_blocked: false,
+                    is_complete: false,
+                }
             }
             PollFuture::Complete => {
                 self.complete();
+                PollOutcome {
+                    is_blocked: false,
+                    is_complete: true,
+                }
             }
             PollFuture::Dealloc => {
                 self.dealloc();
+                PollOutcome {
+                    is_blocked: false,
+                    is_complete: false,
+                }
             }
-            PollFuture::Done => (),
+            PollFuture::Done => PollOutcome {
+                is_blocked: true,
+                is_complete: false,
+            },
         }
     }
 
@@ -270,7 +290,10 @@ where
         // are allowed to be dangling after their last use, even if the
         // reference has not yet gone out of scope.
         unsafe {
-            drop(Box::from_raw(self.cell.as_ptr()));
+            let cell = Box::from_raw(self.cell.as_ptr());
+            if cell.core.scheduler.task_pooling_enabled() {
+                super::pool::recycle(cell);
+            }
         }
     }
 
@@ -59,6 +59,10 @@ pub(crate) struct OwnedTasks<S: 'static> {
     list: List<S>,
     pub(crate) id: NonZeroU64,
     closed: AtomicBool,
+    /// Caps how many tasks this collection will admit at once, checked
+    /// against `list.len()` in `bind_inner`. `None` means unbounded, i.e.
+    /// the existing behavior. See `Config::max_live_tasks`.
+    max: Option<usize>,
 }
 
 type List<S> = sharded_list::ShardedList<Task<S>, <Task<S> as Link>::Target>;
@@ -75,29 +79,32 @@ struct OwnedTasksInner<S: 'static> {
 }
 
 impl<S: 'static> OwnedTasks<S> {
-    pub(crate) fn new(num_cores: usize) -> Self {
+    pub(crate) fn new(num_cores: usize, max: Option<usize>) -> Self {
         let shard_size = Self::gen_shared_list_size(num_cores);
         Self {
             list: List::new(shard_size),
             closed: AtomicBool::new(false),
             id: get_next_id(),
+            max,
         }
     }
 
     /// Binds the provided task to this `OwnedTasks` instance. This fails if the
-    /// `OwnedTasks` has been closed.
+    /// `OwnedTasks` has been closed or its `max` cap has been reached.
     pub(crate) fn bind<T>(
         &self,
         task: T,
         scheduler: S,
         id: super::Id,
+        when_idle: bool,
+        priority: super::TaskPriority,
     ) -> (JoinHandle<T::Output>, Option<Notified<S>>)
     where
         S: Schedule,
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
-        let (task, notified, join) = super::new_task(task, scheduler, id);
+        let (task, notified, join) = super::new_task(task, scheduler, id, when_idle, priority);
         let notified = unsafe { self.bind_inner(task, notified) };
         (join, notified)
     }
@@ -121,6 +128,18 @@ impl<S: 'static> OwnedTasks<S> {
             task.shutdown();
             return None;
         }
+        // Reject the task the same way as a closed collection if it would
+        // put us over `max`. `list.len()` is exact and lock-free, but this
+        // isn't holding a lock covering the whole list, just this shard, so
+        // it's possible for two racing binds to both observe a count under
+        // `max` and both be admitted; the cap is best-effort, not exact.
+        if let Some(max) = self.max {
+            if self.list.len() >= max {
+                drop(shard);
+                task.shutdown();
+                return None;
+            }
+        }
         shard.push(task);
         Some(notified)
     }
@@ -192,6 +211,10 @@ impl<S: 'static> OwnedTasks<S> {
         self.list.is_empty()
     }
 
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
     /// Generates the size of the sharded list based on the number of worker threads.
     ///
     /// The sharded lock design can effectively alleviate
@@ -244,7 +267,11 @@ impl<S: 'static> LocalOwnedTasks<S> {
         T: Future + 'static,
         T::Output: 'static,
     {
-        let (task, notified, join) = super::new_task(task, scheduler, id);
+        // `LocalSet` tasks are `!Send` and never migrate between workers, so
+        // there is no multi-threaded scheduler here to honor `when_idle` or
+        // `priority`.
+        let (task, notified, join) =
+            super::new_task(task, scheduler, id, false, super::TaskPriority::Normal);
 
         unsafe {
             // safety: We just created the task, so we have exclusive access
@@ -174,6 +174,8 @@ use self::core::Header;
 
 mod error;
 pub use self::error::JoinError;
+#[cfg(tokio_unstable)]
+pub use self::error::TrySpawnError;
 
 mod harness;
 use self::harness::Harness;
@@ -194,8 +196,10 @@ pub use self::join::JoinHandle;
 mod list;
 pub(crate) use self::list::{LocalOwnedTasks, OwnedTasks};
 
+mod pool;
+
 mod raw;
-pub(crate) use self::raw::RawTask;
+pub(crate) use self::raw::{PollOutcome, RawTask};
 
 mod state;
 use self::state::State;
@@ -212,6 +216,36 @@ use crate::util::sharded_list;
 
 use crate::runtime::TaskCallback;
 use std::marker::PhantomData;
+
+/// Coarse execution-order hint for a spawned task, consulted by the
+/// multi-threaded scheduler's local-queue placement and work-stealing
+/// order. See [`Handle::spawn_with_priority`].
+///
+/// This is a hint, not a guarantee: it's cheaper than a full priority
+/// queue, and a busy scheduler may still run a `High` task after a
+/// `Normal` one, or vice versa.
+///
+/// **Note**: This is an [unstable API][unstable]. The public API of this
+/// type may break in 1.x releases. See [the documentation on unstable
+/// features][unstable] for details.
+///
+/// [`Handle::spawn_with_priority`]: crate::runtime::Handle::spawn_with_priority
+/// [unstable]: crate#unstable-features
+#[cfg_attr(not(tokio_unstable), allow(unreachable_pub, dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TaskPriority {
+    /// Always placed at the back of the local run queue, and stolen only
+    /// after every `Normal` and `High` task stolen in the same batch.
+    Low,
+    /// Default priority. Behaves exactly as every task always has.
+    #[default]
+    Normal,
+    /// Placed at the front of the local run queue (or the LIFO slot) when
+    /// possible, and stolen before any `Normal` or `Low` task stolen in
+    /// the same batch.
+    High,
+}
 use std::ptr::NonNull;
 use std::{fmt, mem};
 
@@ -285,6 +319,16 @@ pub(crate) trait Schedule: Sync + Sized + 'static {
     fn unhandled_panic(&self) {
         // By default, do nothing. This maintains the 1.0 behavior.
     }
+
+    /// Whether a task's backing allocation should be recycled for reuse by a
+    /// later spawn of the same task type, instead of freed immediately, when
+    /// it is dropped. See `Config::task_pooling`.
+    ///
+    /// Defaults to `false`, which frees the allocation immediately, matching
+    /// the scheduler's previous behavior.
+    fn task_pooling_enabled(&self) -> bool {
+        false
+    }
 }
 
 cfg_rt! {
@@ -296,13 +340,15 @@ cfg_rt! {
         task: T,
         scheduler: S,
         id: Id,
+        when_idle: bool,
+        priority: TaskPriority,
     ) -> (Task<S>, Notified<S>, JoinHandle<T::Output>)
     where
         S: Schedule,
         T: Future + 'static,
         T::Output: 'static,
     {
-        let raw = RawTask::new::<T, S>(task, scheduler, id);
+        let raw = RawTask::new::<T, S>(task, scheduler, id, when_idle, priority);
         let task = Task {
             raw,
             _p: PhantomData,
@@ -326,7 +372,7 @@ cfg_rt! {
         T: Send + Future + 'static,
         T::Output: Send + 'static,
     {
-        let (task, notified, join) = new_task(task, scheduler, id);
+        let (task, notified, join) = new_task(task, scheduler, id, false, TaskPriority::Normal);
 
         // This transfers the ref-count of task and notified into an UnownedTask.
         // This is valid because an UnownedTask holds two ref-counts.
@@ -396,6 +442,15 @@ impl<S: 'static> Task<S> {
             // Safety: The header pointer is valid.
             unsafe { Header::get_id(self.raw.header_ptr()) }
         }
+
+        /// Returns the number of times this task has been moved between
+        /// workers' queues by the work-stealing queue.
+        #[cfg(tokio_unstable)]
+        pub(crate) fn migration_count(&self) -> u32 {
+            // Safety: tracing holds exclusive access to the task while
+            // capturing its trace.
+            unsafe { self.raw.migration_count() }
+        }
     }
 }
 
@@ -403,6 +458,67 @@ impl<S: 'static> Notified<S> {
     fn header(&self) -> &Header {
         self.0.header()
     }
+
+    /// Returns `true` if this task was spawned via `Handle::spawn_when_idle`
+    /// and must bypass the local and injection queues.
+    pub(crate) fn is_when_idle(&self) -> bool {
+        self.header().is_when_idle()
+    }
+
+    /// Returns this task's scheduling priority, as set at spawn time via
+    /// `Handle::spawn_with_priority`.
+    pub(crate) fn priority(&self) -> TaskPriority {
+        self.header().priority()
+    }
+
+    /// Records that this task has been moved into a worker's local queue by
+    /// the work-stealing queue.
+    pub(crate) fn set_stolen(&self) {
+        // Safety: `self` grants exclusive access to the task's stolen flag.
+        unsafe { self.header().set_stolen() }
+    }
+
+    /// Returns `true` if this task has ever been moved into a worker's local
+    /// queue by the work-stealing queue.
+    pub(crate) fn is_stolen(&self) -> bool {
+        // Safety: `self` grants exclusive access to the task's stolen flag.
+        unsafe { self.header().is_stolen() }
+    }
+
+    /// Records that this task is being pushed onto an injection queue right
+    /// now, for later use by `SchedulerMetrics::mean_inject_queue_wait`.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn set_inject_enqueued_at(&self, at: std::time::Instant) {
+        // Safety: `self` grants exclusive access to the task's
+        // injection-queue bookkeeping fields.
+        unsafe { self.0.raw.set_inject_enqueued_at(at) }
+    }
+
+    /// Returns and clears the timestamp recorded by
+    /// `set_inject_enqueued_at`, if any.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn take_inject_enqueued_at(&self) -> Option<std::time::Instant> {
+        // Safety: `self` grants exclusive access to the task's
+        // injection-queue bookkeeping fields.
+        unsafe { self.0.raw.take_inject_enqueued_at() }
+    }
+
+    /// Records that this task has just been stolen into a different
+    /// worker's queue.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn incr_migration_count(&self) {
+        // Safety: `self` grants exclusive access to the task's migration
+        // counter.
+        unsafe { self.0.raw.incr_migration_count() }
+    }
+
+    /// Returns the number of times this task has been stolen.
+    #[cfg(all(test, tokio_unstable))]
+    pub(crate) fn migration_count(&self) -> u32 {
+        // Safety: `self` grants exclusive access to the task's migration
+        // counter.
+        unsafe { self.0.raw.migration_count() }
+    }
 }
 
 impl<S: 'static> Notified<S> {
@@ -429,11 +545,11 @@ impl<S: Schedule> Task<S> {
 }
 
 impl<S: Schedule> LocalNotified<S> {
-    /// Runs the task.
-    pub(crate) fn run(self) {
+    /// Runs the task. See [`PollOutcome`] for the meaning of the result.
+    pub(crate) fn run(self) -> PollOutcome {
         let raw = self.task.raw;
         mem::forget(self);
-        raw.poll();
+        raw.poll()
     }
 }
 
@@ -470,7 +586,7 @@ impl<S: Schedule> UnownedTask<S> {
         };
 
         // Use the other ref-count to poll the task.
-        raw.poll();
+        let _ = raw.poll();
         // Decrement our extra ref-count
         drop(task);
     }
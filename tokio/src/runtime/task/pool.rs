@@ -0,0 +1,55 @@
+use crate::future::Future;
+use crate::runtime::task::core::Cell;
+use crate::runtime::task::Schedule;
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+
+/// Caps how many freed task allocations a single worker thread holds onto
+/// for reuse when `Config::task_pooling` is enabled. Bounds the extra memory
+/// pooling can pin down if a burst of one-off task types passes through
+/// before settling into a steady-state spawn pattern.
+const MAX_POOLED: usize = 32;
+
+thread_local! {
+    /// Task allocations freed by the worker currently running on this
+    /// thread, keyed by the concrete `(future type, scheduler type)` they
+    /// were built for. A worker owns at most one `Core` at a time, so this
+    /// approximates a per-core freelist without threading pool state through
+    /// the scheduler-agnostic task module.
+    static POOL: RefCell<Vec<(TypeId, Box<dyn Any>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Stashes `cell`'s allocation on the current thread so a later spawn of a
+/// task with the exact same future and scheduler types can reuse it instead
+/// of going back to the global allocator. Only called from `Harness::dealloc`
+/// when `Schedule::task_pooling_enabled` returns `true`.
+pub(super) fn recycle<T, S>(cell: Box<Cell<T, S>>)
+where
+    T: Future + 'static,
+    S: Schedule,
+{
+    POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < MAX_POOLED {
+            pool.push((TypeId::of::<(T, S)>(), cell));
+        }
+    });
+}
+
+/// Takes back a previously recycled allocation matching `(T, S)`, if the
+/// current thread has one on hand. Only called from `RawTask::new` when
+/// `Schedule::task_pooling_enabled` returns `true`.
+pub(super) fn reuse<T, S>() -> Option<Box<Cell<T, S>>>
+where
+    T: Future + 'static,
+    S: Schedule,
+{
+    POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        let id = TypeId::of::<(T, S)>();
+        let idx = pool.iter().position(|(entry_id, _)| *entry_id == id)?;
+        let (_, cell) = pool.swap_remove(idx);
+        cell.downcast::<Cell<T, S>>().ok()
+    })
+}
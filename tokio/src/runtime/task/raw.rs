@@ -1,6 +1,6 @@
 use crate::future::Future;
 use crate::runtime::task::core::{Core, Trailer};
-use crate::runtime::task::{Cell, Harness, Header, Id, Schedule, State};
+use crate::runtime::task::{Cell, Harness, Header, Id, Schedule, State, TaskPriority};
 
 use std::ptr::NonNull;
 use std::task::{Poll, Waker};
@@ -11,9 +11,21 @@ pub(crate) struct RawTask {
     ptr: NonNull<Header>,
 }
 
+/// The outcome of a single call to [`RawTask::poll`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PollOutcome {
+    /// The poll left the task idle without it immediately rescheduling
+    /// itself (i.e. the future returned `Pending` and did not wake itself
+    /// during this poll).
+    pub(crate) is_blocked: bool,
+
+    /// The future completed on this poll.
+    pub(crate) is_complete: bool,
+}
+
 pub(super) struct Vtable {
-    /// Polls the future.
-    pub(super) poll: unsafe fn(NonNull<Header>),
+    /// Polls the future. See [`PollOutcome`].
+    pub(super) poll: unsafe fn(NonNull<Header>) -> PollOutcome,
 
     /// Schedules the task for execution on the runtime.
     pub(super) schedule: unsafe fn(NonNull<Header>),
@@ -44,7 +56,7 @@ pub(super) struct Vtable {
 }
 
 /// Get the vtable for the requested `T` and `S` generics.
-pub(super) fn vtable<T: Future, S: Schedule>() -> &'static Vtable {
+pub(super) fn vtable<T: Future + 'static, S: Schedule>() -> &'static Vtable {
     &Vtable {
         poll: poll::<T, S>,
         schedule: schedule::<S>,
@@ -157,12 +169,37 @@ const fn get_id_offset(
 }
 
 impl RawTask {
-    pub(super) fn new<T, S>(task: T, scheduler: S, id: Id) -> RawTask
+    pub(super) fn new<T, S>(
+        task: T,
+        scheduler: S,
+        id: Id,
+        when_idle: bool,
+        priority: TaskPriority,
+    ) -> RawTask
     where
-        T: Future,
+        T: Future + 'static,
         S: Schedule,
     {
-        let ptr = Box::into_raw(Cell::<_, S>::new(task, scheduler, State::new(), id));
+        let cell = if scheduler.task_pooling_enabled() {
+            super::pool::reuse::<T, S>()
+        } else {
+            None
+        };
+
+        let cell = match cell {
+            Some(existing) => Cell::<_, S>::recycle(
+                existing,
+                task,
+                scheduler,
+                State::new(),
+                id,
+                when_idle,
+                priority,
+            ),
+            None => Cell::<_, S>::new(task, scheduler, State::new(), id, when_idle, priority),
+        };
+
+        let ptr = Box::into_raw(cell);
         let ptr = unsafe { NonNull::new_unchecked(ptr.cast()) };
 
         RawTask { ptr }
@@ -196,7 +233,7 @@ impl RawTask {
     }
 
     /// Safety: mutual exclusion is required to call this function.
-    pub(crate) fn poll(self) {
+    pub(crate) fn poll(self) -> PollOutcome {
         let vtable = self.header().vtable;
         unsafe { (vtable.poll)(self.ptr) }
     }
@@ -262,13 +299,56 @@ impl RawTask {
     pub(crate) unsafe fn set_queue_next(self, val: Option<RawTask>) {
         self.header().set_next(val.map(|task| task.ptr));
     }
+
+    /// Records that this task is being pushed onto an injection queue right
+    /// now.
+    ///
+    /// Safety: same as `set_queue_next`; access must be synchronized by
+    /// whichever queue currently owns the task.
+    #[cfg(tokio_unstable)]
+    pub(crate) unsafe fn set_inject_enqueued_at(self, at: std::time::Instant) {
+        self.header()
+            .inject_enqueued_at
+            .with_mut(|ptr| *ptr = Some(at));
+    }
+
+    /// Returns the timestamp recorded by `set_inject_enqueued_at`, if any.
+    ///
+    /// Safety: same as `set_queue_next`; access must be synchronized by
+    /// whichever queue currently owns the task.
+    #[cfg(tokio_unstable)]
+    pub(crate) unsafe fn take_inject_enqueued_at(self) -> Option<std::time::Instant> {
+        self.header().inject_enqueued_at.with_mut(|ptr| (*ptr).take())
+    }
+
+    /// Records that this task has just been moved to a different worker's
+    /// queue by the work-stealing queue.
+    ///
+    /// Safety: same as `set_queue_next`; access must be synchronized by
+    /// whichever queue currently owns the task.
+    #[cfg(tokio_unstable)]
+    pub(crate) unsafe fn incr_migration_count(self) {
+        self.header()
+            .migration_count
+            .with_mut(|ptr| *ptr = (*ptr).wrapping_add(1));
+    }
+
+    /// Returns the number of times this task has been moved between
+    /// workers' queues by the work-stealing queue.
+    ///
+    /// Safety: same as `set_queue_next`; access must be synchronized by
+    /// whichever queue currently owns the task.
+    #[cfg(any(tokio_taskdump, all(test, tokio_unstable)))]
+    pub(crate) unsafe fn migration_count(self) -> u32 {
+        self.header().migration_count.with(|ptr| *ptr)
+    }
 }
 
 impl Copy for RawTask {}
 
-unsafe fn poll<T: Future, S: Schedule>(ptr: NonNull<Header>) {
+unsafe fn poll<T: Future + 'static, S: Schedule>(ptr: NonNull<Header>) -> PollOutcome {
     let harness = Harness::<T, S>::from_raw(ptr);
-    harness.poll();
+    harness.poll()
 }
 
 unsafe fn schedule<S: Schedule>(ptr: NonNull<Header>) {
@@ -280,12 +360,12 @@ unsafe fn schedule<S: Schedule>(ptr: NonNull<Header>) {
         .schedule(Notified(Task::from_raw(ptr.cast())));
 }
 
-unsafe fn dealloc<T: Future, S: Schedule>(ptr: NonNull<Header>) {
+unsafe fn dealloc<T: Future + 'static, S: Schedule>(ptr: NonNull<Header>) {
     let harness = Harness::<T, S>::from_raw(ptr);
     harness.dealloc();
 }
 
-unsafe fn try_read_output<T: Future, S: Schedule>(
+unsafe fn try_read_output<T: Future + 'static, S: Schedule>(
     ptr: NonNull<Header>,
     dst: *mut (),
     waker: &Waker,
@@ -296,17 +376,17 @@ unsafe fn try_read_output<T: Future, S: Schedule>(
     harness.try_read_output(out, waker);
 }
 
-unsafe fn drop_join_handle_slow<T: Future, S: Schedule>(ptr: NonNull<Header>) {
+unsafe fn drop_join_handle_slow<T: Future + 'static, S: Schedule>(ptr: NonNull<Header>) {
     let harness = Harness::<T, S>::from_raw(ptr);
     harness.drop_join_handle_slow();
 }
 
-unsafe fn drop_abort_handle<T: Future, S: Schedule>(ptr: NonNull<Header>) {
+unsafe fn drop_abort_handle<T: Future + 'static, S: Schedule>(ptr: NonNull<Header>) {
     let harness = Harness::<T, S>::from_raw(ptr);
     harness.drop_reference();
 }
 
-unsafe fn shutdown<T: Future, S: Schedule>(ptr: NonNull<Header>) {
+unsafe fn shutdown<T: Future + 'static, S: Schedule>(ptr: NonNull<Header>) {
     let harness = Harness::<T, S>::from_raw(ptr);
     harness.shutdown();
 }
@@ -267,7 +267,7 @@ pub(in crate::runtime) fn trace_current_thread(
     owned: &OwnedTasks<Arc<current_thread::Handle>>,
     local: &mut VecDeque<Notified<Arc<current_thread::Handle>>>,
     injection: &Inject<Arc<current_thread::Handle>>,
-) -> Vec<(Id, Trace)> {
+) -> Vec<(Id, u32, Trace)> {
     // clear the local and injection queues
 
     let mut dequeued = Vec::new();
@@ -300,7 +300,7 @@ cfg_rt_multi_thread! {
         local: &mut multi_thread::queue::Local<Arc<multi_thread::Handle>>,
         synced: &Mutex<Synced>,
         injection: &Shared<Arc<multi_thread::Handle>>,
-    ) -> Vec<(Id, Trace)> {
+    ) -> Vec<(Id, u32, Trace)> {
         let mut dequeued = Vec::new();
 
         // clear the local queue
@@ -328,7 +328,10 @@ cfg_rt_multi_thread! {
 ///
 /// This helper presumes exclusive access to each task. The tasks must not exist
 /// in any other queue.
-fn trace_owned<S: Schedule>(owned: &OwnedTasks<S>, dequeued: Vec<Notified<S>>) -> Vec<(Id, Trace)> {
+fn trace_owned<S: Schedule>(
+    owned: &OwnedTasks<S>,
+    dequeued: Vec<Notified<S>>,
+) -> Vec<(Id, u32, Trace)> {
     let mut tasks = dequeued;
     // Notify and trace all un-notified tasks. The dequeued tasks are already
     // notified and so do not need to be re-notified.
@@ -349,8 +352,9 @@ fn trace_owned<S: Schedule>(owned: &OwnedTasks<S>, dequeued: Vec<Notified<S>>) -
         .map(|task| {
             let local_notified = owned.assert_owner(task);
             let id = local_notified.task.id();
+            let migration_count = local_notified.task.migration_count();
             let ((), trace) = Trace::capture(|| local_notified.run());
-            (id, trace)
+            (id, migration_count, trace)
         })
         .collect()
 }
@@ -34,3 +34,36 @@ impl<'a> TaskMeta<'a> {
 
 /// Runs on specific task-related events
 pub(crate) type TaskCallback = std::sync::Arc<dyn Fn(&TaskMeta<'_>) + Send + Sync>;
+
+/// Where a newly spawned task's `Notified` handle should initially land, as
+/// decided by a [`Config::placement`] hook. Consulted once, at spawn time;
+/// it has no bearing on where the task runs after it first wakes up.
+///
+/// [`Config::placement`]: crate::runtime::Builder::task_placement
+#[cfg(tokio_unstable)]
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Placement {
+    /// Reproduces the scheduler's default behavior: the task runs locally
+    /// if spawned from one of the runtime's own worker threads, otherwise
+    /// it lands on the injection queue.
+    Auto,
+
+    /// Prefer the worker at this index.
+    ///
+    /// The multi-threaded scheduler's local run queues are single-producer,
+    /// so a task can't be pushed directly into another worker's queue from
+    /// spawn: instead, the task is pushed to the injection queue and the
+    /// requested worker is woken directly, giving it first chance to steal
+    /// it back out. This is advisory, not a placement guarantee, and an
+    /// out-of-range index is treated the same as `Auto`.
+    Worker(usize),
+
+    /// Always land on the injection queue, regardless of which thread
+    /// spawned the task.
+    Inject,
+}
+
+/// Runs once per task, at spawn time, to decide its initial placement.
+#[cfg(tokio_unstable)]
+pub(crate) type PlacementFn = std::sync::Arc<dyn Fn(&TaskMeta<'_>) -> Placement + Send + Sync>;
@@ -0,0 +1,74 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, so once a worker polls it, it's gone from that worker's queue for
+/// good rather than bouncing back for another poll. Used so a burst of these
+/// only ever gets a worker's *initial* batch pull counted, not whatever it
+/// picks up afterward.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn low_cap_spreads_pickup_across_waking_workers() {
+    const WORKERS: usize = 4;
+
+    // Seeing the burst actually spread across `WORKERS` distinct OS threads
+    // needs at least that many CPUs to run them concurrently. On a more
+    // constrained machine, one worker can keep winning the CPU and draining
+    // the injection queue itself before its siblings ever get scheduled.
+    if std::thread::available_parallelism().map_or(0, |n| n.get()) < WORKERS {
+        return;
+    }
+
+    let rt = Builder::new_multi_thread_alt()
+        .worker_threads(WORKERS)
+        .acquire_core_batch_cap(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    // Every worker starts out parked. Spawning a burst of tasks from outside
+    // the runtime lands them all in the injection queue and wakes every
+    // worker at once; with the batch cap in place, each worker can only take
+    // one task off the top when it acquires its core, leaving the rest for
+    // its peers instead of the first waker draining half the queue itself.
+    rt.block_on(async {
+        for _ in 0..(WORKERS * 4) {
+            crate::spawn(Never);
+        }
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let workers_polled = (0..WORKERS)
+            .filter(|&i| metrics.worker_poll_count(i) > 0)
+            .count();
+
+        if workers_polled > 1 {
+            break;
+        }
+
+        assert!(
+            Instant::now() < deadline,
+            "expected pickup to spread across multiple workers, only {workers_polled} polled anything: {:?}",
+            (0..WORKERS)
+                .map(|i| metrics.worker_poll_count(i))
+                .collect::<Vec<_>>()
+        );
+
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
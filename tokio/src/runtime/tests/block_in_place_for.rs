@@ -0,0 +1,135 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn default_threshold_always_hands_off_the_core() {
+    // With `block_in_place_threshold` left at its default of `Duration::ZERO`,
+    // every `hint_duration` is above the threshold, so `block_in_place_for`
+    // keeps `block_in_place`'s existing behavior of spawning a thread to take
+    // over the core.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let blocking = Arc::new(AtomicBool::new(true));
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let blocking = blocking.clone();
+        std::thread::spawn(move || {
+            while blocking.load(Ordering::Relaxed) {
+                max_seen.fetch_max(metrics.live_worker_thread_count(), Ordering::Relaxed);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::runtime::Handle::block_in_place_for(Duration::from_millis(1), || {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+    });
+
+    blocking.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn hint_below_threshold_runs_inline_without_a_handoff() {
+    // With a configured threshold above the given hint, the blocking closure
+    // runs on the worker's own thread instead of handing its core off, so
+    // the live worker thread count never rises above the lone worker itself.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .block_in_place_threshold(Duration::from_secs(1))
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let blocking = Arc::new(AtomicBool::new(true));
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let blocking = blocking.clone();
+        std::thread::spawn(move || {
+            while blocking.load(Ordering::Relaxed) {
+                max_seen.fetch_max(metrics.live_worker_thread_count(), Ordering::Relaxed);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::runtime::Handle::block_in_place_for(Duration::from_millis(1), || {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+    });
+
+    blocking.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn hint_at_or_above_threshold_still_hands_off() {
+    // A `hint_duration` that meets or exceeds the configured threshold hands
+    // off the core exactly like the default, even with a threshold set.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .block_in_place_threshold(Duration::from_millis(1))
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let blocking = Arc::new(AtomicBool::new(true));
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let blocking = blocking.clone();
+        std::thread::spawn(move || {
+            while blocking.load(Ordering::Relaxed) {
+                max_seen.fetch_max(metrics.live_worker_thread_count(), Ordering::Relaxed);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::runtime::Handle::block_in_place_for(Duration::from_secs(1), || {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+    });
+
+    blocking.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,105 @@
+use crate::runtime::{Builder, RuntimeMetrics};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn new_builder() -> Builder {
+    let mut builder = Builder::new_multi_thread();
+    builder.worker_threads(2).enable_all();
+    builder
+}
+
+async fn wait_for_a_worker_to_park(metrics: &RuntimeMetrics) {
+    while (0..metrics.num_workers()).all(|i| metrics.worker_park_count(i) == 0) {
+        crate::time::sleep(Duration::from_millis(1)).await;
+    }
+}
+
+// Worker threads start up asynchronously after `build()` returns, so wait
+// for all of them to be live before sampling: otherwise the sampler can
+// catch the count still ramping up and mistake that for a `block_in_place`
+// handoff.
+fn wait_for_all_workers_live(metrics: &RuntimeMetrics) {
+    while metrics.live_worker_thread_count() < metrics.num_workers() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn priority_true_reclaims_the_core_even_with_a_parked_worker() {
+    let rt = new_builder().build().unwrap();
+    let metrics = rt.metrics();
+    wait_for_all_workers_live(&metrics);
+
+    let blocking = Arc::new(AtomicBool::new(true));
+    let min_seen = Arc::new(AtomicUsize::new(usize::MAX));
+    let sampler = {
+        let metrics = metrics.clone();
+        let blocking = blocking.clone();
+        let min_seen = min_seen.clone();
+        std::thread::spawn(move || {
+            while blocking.load(Ordering::Relaxed) {
+                min_seen.fetch_min(metrics.live_worker_thread_count(), Ordering::Relaxed);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        // Wait until the otherwise idle worker has actually parked.
+        wait_for_a_worker_to_park(&metrics).await;
+
+        crate::spawn(async {
+            crate::task::block_in_place(|| {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+    });
+
+    blocking.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    // The returning thread always races to reclaim its core, so even though
+    // a worker was parked and could have used the head start, the live
+    // thread count never dipped below the two workers that were there from
+    // the start.
+    assert_eq!(min_seen.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn priority_false_yields_the_core_to_a_parked_worker() {
+    // `false` only changes anything when there is a parked worker for the
+    // returning thread to yield to; otherwise it behaves exactly like the
+    // default. This drives that path with a worker parked and confirms the
+    // blocking call still completes correctly and leaves the runtime in a
+    // working state, rather than asserting on the exact moment the core
+    // changes hands: with only two workers, the thread that gives up the
+    // core is immediately eligible to be reused by the runtime for the very
+    // handoff it just yielded to, so the live worker count never visibly
+    // dips below two for a sampler to catch, even though the core did
+    // change hands.
+    let mut builder = new_builder();
+    builder.block_in_place_reacquire_priority(false);
+    let rt = builder.build().unwrap();
+    let metrics = rt.metrics();
+    wait_for_all_workers_live(&metrics);
+
+    rt.block_on(async {
+        wait_for_a_worker_to_park(&metrics).await;
+
+        crate::spawn(async {
+            crate::task::block_in_place(|| {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+
+        // The runtime should still be fully usable afterward: the core that
+        // was yielded must have found its way to a worker rather than being
+        // lost, or this would never complete.
+        assert_eq!(crate::spawn(async { 1 + 1 }).await.unwrap(), 2);
+    });
+}
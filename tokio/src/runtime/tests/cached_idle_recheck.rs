@@ -0,0 +1,39 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn no_task_is_lost_across_repeated_idle_transitions() {
+    // With two workers and only a handful of tasks per round, both workers
+    // repeatedly race down to empty and park between rounds, exercising the
+    // last searching worker's final recheck (see `Core::transition_to_parked`
+    // and `Handle::notify_if_work_pending`) on nearly every round. If the
+    // cached bit this option swaps in ever missed a task made visible right
+    // before that recheck, a round here would just hang instead of
+    // completing.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .cached_idle_recheck()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    for _ in 0..200 {
+        let remaining = Arc::new(AtomicUsize::new(8));
+        rt.block_on(async {
+            let mut handles = Vec::new();
+            for _ in 0..8 {
+                let remaining = remaining.clone();
+                handles.push(crate::spawn(async move {
+                    remaining.fetch_sub(1, Relaxed);
+                }));
+            }
+
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        });
+
+        assert_eq!(remaining.load(Relaxed), 0);
+    }
+}
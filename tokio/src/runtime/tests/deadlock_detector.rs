@@ -0,0 +1,37 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn deadlock_detector_does_not_fire_under_normal_load() {
+    let fire_count = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let fire_count = fire_count.clone();
+        Builder::new_multi_thread()
+            .worker_threads(2)
+            .deadlock_detector(Duration::from_millis(10), move || {
+                fire_count.fetch_add(1, Relaxed);
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        for _ in 0..10 {
+            crate::spawn(async {
+                crate::time::sleep(Duration::from_millis(5)).await;
+            })
+            .await
+            .unwrap();
+        }
+
+        // Give the monitor thread several chances to (incorrectly) fire
+        // while the runtime is idle between spawns.
+        crate::time::sleep(Duration::from_millis(50)).await;
+    });
+
+    assert_eq!(fire_count.load(Relaxed), 0);
+}
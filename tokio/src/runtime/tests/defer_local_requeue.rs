@@ -0,0 +1,45 @@
+use crate::runtime::Builder;
+
+#[test]
+fn yielding_tasks_are_requeued_locally() {
+    // `task::yield_now()` re-enters the runtime through `Context::defer`,
+    // which is flushed by the owning worker right after it comes back from
+    // parking. As long as that worker still holds its core at that point
+    // (the common case), the deferred task is pushed straight to the local
+    // run queue instead of the injection queue, so `remote_schedule_count`
+    // should stay at zero no matter how many times tasks yield.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    let baseline = rt.block_on(async {
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                crate::spawn(async {
+                    for _ in 0..64 {
+                        crate::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+
+        // Spawning itself happens from the `block_on` future, which runs on
+        // the calling thread rather than a worker, so each of the spawns
+        // above is itself a remote schedule. That is not what this test is
+        // about, so it is captured as a baseline before the tasks actually
+        // run and start yielding.
+        let baseline = metrics.remote_schedule_count();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        baseline
+    });
+
+    assert_eq!(metrics.remote_schedule_count(), baseline);
+}
@@ -0,0 +1,40 @@
+use crate::runtime::Builder;
+
+#[test]
+fn tracks_deferred_wake_count_and_high_water_mark() {
+    const TASKS: u64 = 5;
+
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let handles: Vec<_> = (0..TASKS)
+            .map(|_| {
+                crate::spawn(async {
+                    // `yield_now` defers the task instead of rescheduling it
+                    // directly; with nothing else runnable, the worker parks
+                    // and flushes all `TASKS` deferred wakers in one go.
+                    crate::task::yield_now().await;
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    });
+
+    assert_eq!(metrics.worker_deferred_wake_count(0), TASKS);
+    assert_eq!(metrics.worker_deferred_wake_high_water_mark(0), TASKS);
+
+    metrics.reset_worker_deferred_wake_high_water_mark(0);
+    assert_eq!(metrics.worker_deferred_wake_high_water_mark(0), 0);
+
+    // Resetting the high-water mark does not touch the running total.
+    assert_eq!(metrics.worker_deferred_wake_count(0), TASKS);
+}
@@ -0,0 +1,44 @@
+use crate::runtime::Builder;
+
+#[test]
+fn returns_true_when_called_from_a_worker() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        // The task driving `block_on` isn't a worker, so hop through a
+        // normal `spawn` first to reach one.
+        let drove = crate::spawn(async { crate::runtime::Handle::current().drive_once() })
+            .await
+            .unwrap();
+
+        assert!(drove);
+    });
+}
+
+#[test]
+fn returns_false_outside_a_worker() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    // Called directly from `block_on`'s thread, which isn't a worker
+    // holding a core, there's nothing for `drive_once` to drive.
+    let drove = rt.block_on(async { crate::runtime::Handle::current().drive_once() });
+
+    assert!(!drove);
+}
+
+#[test]
+fn returns_false_on_current_thread_runtime() {
+    let rt = Builder::new_current_thread().enable_all().build().unwrap();
+
+    let drove = rt.block_on(async { crate::runtime::Handle::current().drive_once() });
+
+    assert!(!drove);
+}
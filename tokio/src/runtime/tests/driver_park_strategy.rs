@@ -0,0 +1,30 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn driver_park_strategy_overrides_default_park() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+    let call_count2 = call_count.clone();
+
+    let rt = Builder::new_multi_thread_alt()
+        .worker_threads(1)
+        .enable_all()
+        .driver_park_strategy(Arc::new(move |driver, handle, timeout| {
+            call_count2.fetch_add(1, Ordering::SeqCst);
+            match timeout {
+                Some(timeout) => driver.park_timeout(handle, timeout),
+                None => driver.park(handle),
+            }
+        }))
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        crate::time::sleep(std::time::Duration::from_millis(10)).await;
+    });
+
+    drop(rt);
+
+    assert!(call_count.load(Ordering::SeqCst) > 0);
+}
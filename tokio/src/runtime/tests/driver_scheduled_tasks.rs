@@ -0,0 +1,45 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn increments_when_a_timer_wakes_a_task_via_the_driver() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        // The sleeping task can only be woken by the driver observing the
+        // timer firing while parked, so its reschedule is counted as
+        // driver-origin.
+        crate::spawn(crate::time::sleep(Duration::from_millis(1)))
+            .await
+            .unwrap();
+    });
+
+    assert!(metrics.worker_driver_scheduled_tasks(0) >= 1);
+}
+
+#[test]
+fn does_not_increment_for_locally_scheduled_tasks() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::spawn(async {}).await.unwrap();
+        })
+        .await
+        .unwrap();
+    });
+
+    assert_eq!(metrics.worker_driver_scheduled_tasks(0), 0);
+}
@@ -0,0 +1,75 @@
+use crate::runtime::Builder;
+use crate::sync::Notify;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn run_order(inject_priority_over_local: bool) -> Vec<&'static str> {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .disable_lifo_slot()
+        .global_queue_interval(1_000_000)
+        .inject_priority_over_local(inject_priority_over_local)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let ready = Arc::new(Notify::new());
+
+    // Spawned from the test thread, which holds no `Core`, so this lands in
+    // the injection queue. Once it runs, it queues 8 local children of its
+    // own, then backs its worker's OS thread up with a blocking sleep so
+    // those children sit untouched in the local queue for a moment.
+    let driver_order = order.clone();
+    let driver_ready = ready.clone();
+    let driver = rt.spawn(async move {
+        let children: Vec<_> = (0..8)
+            .map(|_| {
+                let order = driver_order.clone();
+                crate::spawn(async move {
+                    order.lock().unwrap().push("local");
+                })
+            })
+            .collect();
+
+        driver_ready.notify_one();
+        std::thread::sleep(Duration::from_millis(200));
+
+        for child in children {
+            child.await.unwrap();
+        }
+    });
+
+    // Wait for the children to be queued before injecting the marker task,
+    // so the marker and the local children are both waiting when the
+    // worker's OS thread wakes back up.
+    rt.block_on(ready.notified());
+
+    // Also queued from the test thread, so this lands in the injection
+    // queue alongside (not ahead of) the local children queued above.
+    let marker_order = order.clone();
+    let marker = rt.spawn(async move {
+        marker_order.lock().unwrap().push("remote");
+    });
+
+    rt.block_on(async move {
+        driver.await.unwrap();
+        marker.await.unwrap();
+    });
+
+    Arc::try_unwrap(order).unwrap().into_inner().unwrap()
+}
+
+#[test]
+fn inject_priority_over_local_runs_injected_task_first() {
+    let order = run_order(true);
+    assert_eq!(order[0], "remote");
+    assert_eq!(&order[1..], ["local"; 8]);
+}
+
+#[test]
+fn default_runs_local_tasks_before_injected_task() {
+    let order = run_order(false);
+    assert_eq!(&order[..8], ["local"; 8]);
+    assert_eq!(order[8], "remote");
+}
@@ -0,0 +1,26 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn mean_inject_queue_wait_reflects_remote_spawns() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    assert_eq!(metrics.mean_inject_queue_wait(), Duration::ZERO);
+
+    // Spawning from outside a runtime thread always goes through the
+    // injection queue, so this gives the average a non-zero sample.
+    for _ in 0..10 {
+        rt.spawn(async {});
+    }
+
+    rt.block_on(async {
+        crate::time::sleep(Duration::from_millis(10)).await;
+    });
+
+    assert!(metrics.mean_inject_queue_wait() > Duration::ZERO);
+}
@@ -0,0 +1,59 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn inject_to_worker_delivers_task() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let result = rt.block_on(rt.handle().inject_to_worker(0, async { 42 }));
+    assert_eq!(result.unwrap(), 42);
+}
+
+#[test]
+fn idle_peer_steals_from_another_workers_inject_queue() {
+    // Worker 0 is targeted with a task via `inject_to_worker`, then backs its
+    // own OS thread up with a blocking sleep before it can drain its queue.
+    // That forces the other, idle worker to steal the task out of worker 0's
+    // inject queue instead. Retried a few times, since which worker parks
+    // first is timing-dependent.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    for _ in 0..20 {
+        let busy = rt
+            .handle()
+            .notify_fast(0, async { std::thread::sleep(Duration::from_millis(50)) });
+        // A throwaway task that targets worker 1 directly, so that worker 1
+        // wakes up and, finding nothing of its own left to do, goes looking
+        // for work to steal while worker 0 is stuck in the blocking sleep
+        // above.
+        let poke = rt.handle().notify_fast(1, async {});
+        let task = rt.handle().inject_to_worker(0, async { "stolen" });
+
+        let (busy, poke, task) =
+            rt.block_on(async move { (busy.await, poke.await, task.await) });
+
+        busy.unwrap();
+        poke.unwrap();
+        assert_eq!(task.unwrap(), "stolen");
+
+        let steal_count: u64 = (0..metrics.num_workers())
+            .map(|i| metrics.worker_steal_count(i))
+            .sum();
+
+        if steal_count > 0 {
+            return;
+        }
+    }
+
+    panic!("no worker stole from another worker's inject queue after 20 attempts");
+}
@@ -0,0 +1,79 @@
+use crate::runtime::Builder;
+use crate::sync::oneshot;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A future that, on its first poll, stashes its waker for someone else to
+/// wake and signals `ready_tx` once it has done so; on the second poll (once
+/// woken), it completes.
+struct WaitForWake {
+    waker_slot: Arc<Mutex<Option<Waker>>>,
+    ready_tx: Option<oneshot::Sender<()>>,
+    woken: bool,
+}
+
+impl Future for WaitForWake {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.woken {
+            return Poll::Ready(());
+        }
+        this.woken = true;
+        *this.waker_slot.lock().unwrap() = Some(cx.waker().clone());
+        let _ = this.ready_tx.take().unwrap().send(());
+        Poll::Pending
+    }
+}
+
+#[test]
+fn lifo_budget_demotion_count_increments_when_budget_runs_out_mid_chain() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let waker_slot = Arc::new(Mutex::new(None));
+        let (ready_tx, ready_rx) = oneshot::channel();
+
+        let waiter = crate::spawn(WaitForWake {
+            waker_slot: waker_slot.clone(),
+            ready_tx: Some(ready_tx),
+            woken: false,
+        });
+
+        // Wait until `waiter` has stashed its waker and gone to sleep before
+        // spawning the task that exhausts the budget and wakes it, so the
+        // wake below always lands the already parked `waiter` into the
+        // worker's LIFO slot rather than racing it.
+        ready_rx.await.unwrap();
+
+        let burner = crate::spawn(async move {
+            // Drain the worker's entire coop budget for this `run_task`
+            // call (the same budget `waiter`'s eventual LIFO turn will be
+            // checked against) before waking `waiter`, so its wake lands it
+            // in the LIFO slot with no budget left to run it.
+            for _ in 0..128 {
+                crate::task::consume_budget().await;
+            }
+
+            waker_slot.lock().unwrap().take().unwrap().wake();
+        });
+
+        burner.await.unwrap();
+        waiter.await.unwrap();
+
+        // Gives the worker a chance to submit the resulting stats.
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    });
+
+    assert!(metrics.worker_lifo_budget_demotion_count(0) >= 1);
+}
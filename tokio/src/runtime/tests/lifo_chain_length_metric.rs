@@ -0,0 +1,36 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn lifo_chain_length_histogram_tracks_chain_lengths() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            // Spawned back-to-back from within a worker, this claims the
+            // (empty) LIFO slot and gets polled to completion as part of the
+            // same `run_task` LIFO chain as its parent.
+            crate::spawn(async {}).await.unwrap();
+        })
+        .await
+        .unwrap();
+
+        // Give the worker a chance to submit the resulting stats.
+        std::thread::sleep(Duration::from_millis(50));
+    });
+
+    let buckets = metrics.lifo_chain_length_histogram_num_buckets();
+    assert!(buckets > 1);
+
+    let chains_of_at_least_one: u64 = (1..buckets)
+        .map(|bucket| metrics.lifo_chain_length_histogram_bucket_count(0, bucket))
+        .sum();
+    assert!(chains_of_at_least_one >= 1);
+}
@@ -0,0 +1,33 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn lifo_eviction_count_increments_when_a_newer_task_claims_the_slot() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            // Spawned back-to-back from within a worker, the first task
+            // claims the (empty) LIFO slot. The second then evicts it to the
+            // run queue to claim the slot for itself.
+            crate::spawn(async {});
+            crate::spawn(async {})
+        })
+        .await
+        .unwrap()
+        .await
+        .unwrap();
+
+        // Gives the worker a chance to submit the resulting stats.
+        std::thread::sleep(Duration::from_millis(50));
+    });
+
+    assert!(metrics.worker_lifo_eviction_count(0) >= 1);
+}
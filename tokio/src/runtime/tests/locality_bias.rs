@@ -0,0 +1,60 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+fn steal_count_with_bias(bias: f64) -> u64 {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .disable_lifo_slot()
+        .locality_bias(bias)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            // Backs up this worker thread long enough for the other, idle
+            // worker to search for work and consider stealing the tasks
+            // queued above.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    (0..metrics.num_workers())
+        .map(|i| metrics.worker_steal_count(i))
+        .sum()
+}
+
+#[test]
+fn locality_bias_reduces_task_migration() {
+    // With the default bias, an idle worker steals from a victim that has
+    // any tasks at all.
+    assert!(steal_count_with_bias(0.0) > 0);
+
+    // With bias maxed out, a victim's queue must be completely full before
+    // it becomes a valid steal target. A handful of queued tasks never gets
+    // close to that, so no migration happens.
+    assert_eq!(steal_count_with_bias(1.0), 0);
+}
@@ -312,6 +312,36 @@ mod group_c {
 mod group_d {
     use super::*;
 
+    #[cfg(tokio_unstable)]
+    #[test]
+    fn cached_idle_recheck_does_not_lose_work() {
+        // With `cached_idle_recheck` on, the last searching worker's final
+        // check before parking (see `Core::transition_to_parked` and
+        // `Handle::notify_if_work_pending`) reads a single cached bit
+        // instead of scanning every queue. Race a spawn from one worker
+        // against the other one going idle and confirm the spawned task is
+        // always eventually polled, i.e. the bit is never missed.
+        loom::model(|| {
+            let pool = runtime::Builder::new_multi_thread()
+                .worker_threads(2)
+                .event_interval(2)
+                .cached_idle_recheck()
+                .build()
+                .unwrap();
+
+            let (done_tx, done_rx) = oneshot::channel();
+            let done_tx = AtomicOneshot::new(done_tx);
+
+            pool.spawn(track(async move {
+                spawn(track(async move {
+                    done_tx.assert_send(());
+                }));
+            }));
+
+            done_rx.recv();
+        });
+    }
+
     #[test]
     fn pool_multi_notify() {
         loom::model(|| {
@@ -1,3 +1,4 @@
+use crate::runtime::config::OverflowPolicy;
 use crate::runtime::scheduler::multi_thread::{queue, Stats};
 use crate::runtime::tests::{unowned, NoopSchedule};
 
@@ -21,7 +22,7 @@ fn basic() {
             let mut n = 0;
 
             for _ in 0..3 {
-                if steal.steal_into(&mut local, &mut stats).is_some() {
+                if steal.steal_into(&mut local, &mut stats, None).is_some() {
                     n += 1;
                 }
 
@@ -38,7 +39,7 @@ fn basic() {
         for _ in 0..2 {
             for _ in 0..2 {
                 let (task, _) = unowned(async {});
-                local.push_back_or_overflow(task, &inject, &mut stats);
+                local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
             }
 
             if local.pop().is_some() {
@@ -47,7 +48,7 @@ fn basic() {
 
             // Push another task
             let (task, _) = unowned(async {});
-            local.push_back_or_overflow(task, &inject, &mut stats);
+            local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
 
             while local.pop().is_some() {
                 n += 1;
@@ -74,7 +75,7 @@ fn steal_overflow() {
             let (_, mut local) = queue::local();
             let mut n = 0;
 
-            if steal.steal_into(&mut local, &mut stats).is_some() {
+            if steal.steal_into(&mut local, &mut stats, None).is_some() {
                 n += 1;
             }
 
@@ -89,7 +90,7 @@ fn steal_overflow() {
 
         // push a task, pop a task
         let (task, _) = unowned(async {});
-        local.push_back_or_overflow(task, &inject, &mut stats);
+        local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
 
         if local.pop().is_some() {
             n += 1;
@@ -97,7 +98,7 @@ fn steal_overflow() {
 
         for _ in 0..6 {
             let (task, _) = unowned(async {});
-            local.push_back_or_overflow(task, &inject, &mut stats);
+            local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
         }
 
         n += th.join().unwrap();
@@ -120,7 +121,7 @@ fn multi_stealer() {
         let mut stats = new_stats();
         let (_, mut local) = queue::local();
 
-        if steal.steal_into(&mut local, &mut stats).is_none() {
+        if steal.steal_into(&mut local, &mut stats, None).is_none() {
             return 0;
         }
 
@@ -141,7 +142,7 @@ fn multi_stealer() {
         // Push work
         for _ in 0..NUM_TASKS {
             let (task, _) = unowned(async {});
-            local.push_back_or_overflow(task, &inject, &mut stats);
+            local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
         }
 
         let th1 = {
@@ -177,17 +178,17 @@ fn chained_steal() {
         // Load up some tasks
         for _ in 0..4 {
             let (task, _) = unowned(async {});
-            l1.push_back_or_overflow(task, &inject, &mut stats);
+            l1.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
 
             let (task, _) = unowned(async {});
-            l2.push_back_or_overflow(task, &inject, &mut stats);
+            l2.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
         }
 
         // Spawn a task to steal from **our** queue
         let th = thread::spawn(move || {
             let mut stats = new_stats();
             let (_, mut local) = queue::local();
-            s1.steal_into(&mut local, &mut stats);
+            s1.steal_into(&mut local, &mut stats, None);
 
             while local.pop().is_some() {}
         });
@@ -195,7 +196,7 @@ fn chained_steal() {
         // Drain our tasks, then attempt to steal
         while l1.pop().is_some() {}
 
-        s2.steal_into(&mut l1, &mut stats);
+        s2.steal_into(&mut l1, &mut stats, None);
 
         th.join().unwrap();
 
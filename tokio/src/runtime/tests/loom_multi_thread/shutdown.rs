@@ -26,3 +26,28 @@ fn join_handle_cancel_on_shutdown() {
         assert!(err2.is_cancelled());
     });
 }
+
+// Exercises the debug-only assertion in `shutdown_core`/`shutdown_finalize`
+// that no collection can hold a task once shutdown has drained it. A spawn
+// racing the drop can land in either of the two cases described in the
+// "Spawns during shutdown" section of the module docs; neither should ever
+// leave a task behind for the final verification pass to catch.
+#[test]
+fn spawn_racing_with_shutdown_never_leaks_a_task() {
+    let mut builder = loom::model::Builder::new();
+    builder.preemption_bound = Some(2);
+    builder.check(|| {
+        let rt = Builder::new_multi_thread()
+            .worker_threads(2)
+            .build()
+            .unwrap();
+
+        let handle = rt.block_on(async move { Handle::current() });
+
+        loom::thread::spawn(move || {
+            let _ = handle.spawn(async {});
+        });
+
+        drop(rt);
+    });
+}
@@ -162,6 +162,34 @@ mod group_a {
     fn only_blocking_with_pending() {
         only_blocking_inner(true)
     }
+
+    #[test]
+    fn core_returned_to_waiting_worker() {
+        // `block_in_place` on one worker hands its core back to the pool
+        // while a second task is spawned concurrently. The spawn must be
+        // able to claim that returned core (or steal work once assigned)
+        // rather than the task getting stuck unpolled forever.
+        loom::model(|| {
+            let pool = mk_pool(2);
+            let (block_tx, block_rx) = oneshot::channel();
+            let (done_tx, done_rx) = oneshot::channel();
+
+            pool.spawn(track(async move {
+                crate::task::block_in_place(move || {
+                    block_tx.send(());
+                });
+            }));
+
+            pool.spawn(track(async move {
+                done_tx.send(());
+            }));
+
+            block_rx.recv();
+            done_rx.recv();
+
+            drop(pool);
+        });
+    }
 }
 
 mod group_b {
@@ -0,0 +1,26 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn lost_wakeup_checks_do_not_fire_under_normal_load() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .lost_wakeup_checks(true)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        for _ in 0..10 {
+            crate::spawn(async {
+                crate::time::sleep(Duration::from_millis(5)).await;
+            })
+            .await
+            .unwrap();
+        }
+
+        // Give every worker several chances to park (and self-check) while
+        // the runtime is idle between spawns.
+        crate::time::sleep(Duration::from_millis(50)).await;
+    });
+}
@@ -0,0 +1,126 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Bounded rendezvous: bump `arrived` and wait (up to `deadline`) for it to
+// reach `target`. Unlike `std::sync::Barrier`, this can never hang a test
+// outright if the runtime doesn't actually run the calls concurrently; it
+// just gives up, and the resulting `max_seen` assertion fails instead.
+fn rendezvous(arrived: &AtomicUsize, target: usize, deadline: Instant) {
+    arrived.fetch_add(1, Ordering::Relaxed);
+    while arrived.load(Ordering::Relaxed) < target && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    // Hold here a little longer so a concurrently running sampler thread
+    // has a real chance to observe the outstanding calls together, rather
+    // than the rendezvous above resolving and every call returning within
+    // the same instant.
+    std::thread::sleep(Duration::from_millis(50));
+}
+
+#[test]
+fn max_concurrent_block_in_place_keeps_extra_calls_on_the_same_core_once_capped() {
+    // Two tasks call `block_in_place` concurrently, but the cap only allows
+    // one handoff at a time. The other call must run its closure inline,
+    // keeping its core, so the number of outstanding handoffs never rises
+    // above the cap even though both calls are in flight together.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .max_concurrent_block_in_place(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let sampling = Arc::new(AtomicBool::new(true));
+    let arrived = Arc::new(AtomicUsize::new(0));
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let sampling = sampling.clone();
+        std::thread::spawn(move || {
+            while sampling.load(Ordering::Relaxed) {
+                max_seen.fetch_max(
+                    metrics.outstanding_block_in_place_count(),
+                    Ordering::Relaxed,
+                );
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        let mut handles = Vec::new();
+        for _ in 0..2 {
+            let arrived = arrived.clone();
+            handles.push(crate::spawn(async move {
+                crate::task::block_in_place(|| rendezvous(&arrived, 2, deadline));
+            }));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    });
+
+    sampling.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn max_concurrent_block_in_place_default_allows_unbounded_handoffs() {
+    // Without a cap configured, both concurrent `block_in_place` calls hand
+    // off their cores, so both are outstanding at once.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let sampling = Arc::new(AtomicBool::new(true));
+    let arrived = Arc::new(AtomicUsize::new(0));
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let sampling = sampling.clone();
+        std::thread::spawn(move || {
+            while sampling.load(Ordering::Relaxed) {
+                max_seen.fetch_max(
+                    metrics.outstanding_block_in_place_count(),
+                    Ordering::Relaxed,
+                );
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        let mut handles = Vec::new();
+        for _ in 0..2 {
+            let arrived = arrived.clone();
+            handles.push(crate::spawn(async move {
+                crate::task::block_in_place(|| rendezvous(&arrived, 2, deadline));
+            }));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    });
+
+    sampling.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,74 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// A future with an expensive poll that reschedules itself (via a
+/// self-wakeup) onto the worker's LIFO slot several times before completing.
+struct SlowRelay {
+    polls: Arc<AtomicU32>,
+}
+
+impl Future for SlowRelay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        std::thread::sleep(Duration::from_millis(200));
+
+        if self.polls.fetch_add(1, Ordering::Relaxed) + 1 < 10 {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
+
+#[test]
+fn max_lifo_duration_bounds_lifo_loop() {
+    // Without a duration cap, the LIFO loop keeps polling a self-rescheduling
+    // task for up to `max_lifo_polls` (3 by default) polls before giving other
+    // queued work a turn. With slow polls, that lets one task monopolize the
+    // worker for multiple poll durations. Configuring `max_lifo_duration`
+    // should force the loop to give up the worker much sooner.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_lifo_duration(Duration::from_millis(50))
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let polls = Arc::new(AtomicU32::new(0));
+    let marker_ran_at = Arc::new(Mutex::new(None));
+
+    rt.block_on(async {
+        let start = Instant::now();
+
+        crate::spawn(SlowRelay {
+            polls: polls.clone(),
+        });
+
+        let marker_ran_at = marker_ran_at.clone();
+        crate::spawn(async move {
+            *marker_ran_at.lock().unwrap() = Some(start.elapsed());
+        })
+        .await
+        .unwrap();
+    });
+
+    let elapsed = marker_ran_at.lock().unwrap().unwrap();
+
+    // Each poll takes 200ms. Without the duration cap, the marker task would
+    // have to wait behind an initial poll plus 3 back-to-back LIFO polls
+    // (~800ms). The 50ms duration cap should force the LIFO loop to give up
+    // the worker after just one of those, so the marker runs well before
+    // that.
+    assert!(
+        elapsed < Duration::from_millis(600),
+        "marker ran after {:?}",
+        elapsed
+    );
+}
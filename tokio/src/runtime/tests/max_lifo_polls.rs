@@ -0,0 +1,111 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+/// A future that reschedules itself (via a self-wakeup) onto the worker's
+/// LIFO slot until it has been polled `target_polls` times.
+struct SelfWaking {
+    polls: Arc<AtomicU32>,
+    target_polls: u32,
+}
+
+impl Future for SelfWaking {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.polls.fetch_add(1, Ordering::Relaxed) + 1 < self.target_polls {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+}
+
+#[test]
+fn max_lifo_polls_bounds_lifo_chain_length() {
+    // By default, the LIFO loop chases a self-rescheduling task for up to
+    // 3 polls before giving other queued work a turn. Configuring
+    // `max_lifo_polls(1)` should force it to give up the worker after the
+    // very first one instead.
+    //
+    // `global_queue_interval(1)` pins the worker to check the injection
+    // queue on every tick instead of leaving it to the `tuned_global_queue_interval`
+    // EWMA heuristic, which can otherwise settle on an interval wide enough
+    // that the self-waking task wins the local queue race and runs to
+    // completion before the marker task is ever pulled off the injection
+    // queue, making this test flaky.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_lifo_polls(1)
+        .global_queue_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let polls = Arc::new(AtomicU32::new(0));
+    let polls_seen_by_marker = Arc::new(Mutex::new(None));
+
+    rt.block_on(async {
+        crate::spawn(SelfWaking {
+            polls: polls.clone(),
+            target_polls: 10,
+        });
+
+        let polls = polls.clone();
+        let polls_seen_by_marker = polls_seen_by_marker.clone();
+        crate::spawn(async move {
+            *polls_seen_by_marker.lock().unwrap() = Some(polls.load(Ordering::Relaxed));
+        })
+        .await
+        .unwrap();
+    });
+
+    // The marker task should have run well before the self-waking task
+    // reached its own limit of 10 polls.
+    let polls_before_marker = polls_seen_by_marker.lock().unwrap().unwrap();
+    assert!(
+        polls_before_marker < 10,
+        "self-waking task ran to completion before the marker: {polls_before_marker} polls"
+    );
+}
+
+#[test]
+fn max_lifo_polls_zero_disables_lifo_slot() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_lifo_polls(0)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let polls = Arc::new(AtomicU32::new(0));
+    let polls_seen_by_marker = Arc::new(Mutex::new(None));
+
+    rt.block_on(async {
+        crate::spawn(SelfWaking {
+            polls: polls.clone(),
+            target_polls: 10,
+        });
+
+        let polls = polls.clone();
+        let polls_seen_by_marker = polls_seen_by_marker.clone();
+        crate::spawn(async move {
+            *polls_seen_by_marker.lock().unwrap() = Some(polls.load(Ordering::Relaxed));
+        })
+        .await
+        .unwrap();
+    });
+
+    // With the LIFO slot disabled outright, the self-waking task never gets
+    // to chain at all: the marker should see it at (at most) its very first
+    // poll.
+    let polls_before_marker = polls_seen_by_marker.lock().unwrap().unwrap();
+    assert!(
+        polls_before_marker <= 1,
+        "self-waking task chained polls despite max_lifo_polls(0): {polls_before_marker} polls"
+    );
+}
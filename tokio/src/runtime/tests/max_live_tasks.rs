@@ -0,0 +1,41 @@
+use crate::runtime::{Builder, Handle};
+use std::future::pending;
+
+#[test]
+fn spawn_beyond_cap_is_cancelled_and_try_spawn_reports_it() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_live_tasks(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        // These never complete on their own; `bind` counts them as alive
+        // from the moment `spawn` returns, so there's no need to wait for
+        // them to actually be polled.
+        let a = crate::spawn(pending::<()>());
+        let b = crate::spawn(pending::<()>());
+
+        // At the cap: a plain `spawn` is admitted like any other, up until
+        // `OwnedTasks::bind` rejects it the same way it would a spawn onto a
+        // closed runtime, so the `JoinHandle` resolves to a cancelled
+        // `JoinError` without the task ever running.
+        let over_cap = crate::spawn(async { unreachable!("never polled") });
+        assert!(over_cap.await.unwrap_err().is_cancelled());
+
+        // `try_spawn` catches the same condition up front instead.
+        assert!(Handle::current()
+            .try_spawn(async { unreachable!("never polled") })
+            .is_err());
+
+        a.abort();
+        b.abort();
+        assert!(a.await.unwrap_err().is_cancelled());
+        assert!(b.await.unwrap_err().is_cancelled());
+
+        // Now that `a` and `b` have completed and been removed, there's
+        // room again.
+        assert!(Handle::current().try_spawn(async {}).is_ok());
+    });
+}
@@ -0,0 +1,91 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn max_worker_threads_keeps_block_in_place_on_the_same_core_once_capped() {
+    // With the cap already met by the lone worker thread itself, a
+    // `block_in_place` call must not spawn another thread to take over the
+    // core; it should just run its closure inline, keeping the core, so the
+    // live worker thread count never rises above the cap.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let blocking = Arc::new(AtomicBool::new(true));
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let blocking = blocking.clone();
+        std::thread::spawn(move || {
+            while blocking.load(Ordering::Relaxed) {
+                max_seen.fetch_max(metrics.live_worker_thread_count(), Ordering::Relaxed);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::task::block_in_place(|| {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+    });
+
+    blocking.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn max_worker_threads_default_allows_block_in_place_to_hand_off_the_core() {
+    // Without a cap configured, `block_in_place` keeps its existing
+    // behavior of spawning a thread to take over the core.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    let max_seen = Arc::new(AtomicUsize::new(0));
+    let blocking = Arc::new(AtomicBool::new(true));
+
+    let sampler = {
+        let metrics = metrics.clone();
+        let max_seen = max_seen.clone();
+        let blocking = blocking.clone();
+        std::thread::spawn(move || {
+            while blocking.load(Ordering::Relaxed) {
+                max_seen.fetch_max(metrics.live_worker_thread_count(), Ordering::Relaxed);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        })
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::task::block_in_place(|| {
+                std::thread::sleep(Duration::from_millis(100));
+            });
+        })
+        .await
+        .unwrap();
+    });
+
+    blocking.store(false, Ordering::Relaxed);
+    sampler.join().unwrap();
+
+    assert_eq!(max_seen.load(Ordering::Relaxed), 2);
+}
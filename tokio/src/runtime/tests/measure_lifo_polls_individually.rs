@@ -0,0 +1,108 @@
+use crate::runtime::Builder;
+use crate::sync::oneshot;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A future that, on its first poll, stashes its waker for someone else to
+/// wake and signals `ready_tx` once it has done so; on the second poll (once
+/// woken), it completes.
+///
+/// Waking this future from another task's poll is what lands it in that
+/// task's LIFO slot, exercising `run_task`'s LIFO loop the same way a
+/// ping-pong pair of tasks would.
+struct WaitForWake {
+    waker_slot: Arc<Mutex<Option<Waker>>>,
+    ready_tx: Option<oneshot::Sender<()>>,
+    woken: bool,
+}
+
+impl Future for WaitForWake {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.woken {
+            return Poll::Ready(());
+        }
+        this.woken = true;
+        *this.waker_slot.lock().unwrap() = Some(cx.waker().clone());
+        let _ = this.ready_tx.take().unwrap().send(());
+        Poll::Pending
+    }
+}
+
+/// A future that, once polled, wakes the peer stashed in `waker_slot` and
+/// completes immediately. The wake happens while this task is running on
+/// the worker, so the peer is scheduled into this task's LIFO slot.
+struct WakePeer {
+    waker_slot: Arc<Mutex<Option<Waker>>>,
+}
+
+impl Future for WakePeer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        let waker = self.waker_slot.lock().unwrap().take().unwrap();
+        waker.wake();
+        Poll::Ready(())
+    }
+}
+
+fn run_ping_pong(measure_individually: bool) -> u64 {
+    let mut builder = Builder::new_multi_thread();
+    builder.worker_threads(1).enable_all();
+    if measure_individually {
+        builder.measure_lifo_polls_individually(true);
+    }
+    let rt = builder.build().unwrap();
+
+    let metrics = rt.metrics();
+    let baseline = metrics.worker_poll_count(0);
+
+    rt.block_on(async {
+        let waker_slot = Arc::new(Mutex::new(None));
+        let (ready_tx, ready_rx) = oneshot::channel();
+
+        let waiter = crate::spawn(WaitForWake {
+            waker_slot: waker_slot.clone(),
+            ready_tx: Some(ready_tx),
+            woken: false,
+        });
+
+        // Wait until `waiter` has actually stashed its waker before spawning
+        // the task that wakes it, so the wake below always lands the already
+        // parked `waiter` into the waker's LIFO slot rather than racing it.
+        ready_rx.await.unwrap();
+
+        let waker = crate::spawn(WakePeer { waker_slot });
+
+        waiter.await.unwrap();
+        waker.await.unwrap();
+    });
+
+    // Stats are only submitted to `WorkerMetrics` periodically; dropping the
+    // runtime forces a final submission so the count above is visible.
+    drop(rt);
+
+    metrics.worker_poll_count(0) - baseline
+}
+
+#[test]
+fn default_inherits_a_single_poll_measurement() {
+    // `waiter`'s first poll (stashing its waker) is its own `run_task` call
+    // and always gets its own measurement. `waker`'s poll then wakes
+    // `waiter` while `waker` is running, so `waiter`'s second poll lands in
+    // `waker`'s LIFO slot and runs within the same `run_task` call. Left
+    // disabled, those two polls share a single measurement, for 2 total.
+    assert_eq!(run_ping_pong(false), 2);
+}
+
+#[test]
+fn individually_measures_each_lifo_loop_poll() {
+    // Enabled, `waker`'s poll and `waiter`'s LIFO-loop poll each get their
+    // own measurement, on top of `waiter`'s independent first poll, for 3
+    // total.
+    assert_eq!(run_ping_pong(true), 3);
+}
@@ -0,0 +1,41 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn sink_receives_deltas_matching_completed_tasks() {
+    let sink_calls = Arc::new(AtomicUsize::new(0));
+    let sink_completed_total = Arc::new(AtomicU64::new(0));
+
+    let sink_calls_2 = sink_calls.clone();
+    let sink_completed_total_2 = sink_completed_total.clone();
+
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .metrics_submit_interval(1)
+        .metrics_sink(move |_worker, delta| {
+            sink_calls_2.fetch_add(1, Relaxed);
+            sink_completed_total_2.fetch_add(delta.completed_tasks, Relaxed);
+        })
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let mut handles = Vec::new();
+        for _ in 0..100 {
+            handles.push(crate::spawn(async {}));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Give the workers a chance to hit their next maintenance cycle and
+        // submit stats after the tasks above have completed.
+        crate::time::sleep(std::time::Duration::from_millis(50)).await;
+    });
+
+    assert!(sink_calls.load(Relaxed) > 0);
+    assert_eq!(sink_completed_total.load(Relaxed), 100);
+}
@@ -0,0 +1,31 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn metrics_submit_interval_makes_worker_metrics_fresher() {
+    // The default `event_interval` is 61, so without `metrics_submit_interval`
+    // set, polling only 20 tasks would not yet trigger a maintenance-gated
+    // stats submission, and `worker_poll_count` would still read stale.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let handles: Vec<_> = (0..20).map(|_| crate::spawn(async {})).collect();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Gives the worker a chance to run the submit that immediately
+        // follows the last task's tick.
+        std::thread::sleep(Duration::from_millis(50));
+    });
+
+    assert!(metrics.worker_poll_count(0) >= 20);
+}
@@ -0,0 +1,56 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn min_active_workers_keeps_workers_from_parking() {
+    // With the floor set to the number of workers, none of them should ever
+    // observe an actual park while the runtime is otherwise idle: declining
+    // to park is what keeps them "active" in the first place.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .min_active_workers(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {}).await.unwrap();
+
+        // Give both workers a chance to run out of work and hit the park path.
+        crate::time::sleep(Duration::from_millis(50)).await;
+    });
+
+    for i in 0..metrics.num_workers() {
+        assert_eq!(
+            metrics.worker_park_count(i),
+            0,
+            "worker {i} parked despite the min_active_workers floor"
+        );
+    }
+}
+
+#[test]
+fn min_active_workers_default_allows_parking() {
+    // Sanity check for the above: without the option, an idle worker parks
+    // like normal.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {}).await.unwrap();
+        crate::time::sleep(Duration::from_millis(50)).await;
+    });
+
+    let total_parks: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_park_count(i))
+        .sum();
+
+    assert!(total_parks > 0);
+}
@@ -72,6 +72,7 @@ cfg_loom! {
 
 cfg_not_loom! {
     mod inject;
+    mod priority_inject;
     mod queue;
 
     #[cfg(not(miri))]
@@ -79,4 +80,196 @@ cfg_not_loom! {
 
     #[cfg(miri)]
     mod task;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod driver_park_strategy;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod steal_back;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod task_pooling;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod max_lifo_duration;
+
+    #[cfg(feature = "rt-multi-thread")]
+    mod max_lifo_polls;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod on_inject_nonempty;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod min_active_workers;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod deadlock_detector;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod lost_wakeup_checks;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod placement;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod inject_queue_wait;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod locality_bias;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod steal_batch;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod test_only_rand_hook;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod rebalance_threshold;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod strict_defer_assertions;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread", target_has_atomic = "64"))]
+    mod steal_matrix;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod park_backoff;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod metrics_submit_interval;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod poll_outcome_metrics;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod lifo_eviction_metric;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod drive_once;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod max_worker_threads;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod max_concurrent_block_in_place;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod steal_order;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod on_core_callbacks;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod on_driver_poll;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread", target_has_atomic = "64"))]
+    mod defer_local_requeue;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod peak_searching_workers;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod spawn_inline;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod worker_stack_size;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod on_event_interval;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod spawn_when_idle;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod measure_lifo_polls_individually;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod deferred_wake_metrics;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod block_in_place_for;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod lifo_budget_demotion_metric;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod startup_distribution;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod inject_priority_over_local;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod update_config;
+
+    #[cfg(tokio_unstable)]
+    mod spawn_with_cpu_limit;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod notify_fast;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod task_budget;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod synced_lock_contention;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod worker_last_activity;
+
+    #[cfg(feature = "rt-multi-thread")]
+    mod stolen_task_fairness;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod worker_completed_tasks;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod pause_inject;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread", target_has_atomic = "64"))]
+    mod lifo_chain_length_metric;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod shutdown_requested;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread", target_has_atomic = "64"))]
+    mod acquire_core_batch_cap;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread", target_has_atomic = "64"))]
+    mod self_wake_metrics;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod on_all_idle;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread", target_has_atomic = "64"))]
+    mod driver_scheduled_tasks;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod block_in_place_reacquire_priority;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod strict_fifo;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod steal_search_denied;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod inject_to_worker;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod worker_overflow_ratio;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod cached_idle_recheck;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod metrics_sink;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod max_live_tasks;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod shutdown_phase_timing;
+
+    #[cfg(all(tokio_unstable, feature = "rt-multi-thread"))]
+    mod spawn_with_priority;
 }
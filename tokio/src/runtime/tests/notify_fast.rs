@@ -0,0 +1,53 @@
+use crate::runtime::Builder;
+use crate::sync::Notify;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn notify_fast_delivers_task() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .disable_lifo_slot()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let result = rt.block_on(rt.handle().notify_fast(0, async { 42 }));
+    assert_eq!(result.unwrap(), 42);
+}
+
+#[test]
+fn notify_fast_falls_back_to_inject_when_occupied() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .disable_lifo_slot()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let ready = Arc::new(Notify::new());
+    let driver_ready = ready.clone();
+
+    // Backs the sole worker's OS thread up with a blocking sleep, so neither
+    // task pushed into its mailbox below can be drained until both have
+    // already landed.
+    let driver = rt.spawn(async move {
+        driver_ready.notify_one();
+        std::thread::sleep(Duration::from_millis(200));
+    });
+
+    rt.block_on(ready.notified());
+
+    let first = rt.handle().notify_fast(0, async { "first" });
+    // The mailbox is occupied by `first` until the worker wakes back up, so
+    // this one must fall back to the inject queue instead of overwriting it.
+    let second = rt.handle().notify_fast(0, async { "second" });
+
+    let (driver, first, second) = rt.block_on(async move {
+        (driver.await, first.await, second.await)
+    });
+
+    driver.unwrap();
+    assert_eq!(first.unwrap(), "first");
+    assert_eq!(second.unwrap(), "second");
+}
@@ -0,0 +1,29 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn fires_when_the_last_worker_parks() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let calls = calls.clone();
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .on_all_idle(move |_budget| {
+                calls.fetch_add(1, Relaxed);
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        // Give the single worker a moment to run out of work and park; with
+        // only one worker, every park is also the last one.
+        crate::time::sleep(Duration::from_millis(50)).await;
+    });
+
+    assert!(calls.load(Relaxed) > 0);
+}
@@ -0,0 +1,59 @@
+use crate::runtime::Builder;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    Acquired(usize),
+    Released(usize),
+}
+
+#[test]
+fn on_core_acquired_and_released_bracket_being_idle() {
+    // A lone worker that runs out of work parks and gives its core back to
+    // the shared pool of available cores; once new work shows up, some
+    // worker (here, necessarily the same one) reacquires a core to run it.
+    // That round trip is exactly the "worker holds a core" lifetime
+    // `on_core_acquired`/`on_core_released` are meant to bracket. This is
+    // specific to `multi_thread_alt`: the default multi-threaded scheduler
+    // instead keeps its core for a worker's entire lifetime.
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    let rt = {
+        let acquired_events = events.clone();
+        let released_events = events.clone();
+        Builder::new_multi_thread_alt()
+            .worker_threads(1)
+            .on_core_acquired(move |index| {
+                acquired_events.lock().unwrap().push(Event::Acquired(index))
+            })
+            .on_core_released(move |index| {
+                released_events.lock().unwrap().push(Event::Released(index))
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {}).await.unwrap();
+
+        // Give the now-idle worker time to actually park and release its
+        // core before handing it more work.
+        std::thread::sleep(Duration::from_millis(50));
+
+        crate::spawn(async {}).await.unwrap();
+    });
+
+    let events = events.lock().unwrap();
+    let released_index = events
+        .iter()
+        .position(|e| *e == Event::Released(0))
+        .expect("worker 0 should have released its core once idle");
+    let reacquired = events[released_index + 1..].contains(&Event::Acquired(0));
+
+    assert!(
+        reacquired,
+        "expected an acquire to follow the release, got {events:?}"
+    );
+}
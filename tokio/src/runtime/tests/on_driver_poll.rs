@@ -0,0 +1,30 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn on_driver_poll_runs_alongside_maintenance() {
+    // Every maintenance cycle parks the driver with a zero timeout, so an
+    // idle worker's regular ticking is enough to observe the callback fire
+    // more than once without needing any actual I/O or timer activity.
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let calls = calls.clone();
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .on_driver_poll(move || {
+                calls.fetch_add(1, Relaxed);
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        crate::time::sleep(Duration::from_millis(50)).await;
+    });
+
+    assert!(calls.load(Relaxed) > 0);
+}
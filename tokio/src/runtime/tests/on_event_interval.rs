@@ -0,0 +1,33 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn on_event_interval_fires_alongside_maintenance() {
+    // With `event_interval(1)`, every scheduled task's poll crosses the
+    // maintenance boundary, so `on_event_interval` should fire at least once
+    // per spawned task, always with worker 0's index.
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let calls = calls.clone();
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .event_interval(1)
+            .on_event_interval(move |index| {
+                assert_eq!(index, 0);
+                calls.fetch_add(1, Relaxed);
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        for _ in 0..8 {
+            crate::spawn(async {}).await.unwrap();
+        }
+    });
+
+    assert!(calls.load(Relaxed) > 0);
+}
@@ -0,0 +1,28 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn on_inject_nonempty_fires_when_queue_transitions_empty_to_nonempty() {
+    let fire_count = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let fire_count = fire_count.clone();
+        Builder::new_multi_thread()
+            .worker_threads(1)
+            .on_inject_nonempty(move || {
+                fire_count.fetch_add(1, Relaxed);
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    // Spawning from outside a runtime thread always goes through the
+    // injection queue, so this drives it from empty to non-empty.
+    rt.spawn(async {});
+
+    rt.block_on(async {});
+
+    assert!(fire_count.load(Relaxed) > 0);
+}
@@ -0,0 +1,29 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn park_backoff_does_not_stall_scheduling() {
+    // With a tiny backoff, a worker that loses its core (via `block_in_place`)
+    // must still wake up and pick up new work promptly, rather than getting
+    // stuck waiting on an escalated timeout.
+    let rt = Builder::new_multi_thread_alt()
+        .worker_threads(1)
+        .park_backoff(Duration::from_millis(1), Duration::from_millis(5))
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            crate::task::block_in_place(|| {
+                std::thread::sleep(Duration::from_millis(20));
+            });
+        })
+        .await
+        .unwrap();
+
+        // The single worker gave its core away for the `block_in_place` call
+        // above and must reacquire one (via `wait_for_core`) to run this.
+        crate::spawn(async {}).await.unwrap();
+    });
+}
@@ -0,0 +1,34 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn paused_inject_holds_remote_tasks_until_resumed() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let handle = rt.handle().clone();
+    let ran = Arc::new(AtomicBool::new(false));
+
+    handle.pause_inject();
+
+    // Spawned from outside a worker thread, so this can only ever reach the
+    // worker via the injection queue.
+    let ran2 = ran.clone();
+    let jh = handle.spawn(async move {
+        ran2.store(true, Relaxed);
+    });
+
+    // Give the worker plenty of chances to (incorrectly) pick up the task
+    // while injection is paused.
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(!ran.load(Relaxed), "task ran while injection was paused");
+
+    handle.resume_inject();
+    rt.block_on(jh).unwrap();
+    assert!(ran.load(Relaxed));
+}
@@ -0,0 +1,72 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn peak_searching_workers_tracks_high_water_mark() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(4)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    // Every worker starts out idle. Spawning a batch of tasks all at once
+    // from outside the runtime wakes several of them up simultaneously, and
+    // each one searches for work before finding its share of the batch,
+    // bumping the number of workers concurrently searching above one.
+    let remaining = Arc::new(AtomicUsize::new(4));
+    rt.block_on(async {
+        let mut handles = Vec::new();
+        for _ in 0..4 {
+            let remaining = remaining.clone();
+            handles.push(crate::spawn(async move {
+                remaining.fetch_sub(1, Relaxed);
+                while remaining.load(Relaxed) > 0 {
+                    crate::task::yield_now().await;
+                }
+            }));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    });
+
+    let peak = metrics.peak_searching_workers();
+    assert!(peak >= 1);
+    assert!(peak <= metrics.num_workers());
+
+    // Resetting brings the high-water mark back down to (at most) however
+    // many workers happen to be searching right now, which is at most the
+    // total worker count and, with the runtime otherwise idle, usually zero.
+    metrics.reset_peak_searching_workers();
+    let after_reset = metrics.peak_searching_workers();
+    assert!(after_reset <= peak);
+
+    // Give the now-idle workers time to settle so a follow-up burst of work
+    // has fresh searching activity to record.
+    std::thread::sleep(Duration::from_millis(50));
+
+    let remaining = Arc::new(AtomicUsize::new(4));
+    rt.block_on(async {
+        let mut handles = Vec::new();
+        for _ in 0..4 {
+            let remaining = remaining.clone();
+            handles.push(crate::spawn(async move {
+                remaining.fetch_sub(1, Relaxed);
+                while remaining.load(Relaxed) > 0 {
+                    crate::task::yield_now().await;
+                }
+            }));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+    });
+
+    assert!(metrics.peak_searching_workers() >= after_reset);
+}
@@ -0,0 +1,58 @@
+use crate::runtime::{Builder, Placement};
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn placement_inject_lands_on_injection_queue() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let calls = calls.clone();
+        Builder::new_multi_thread()
+            .worker_threads(2)
+            .task_placement(move |_meta| {
+                calls.fetch_add(1, Relaxed);
+                Placement::Inject
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {}).await.unwrap();
+    });
+
+    // The hook runs once per spawn: here, once for the explicitly spawned
+    // task. The outer `block_on` future is not spawned, so it is not counted.
+    assert!(calls.load(Relaxed) >= 1);
+}
+
+#[test]
+fn placement_worker_targets_out_of_range_index_falls_back() {
+    // An out-of-range worker index must not panic or drop the task; it
+    // should just run like `Placement::Auto`.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .task_placement(|_meta| Placement::Worker(usize::MAX))
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let out = rt.block_on(async { crate::spawn(async { 42 }).await.unwrap() });
+
+    assert_eq!(out, 42);
+}
+
+#[test]
+fn placement_auto_is_the_default() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let out = rt.block_on(async { crate::spawn(async { 1 + 1 }).await.unwrap() });
+
+    assert_eq!(out, 2);
+}
@@ -0,0 +1,44 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn completed_and_pending_poll_counts_reflect_task_outcomes() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        for _ in 0..10 {
+            crate::spawn(async {}).await.unwrap();
+        }
+
+        crate::spawn(Never);
+
+        // Gives the worker a chance to poll the never-completing task and
+        // submit the resulting stats.
+        std::thread::sleep(Duration::from_millis(50));
+    });
+
+    assert!(metrics.worker_completed_poll_count(0) >= 10);
+    assert!(metrics.worker_pending_poll_count(0) >= 1);
+}
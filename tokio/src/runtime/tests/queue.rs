@@ -1,3 +1,4 @@
+use crate::runtime::config::OverflowPolicy;
 use crate::runtime::scheduler::multi_thread::{queue, Stats};
 use crate::runtime::task::{self, Schedule, Task, TaskHarnessScheduleHooks};
 
@@ -14,7 +15,7 @@ macro_rules! assert_metrics {
             use std::sync::atomic::Ordering::Relaxed;
 
             let worker = WorkerMetrics::new();
-            $stats.submit(&worker);
+            $stats.submit(&worker, 0, 0);
 
             let expect = $v;
             let actual = worker.$field.load(Relaxed);
@@ -37,7 +38,7 @@ fn fits_256_one_at_a_time() {
 
     for _ in 0..256 {
         let (task, _) = super::unowned(async {});
-        local.push_back_or_overflow(task, &inject, &mut stats);
+        local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
     }
 
     cfg_unstable_metrics! {
@@ -95,7 +96,7 @@ fn overflow() {
 
     for _ in 0..257 {
         let (task, _) = super::unowned(async {});
-        local.push_back_or_overflow(task, &inject, &mut stats);
+        local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
     }
 
     cfg_unstable_metrics! {
@@ -113,6 +114,62 @@ fn overflow() {
     assert_eq!(n, 257);
 }
 
+#[test]
+fn overflow_spill_newest_keeps_new_task_local() {
+    let (_, mut local) = queue::local();
+    let inject = RefCell::new(vec![]);
+    let mut stats = new_stats();
+
+    for _ in 0..257 {
+        let (task, _) = super::unowned(async {});
+        local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillNewest);
+    }
+
+    cfg_unstable_metrics! {
+        assert_metrics!(stats, overflow_count == 1);
+    }
+
+    // Unlike `SpillOldest` (see `overflow`, above), the task that triggered
+    // the overflow stays local instead of moving to the injection queue
+    // alongside the evicted half: only the 128 oldest tasks move, leaving
+    // 129 behind.
+    assert_eq!(inject.borrow().len(), 128);
+
+    let mut n = 0;
+    while local.pop().is_some() {
+        n += 1;
+    }
+    assert_eq!(n, 129);
+}
+
+#[test]
+fn overflow_reject_leaves_local_queue_untouched() {
+    let (_, mut local) = queue::local();
+    let inject = RefCell::new(vec![]);
+    let mut stats = new_stats();
+
+    for _ in 0..257 {
+        let (task, _) = super::unowned(async {});
+        local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::Reject);
+    }
+
+    // `Reject` never claims any tasks out of the local queue: the task that
+    // didn't fit is sent to the injection queue on its own, and nothing else
+    // moves.
+    cfg_unstable_metrics! {
+        assert_metrics!(stats, overflow_count == 0);
+        assert_metrics!(stats, overflow_reject_count == 1);
+    }
+
+    assert_eq!(inject.borrow().len(), 1);
+
+    let mut n = 0;
+    while local.pop().is_some() {
+        n += 1;
+    }
+    assert_eq!(n, 256);
+}
+
 #[test]
 fn steal_batch() {
     let mut stats = new_stats();
@@ -123,10 +180,10 @@ fn steal_batch() {
 
     for _ in 0..4 {
         let (task, _) = super::unowned(async {});
-        local1.push_back_or_overflow(task, &inject, &mut stats);
+        local1.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
     }
 
-    assert!(steal1.steal_into(&mut local2, &mut stats).is_some());
+    assert!(steal1.steal_into(&mut local2, &mut stats, None).is_some());
 
     cfg_unstable_metrics! {
         assert_metrics!(stats, steal_count == 2);
@@ -145,6 +202,34 @@ fn steal_batch() {
     assert!(local1.pop().is_none());
 }
 
+#[cfg(tokio_unstable)]
+#[test]
+fn steal_into_increments_migration_count() {
+    let mut stats = new_stats();
+
+    let (steal1, mut local1) = queue::local();
+    let (_, mut local2) = queue::local();
+    let inject = RefCell::new(vec![]);
+
+    for _ in 0..4 {
+        let (task, _) = super::unowned(async {});
+        assert_eq!(task.migration_count(), 0);
+        local1.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
+    }
+
+    let stolen = steal1.steal_into(&mut local2, &mut stats, None).unwrap();
+    assert_eq!(stolen.migration_count(), 1);
+
+    while let Some(task) = local2.pop() {
+        assert_eq!(task.migration_count(), 1);
+    }
+
+    // Tasks left behind in the un-stolen-from queue were not migrated.
+    while let Some(task) = local1.pop() {
+        assert_eq!(task.migration_count(), 0);
+    }
+}
+
 const fn normal_or_miri(normal: usize, miri: usize) -> usize {
     if cfg!(miri) {
         miri
@@ -173,7 +258,7 @@ fn stress1() {
             let mut n = 0;
 
             for _ in 0..NUM_STEAL {
-                if steal.steal_into(&mut local, &mut stats).is_some() {
+                if steal.steal_into(&mut local, &mut stats, None).is_some() {
                     n += 1;
                 }
 
@@ -196,7 +281,7 @@ fn stress1() {
         for _ in 0..NUM_LOCAL {
             for _ in 0..NUM_PUSH {
                 let (task, _) = super::unowned(async {});
-                local.push_back_or_overflow(task, &inject, &mut stats);
+                local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
             }
 
             for _ in 0..NUM_POP {
@@ -234,7 +319,7 @@ fn stress2() {
             let mut n = 0;
 
             for _ in 0..NUM_STEAL {
-                if steal.steal_into(&mut local, &mut stats).is_some() {
+                if steal.steal_into(&mut local, &mut stats, None).is_some() {
                     n += 1;
                 }
 
@@ -252,7 +337,7 @@ fn stress2() {
 
         for i in 0..NUM_TASKS {
             let (task, _) = super::unowned(async {});
-            local.push_back_or_overflow(task, &inject, &mut stats);
+            local.push_back_or_overflow(task, &inject, &mut stats, OverflowPolicy::SpillOldest);
 
             if i % 128 == 0 && local.pop().is_some() {
                 num_pop += 1;
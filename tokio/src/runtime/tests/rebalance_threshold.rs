@@ -0,0 +1,49 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn rebalance_threshold_moves_tasks_off_an_overloaded_worker() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .disable_lifo_slot()
+        .rebalance_threshold(1.0)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            // Deliberately imbalanced: every task lands on this worker's
+            // local queue, none on the idle peer's.
+            for _ in 0..32 {
+                crate::spawn(Never);
+            }
+
+            // Gives maintenance a chance to run and notice the imbalance.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    // `push_remote_task` increments this whenever a task lands on the
+    // injection queue, which is exactly what proactive rebalancing does.
+    assert!(metrics.remote_schedule_count() > 0);
+}
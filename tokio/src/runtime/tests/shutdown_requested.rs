@@ -0,0 +1,40 @@
+use crate::runtime::Builder;
+use std::sync::mpsc;
+use std::time::Duration;
+
+#[test]
+fn becomes_true_once_shutdown_starts() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let (started_tx, started_rx) = mpsc::channel();
+    let (done_tx, done_rx) = mpsc::channel();
+
+    rt.spawn(async move {
+        started_tx.send(()).unwrap();
+
+        while !crate::runtime::shutdown_requested() {
+            crate::task::yield_now().await;
+        }
+
+        done_tx.send(()).unwrap();
+    });
+
+    // Make sure the task is actually running before we ask the runtime to
+    // shut down.
+    started_rx.recv().unwrap();
+
+    rt.shutdown_background();
+
+    // The task should have observed the shutdown and bailed out on its own,
+    // rather than being forcibly dropped mid-poll.
+    done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+}
+
+#[test]
+fn false_outside_of_a_worker_context() {
+    assert!(!crate::runtime::shutdown_requested());
+}
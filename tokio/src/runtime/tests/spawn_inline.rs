@@ -0,0 +1,97 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn ready_future_completes_without_touching_the_queue() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let baseline = metrics.remote_schedule_count();
+
+        // Spawned from a worker (the task driving `block_on`'s continuation
+        // isn't one, so hop through a normal `spawn` first), a future that's
+        // immediately ready should complete inline: it never lands on any
+        // run queue, local or remote. The outer `crate::spawn` itself is a
+        // single remote schedule, since it's issued from `block_on`'s
+        // thread rather than a worker; that's the only one expected here.
+        crate::spawn(async {
+            let handle = crate::runtime::Handle::current().spawn_inline(async { 1 + 1 });
+            assert_eq!(handle.await.unwrap(), 2);
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(metrics.remote_schedule_count(), baseline + 1);
+    });
+}
+
+#[test]
+fn pending_future_falls_back_to_normal_scheduling() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            let handle = crate::runtime::Handle::current()
+                .spawn_inline(async { crate::task::yield_now().await });
+            handle.await.unwrap();
+        })
+        .await
+        .unwrap();
+    });
+}
+
+#[test]
+fn panic_during_inline_poll_is_reported_as_a_join_error() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            let handle = crate::runtime::Handle::current()
+                .spawn_inline(async { panic!("boom") });
+            assert!(handle.await.unwrap_err().is_panic());
+        })
+        .await
+        .unwrap();
+    });
+}
+
+#[test]
+fn outside_a_worker_falls_back_to_normal_spawn() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    // Called directly from `block_on`'s thread, which isn't a worker holding
+    // a core, `spawn_inline` should behave exactly like `spawn`.
+    let ran = Arc::new(AtomicBool::new(false));
+    let ran2 = ran.clone();
+    rt.block_on(async move {
+        rt_handle_spawn_inline(&ran2).await;
+    });
+    assert!(ran.load(Relaxed));
+
+    async fn rt_handle_spawn_inline(ran: &Arc<AtomicBool>) {
+        let ran = ran.clone();
+        crate::runtime::Handle::current()
+            .spawn_inline(async move { ran.store(true, Relaxed) })
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,58 @@
+use crate::runtime::Builder;
+use crate::sync::oneshot;
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn does_not_run_while_other_work_is_available() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let ran = Arc::new(AtomicBool::new(false));
+    let ran2 = ran.clone();
+
+    rt.block_on(async move {
+        let (idle_ran_tx, idle_ran_rx) = oneshot::channel();
+
+        crate::runtime::Handle::current().spawn_when_idle(async move {
+            ran2.store(true, Relaxed);
+            idle_ran_tx.send(()).unwrap();
+        });
+
+        // The idle task can't have run yet: nothing has given the single
+        // worker a chance to fall through to `next_idle_task`.
+        assert!(!ran.load(Relaxed));
+
+        // Once this task itself has nothing left to do, the worker picks
+        // the idle task up.
+        idle_ran_rx.await.unwrap();
+        assert!(ran.load(Relaxed));
+    });
+}
+
+#[test]
+fn never_touches_the_local_or_injection_queue() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let local_baseline = metrics.worker_local_schedule_count(0);
+        let remote_baseline = metrics.remote_schedule_count();
+
+        crate::runtime::Handle::current()
+            .spawn_when_idle(async { crate::task::yield_now().await })
+            .await
+            .unwrap();
+
+        assert_eq!(metrics.worker_local_schedule_count(0), local_baseline);
+        assert_eq!(metrics.remote_schedule_count(), remote_baseline);
+    });
+}
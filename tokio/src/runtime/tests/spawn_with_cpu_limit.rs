@@ -0,0 +1,37 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn completes_within_limit() {
+    let rt = Builder::new_current_thread().build().unwrap();
+
+    let result = rt.block_on(async {
+        rt.handle()
+            .spawn_with_cpu_limit(Duration::from_secs(60), async { 42 })
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result, Ok(42));
+}
+
+#[test]
+fn canceled_once_limit_exceeded() {
+    let rt = Builder::new_current_thread().build().unwrap();
+
+    let result = rt.block_on(async {
+        rt.handle()
+            .spawn_with_cpu_limit(Duration::from_millis(1), async {
+                loop {
+                    // Burn CPU time without yielding, so the limit is
+                    // exceeded on the very first poll.
+                    std::thread::sleep(Duration::from_millis(5));
+                    crate::task::yield_now().await;
+                }
+            })
+            .await
+            .unwrap()
+    });
+
+    assert!(result.is_err());
+}
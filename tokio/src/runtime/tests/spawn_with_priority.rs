@@ -0,0 +1,66 @@
+use crate::runtime::{Builder, Handle, TaskPriority};
+use crate::sync::Notify;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// Spawns 3 local children and one `priority` marker from within a task
+// running on the sole worker, so they go through `schedule_local` (unlike a
+// spawn from the test thread, which would land in the injection queue and
+// never touch the local placement logic under test). The worker's OS thread
+// is then blocked long enough for all four to sit queued before it comes
+// back and awaits them.
+fn run_order(priority: TaskPriority) -> Vec<u32> {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let ready = Arc::new(Notify::new());
+
+    let driver_order = order.clone();
+    let driver_ready = ready.clone();
+    let driver = rt.spawn(async move {
+        let mut children: Vec<_> = (0..3u32)
+            .map(|i| {
+                let order = driver_order.clone();
+                crate::spawn(async move {
+                    order.lock().unwrap().push(i);
+                })
+            })
+            .collect();
+
+        let marker_order = driver_order.clone();
+        children.push(Handle::current().spawn_with_priority(
+            async move {
+                marker_order.lock().unwrap().push(99);
+            },
+            priority,
+        ));
+
+        driver_ready.notify_one();
+        std::thread::sleep(Duration::from_millis(200));
+
+        for child in children {
+            child.await.unwrap();
+        }
+    });
+
+    rt.block_on(ready.notified());
+    rt.block_on(driver).unwrap();
+
+    Arc::try_unwrap(order).unwrap().into_inner().unwrap()
+}
+
+#[test]
+fn high_priority_task_runs_before_earlier_normal_tasks() {
+    let order = run_order(TaskPriority::High);
+    assert_eq!(order[0], 99);
+}
+
+#[test]
+fn low_priority_task_runs_after_earlier_normal_tasks() {
+    let order = run_order(TaskPriority::Low);
+    assert_eq!(*order.last().unwrap(), 99);
+}
@@ -0,0 +1,70 @@
+use crate::runtime::{Builder, StartupDistribution};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself. Used so a task, once
+/// picked up by a worker, stays there instead of completing and freeing the
+/// worker up to grab more than its assigned share.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn round_robin_local_fans_a_startup_burst_across_all_workers_quickly() {
+    const WORKERS: usize = 4;
+
+    // Actually distributing a burst across `WORKERS` distinct OS threads at
+    // once needs at least that many CPUs available to run them concurrently.
+    // On a more constrained machine, one worker thread can keep winning the
+    // CPU and draining the injection queue before its siblings ever get
+    // scheduled, which isn't what this test is meant to catch.
+    if std::thread::available_parallelism().map_or(0, |n| n.get()) < WORKERS {
+        return;
+    }
+
+    let rt = Builder::new_multi_thread()
+        .worker_threads(WORKERS)
+        .disable_lifo_slot()
+        .startup_distribution(StartupDistribution::RoundRobinLocal)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        for _ in 0..WORKERS {
+            crate::spawn(Never);
+        }
+    });
+
+    // Each of the `WORKERS` tasks above was round-robined to, and its target
+    // worker explicitly woken, so every worker should end up polling its own
+    // task well before a lazier natural drain of the injection queue would
+    // have reached it.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if (0..WORKERS).all(|i| metrics.worker_poll_count(i) > 0) {
+            break;
+        }
+
+        assert!(
+            Instant::now() < deadline,
+            "not all {WORKERS} workers had polled a task before the deadline: {:?}",
+            (0..WORKERS)
+                .map(|i| metrics.worker_poll_count(i))
+                .collect::<Vec<_>>()
+        );
+
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn steal_back_pushes_stolen_batch_to_injection_queue() {
+    // This only applies to the multi-threaded scheduler.
+    //
+    // One worker queues up a pile of tasks that all block on their first
+    // poll, then backs its own OS thread up with a blocking sleep. That
+    // forces the other worker to steal the queued tasks; since every one of
+    // them blocks immediately, steal-back should push the unrun remainder of
+    // the batch to the injection queue at least once.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .disable_lifo_slot()
+        .steal_back(true)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            // Backs up this worker thread long enough for the other worker
+            // to steal the tasks queued above.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    let steal_back_count: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_steal_back_count(i))
+        .sum();
+
+    assert!(steal_back_count > 0);
+}
@@ -0,0 +1,69 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+fn average_steal_batch_size(max: Option<usize>) -> f64 {
+    let mut builder = Builder::new_multi_thread();
+    builder.worker_threads(2).disable_lifo_slot();
+
+    if let Some(max) = max {
+        builder.steal_batch(max);
+    }
+
+    let rt = builder.enable_all().build().unwrap();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            // Backs up this worker thread long enough for the other, idle
+            // worker to search for work and steal the tasks queued above.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    let steal_count: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_steal_count(i))
+        .sum();
+    let steal_operations: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_steal_operations(i))
+        .sum();
+
+    assert!(steal_count > 0, "expected at least one task to be stolen");
+
+    steal_count as f64 / steal_operations as f64
+}
+
+#[test]
+fn steal_batch_unset_steals_in_batches_larger_than_one() {
+    // With the default (unset) `steal_batch`, a steal attempt moves roughly
+    // half of the victim's queue, so most attempts should move more than a
+    // single task.
+    assert!(average_steal_batch_size(None) > 1.0);
+}
+
+#[test]
+fn steal_batch_one_steals_a_single_task_at_a_time() {
+    // With `steal_batch(1)`, every successful steal attempt should move
+    // exactly one task.
+    assert_eq!(average_steal_batch_size(Some(1)), 1.0);
+}
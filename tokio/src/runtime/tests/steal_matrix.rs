@@ -0,0 +1,60 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn steal_matrix_tracks_stealer_and_victim() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .disable_lifo_slot()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            // Deliberately imbalanced: every task lands on this worker's
+            // local queue, none on the idle peer's, giving it something to
+            // steal.
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            // Gives the idle worker a chance to search for work and steal.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    let matrix = metrics.steal_matrix();
+    assert_eq!(matrix.len(), 2);
+    assert_eq!(matrix[0].len(), 2);
+    assert_eq!(matrix[1].len(), 2);
+
+    // A worker never steals from itself.
+    assert_eq!(matrix[0][0], 0);
+    assert_eq!(matrix[1][1], 0);
+
+    // Exactly one of the two workers did the stealing, since one was busy
+    // running the spawning task the whole time.
+    let total: u64 = matrix[0][1] + matrix[1][0];
+    assert!(total > 0);
+    assert_eq!(matrix.iter().flatten().sum::<u64>(), total);
+}
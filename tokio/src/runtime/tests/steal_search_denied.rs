@@ -0,0 +1,53 @@
+use crate::runtime::Builder;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+
+#[test]
+fn increments_when_the_half_searcher_guard_denies_a_worker() {
+    // With exactly two workers, the idle subsystem's half-searcher guard
+    // (`2 * num_searching >= num_workers`) denies the second worker outright
+    // the moment the first is already searching, so this only needs two
+    // workers to race for real, rather than needing many workers to line up
+    // at once.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    let total_denied =
+        || (0..metrics.num_workers()).map(|w| metrics.worker_steal_search_denied_count(w)).sum::<u64>();
+
+    // Waking both workers at once, with nothing but this single batch of
+    // tasks to go around, means both find their local queue empty and try
+    // to search for work to steal at roughly the same time. Retry the race
+    // a few times in case the two threads don't land close enough together
+    // on a given attempt.
+    for _ in 0..50 {
+        let remaining = Arc::new(AtomicUsize::new(2));
+        rt.block_on(async {
+            let mut handles = Vec::new();
+            for _ in 0..2 {
+                let remaining = remaining.clone();
+                handles.push(crate::spawn(async move {
+                    remaining.fetch_sub(1, Relaxed);
+                    while remaining.load(Relaxed) > 0 {
+                        crate::task::yield_now().await;
+                    }
+                }));
+            }
+
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        });
+
+        if total_denied() > 0 {
+            break;
+        }
+    }
+
+    assert!(total_denied() > 0);
+}
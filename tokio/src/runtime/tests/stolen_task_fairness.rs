@@ -0,0 +1,79 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, Ordering::Relaxed};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that reschedules itself via a self-wakeup a fixed number of
+/// times before completing, to mimic a locally-generated ping-pong task
+/// that keeps reclaiming the LIFO slot.
+struct PingPong {
+    remaining: u32,
+}
+
+impl Future for PingPong {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.remaining == 0 {
+            Poll::Ready(())
+        } else {
+            self.remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[test]
+fn stolen_tasks_make_progress_under_local_ping_pong() {
+    // One worker queues up sentinel tasks, then backs its own OS thread up
+    // with a blocking sleep, forcing the other worker to steal them (as in
+    // `steal_back_pushes_stolen_batch_to_injection_queue`). Meanwhile, a
+    // handful of locally-generated ping-pong tasks keep reclaiming whichever
+    // worker's LIFO slot they land on. Since stolen tasks are tagged to
+    // always reschedule FIFO, they must still complete promptly instead of
+    // being starved behind an endless run of LIFO-favored local work.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let completed = Arc::new(AtomicU32::new(0));
+    const SENTINELS: u32 = 8;
+
+    rt.block_on(async {
+        for _ in 0..4 {
+            crate::spawn(PingPong { remaining: 200_000 });
+        }
+
+        crate::spawn({
+            let completed = completed.clone();
+            async move {
+                for _ in 0..SENTINELS {
+                    let completed = completed.clone();
+                    crate::spawn(async move {
+                        completed.fetch_add(1, Relaxed);
+                    });
+                }
+
+                // Backs up this worker thread long enough for the other
+                // worker to steal the sentinel tasks queued above.
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        })
+        .await
+        .unwrap();
+
+        crate::time::timeout(Duration::from_secs(10), async {
+            while completed.load(Relaxed) < SENTINELS {
+                crate::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("stolen sentinel tasks should complete despite local LIFO ping-pong");
+    });
+}
@@ -0,0 +1,31 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+// Regression test for a worker finding deferred (yielded) tasks right as it
+// is about to park. With the default (strict) setting this would trip a
+// `debug_assert!` in debug builds; with `strict_defer_assertions(false)` the
+// worker should instead flush the deferred tasks and keep making progress.
+#[test]
+fn non_strict_defer_assertions_survive_deferred_wakers_near_park() {
+    let rt = Builder::new_multi_thread_alt()
+        .worker_threads(1)
+        .strict_defer_assertions(false)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        // `yield_now` pushes the current task onto the worker's defer list
+        // rather than rescheduling it immediately, which is exactly the
+        // list that must be empty (or gracefully flushed) right before the
+        // worker parks.
+        for _ in 0..1_000 {
+            crate::task::yield_now().await;
+        }
+
+        // Interleave a timer so the driver is polled while the defer list
+        // may still be non-empty, increasing the odds the worker reaches
+        // the pre-park check with deferred work outstanding.
+        crate::time::sleep(Duration::from_millis(1)).await;
+    });
+}
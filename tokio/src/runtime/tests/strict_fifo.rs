@@ -0,0 +1,40 @@
+use crate::runtime::Builder;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn drains_local_tasks_in_spawn_order() {
+    // Spawning several tasks back-to-back from a running task exercises the
+    // LIFO slot: without `strict_fifo`, each new spawn evicts whatever the
+    // previous spawn just placed in the slot, so the *last* task spawned
+    // here would normally run *first*. `strict_fifo` disables the LIFO slot
+    // (and stealing, moot with a single worker) so the run queue is drained
+    // in exactly the order tasks were scheduled.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .strict_fifo()
+        .build()
+        .unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    rt.block_on(async {
+        let order = order.clone();
+        crate::spawn(async move {
+            let mut handles = Vec::new();
+            for i in 0..5 {
+                let order = order.clone();
+                handles.push(crate::spawn(async move {
+                    order.lock().unwrap().push(i);
+                }));
+            }
+
+            for handle in handles {
+                handle.await.unwrap();
+            }
+        })
+        .await
+        .unwrap();
+    });
+
+    assert_eq!(*order.lock().unwrap(), vec![0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,26 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn synced_lock_contention_time_reflects_remote_spawns() {
+    let rt = Builder::new_multi_thread_alt()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    assert_eq!(metrics.synced_lock_contention_time(), Duration::ZERO);
+
+    // Every remote spawn locks `synced` to push onto the injection queue, so
+    // spawning enough of them is certain to land at least one sample.
+    for _ in 0..1000 {
+        rt.spawn(async {});
+    }
+
+    rt.block_on(async {
+        crate::time::sleep(Duration::from_millis(10)).await;
+    });
+
+    assert!(metrics.synced_lock_contention_time() > Duration::ZERO);
+}
@@ -360,7 +360,7 @@ fn with(f: impl FnOnce(Runtime)) {
     let _reset = Reset;
 
     let rt = Runtime(Arc::new(Inner {
-        owned: OwnedTasks::new(16),
+        owned: OwnedTasks::new(16, None),
         core: Mutex::new(Core {
             queue: VecDeque::new(),
         }),
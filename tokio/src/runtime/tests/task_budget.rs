@@ -0,0 +1,25 @@
+use crate::runtime::coop;
+use crate::runtime::Builder;
+
+#[test]
+fn task_budget_overrides_default_per_worker() {
+    // A single worker, so the spawned task is guaranteed to land on the one
+    // whose budget we configured.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .task_budget(|index| {
+            assert_eq!(index, 0);
+            5
+        })
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            assert_eq!(coop::current_task_budget(), Some(5));
+        })
+        .await
+        .unwrap();
+    });
+}
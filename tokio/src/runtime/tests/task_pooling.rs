@@ -0,0 +1,23 @@
+use crate::runtime::Builder;
+
+// `task_pooling` is `pub(crate)`-only (not exposed as public API), so it
+// can't be exercised from a `benches/` binary the way most scheduler knobs
+// are. This instead runs the tight spawn/complete loop the pooling path is
+// meant for from an internal test, checking that recycled allocations still
+// produce correct results across many reuses of the same future type.
+#[test]
+fn task_pooling_reuses_allocations_across_a_spawn_loop() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .task_pooling(true)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        for i in 0..1_000u64 {
+            let out = crate::spawn(async move { i * 2 }).await.unwrap();
+            assert_eq!(out, i * 2);
+        }
+    });
+}
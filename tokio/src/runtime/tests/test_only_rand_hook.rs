@@ -0,0 +1,56 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[test]
+fn test_only_rand_hook_overrides_steal_start_index() {
+    let call_count = Arc::new(AtomicUsize::new(0));
+
+    let rt = {
+        let call_count = call_count.clone();
+        Builder::new_multi_thread()
+            .worker_threads(2)
+            .disable_lifo_slot()
+            .test_only_rand_hook(move |num| {
+                call_count.fetch_add(1, Relaxed);
+                // Deterministically "pick" the first worker every time,
+                // instead of the real RNG's random starting index.
+                num.saturating_sub(num)
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        crate::spawn(async {
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            // Backs up this worker thread long enough for the other, idle
+            // worker to search for work, which is what consults the hook.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    assert!(call_count.load(Relaxed) > 0);
+}
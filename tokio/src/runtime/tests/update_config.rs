@@ -0,0 +1,87 @@
+use crate::runtime::Builder;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// A future that returns `Pending` on every poll without ever registering a
+/// waker, i.e. it blocks forever without waking itself.
+struct Never;
+
+impl Future for Never {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+fn steal_back_count(rt: &crate::runtime::Runtime) -> u64 {
+    let metrics = rt.metrics();
+    (0..metrics.num_workers())
+        .map(|i| metrics.worker_steal_back_count(i))
+        .sum()
+}
+
+#[test]
+fn update_config_disables_steal_back_dynamically() {
+    // Built with `steal_back` on, but immediately turned back off through
+    // `update_config` before any work runs.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .disable_lifo_slot()
+        .steal_back(true)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.handle().update_config(|config| {
+        config.set_steal_back(false);
+    });
+
+    rt.block_on(async {
+        crate::spawn(async {
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            // Backs up this worker thread long enough for the other worker
+            // to steal the queued tasks above.
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    assert_eq!(steal_back_count(&rt), 0);
+}
+
+#[test]
+fn update_config_enables_steal_back_dynamically() {
+    // Built with `steal_back` off (the default), then turned on through
+    // `update_config` before any work runs.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(2)
+        .disable_lifo_slot()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.handle().update_config(|config| {
+        config.set_steal_back(true);
+    });
+
+    rt.block_on(async {
+        crate::spawn(async {
+            for _ in 0..8 {
+                crate::spawn(Never);
+            }
+
+            std::thread::sleep(Duration::from_millis(100));
+        })
+        .await
+        .unwrap();
+    });
+
+    assert!(steal_back_count(&rt) > 0);
+}
@@ -0,0 +1,44 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn completed_tasks_tracked_on_multi_thread() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        for _ in 0..10 {
+            crate::spawn(async {}).await.unwrap();
+        }
+
+        // Gives the worker a chance to submit the resulting stats.
+        std::thread::sleep(Duration::from_millis(50));
+    });
+
+    assert!(metrics.worker_completed_tasks(0) >= 10);
+}
+
+#[test]
+fn completed_tasks_tracked_on_current_thread() {
+    // `worker_completed_poll_count` is always 0 on the current thread
+    // runtime, but `worker_completed_tasks` is tracked by every scheduler
+    // flavor, so it should still advance here.
+    let rt = Builder::new_current_thread().enable_all().build().unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        for _ in 0..10 {
+            crate::spawn(async {}).await.unwrap();
+        }
+    });
+
+    assert_eq!(metrics.worker_completed_poll_count(0), 0);
+    assert!(metrics.worker_completed_tasks(0) >= 10);
+}
@@ -0,0 +1,28 @@
+use crate::runtime::Builder;
+use std::time::{Duration, Instant};
+
+#[test]
+fn worker_last_activity_advances_as_tasks_run() {
+    // `block_on` itself drives the outer future on the calling thread rather
+    // than through the worker pool, so `last_activity` is only observed to
+    // advance by spawning work onto the pool's one worker.
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let handle = rt.handle().clone();
+    rt.block_on(handle.spawn(async {})).unwrap();
+    let before = handle.worker_last_activity(0);
+
+    rt.block_on(async {
+        crate::time::sleep(Duration::from_millis(1)).await;
+    });
+    rt.block_on(handle.spawn(async {})).unwrap();
+
+    let after = handle.worker_last_activity(0);
+
+    assert!(after > before);
+    assert!(after <= Instant::now());
+}
@@ -0,0 +1,53 @@
+use crate::runtime::Builder;
+use std::time::Duration;
+
+#[test]
+fn zero_before_any_local_schedules() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+    assert_eq!(metrics.worker_overflow_ratio(0), 0.0);
+}
+
+#[test]
+fn reflects_the_share_of_local_schedules_that_overflowed() {
+    let rt = Builder::new_multi_thread()
+        .worker_threads(1)
+        .metrics_submit_interval(1)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        crate::spawn(async {
+            // Spawned back-to-back from within the worker that's running
+            // this task, each of these lands on the local run queue until
+            // it's full, at which point the worker overflows half of it to
+            // the injection queue to make room.
+            for _ in 0..1024 {
+                crate::spawn(async {});
+            }
+        })
+        .await
+        .unwrap();
+
+        // Gives the worker a chance to submit the resulting stats.
+        std::thread::sleep(Duration::from_millis(50));
+    });
+
+    let overflow_count = metrics.worker_overflow_count(0);
+    let local_schedule_count = metrics.worker_local_schedule_count(0);
+    let ratio = metrics.worker_overflow_ratio(0);
+
+    assert!(overflow_count > 0);
+    assert_eq!(
+        ratio,
+        overflow_count as f64 / (overflow_count + local_schedule_count) as f64
+    );
+}
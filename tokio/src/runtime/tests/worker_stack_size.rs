@@ -0,0 +1,34 @@
+use crate::runtime::Builder;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn worker_stack_size_is_queried_once_per_worker() {
+    // Each worker's OS thread should be spawned with the size the hook
+    // computes for its own index. The hook is consulted each time a thread
+    // is spawned to run a worker's core, so with no `block_in_place` core
+    // handoff here, that is exactly once per worker.
+    let queried = Arc::new(Mutex::new(Vec::new()));
+
+    let rt = {
+        let queried = queried.clone();
+        Builder::new_multi_thread()
+            .worker_threads(2)
+            .worker_stack_size(move |index| {
+                queried.lock().unwrap().push(index);
+                2 * 1024 * 1024
+            })
+            .enable_all()
+            .build()
+            .unwrap()
+    };
+
+    rt.block_on(async {
+        for _ in 0..2 {
+            crate::spawn(async {}).await.unwrap();
+        }
+    });
+
+    let mut queried = queried.lock().unwrap().clone();
+    queried.sort_unstable();
+    assert_eq!(*queried, [0, 1]);
+}
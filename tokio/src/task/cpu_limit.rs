@@ -0,0 +1,75 @@
+use pin_project_lite::pin_project;
+use std::error;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+pin_project! {
+    /// Future for the [`Handle::spawn_with_cpu_limit`] method.
+    ///
+    /// [`Handle::spawn_with_cpu_limit`]: crate::runtime::Handle::spawn_with_cpu_limit
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub(crate) struct WithCpuLimit<F> {
+        #[pin]
+        inner: F,
+        limit: Duration,
+        used: Duration,
+    }
+}
+
+impl<F> WithCpuLimit<F> {
+    pub(crate) fn new(inner: F, limit: Duration) -> Self {
+        WithCpuLimit {
+            inner,
+            limit,
+            used: Duration::ZERO,
+        }
+    }
+}
+
+impl<F: Future> Future for WithCpuLimit<F> {
+    type Output = Result<F::Output, CpuLimitExceeded>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let me = self.project();
+
+        // Measures the wall-clock time spent inside this one poll call, which
+        // is the same "poll time" the scheduler's own `start_poll`/`end_poll`
+        // bracketing in `run_task` measures for its per-worker stats. Summed
+        // across polls, it approximates the task's CPU time to the
+        // granularity of a single poll: a task that never yields can overrun
+        // `limit` by however long that final poll takes.
+        let start = Instant::now();
+        let poll = me.inner.poll(cx);
+        *me.used += start.elapsed();
+
+        match poll {
+            Poll::Ready(output) => Poll::Ready(Ok(output)),
+            Poll::Pending if *me.used >= *me.limit => Poll::Ready(Err(CpuLimitExceeded::new())),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Error returned when a task spawned with [`Handle::spawn_with_cpu_limit`]
+/// is canceled after accumulating more poll time than its configured limit.
+///
+/// [`Handle::spawn_with_cpu_limit`]: crate::runtime::Handle::spawn_with_cpu_limit
+#[derive(Debug, PartialEq, Eq)]
+pub struct CpuLimitExceeded(());
+
+impl CpuLimitExceeded {
+    pub(crate) fn new() -> Self {
+        CpuLimitExceeded(())
+    }
+}
+
+impl fmt::Display for CpuLimitExceeded {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "task exceeded its CPU time limit".fmt(fmt)
+    }
+}
+
+impl error::Error for CpuLimitExceeded {}
@@ -694,7 +694,9 @@ impl LocalSet {
                 // task initially. Because `LocalSet` itself is `!Send`, and
                 // `spawn_local` spawns into the `LocalSet` on the current
                 // thread, the invariant is maintained.
-                Some(task) => crate::runtime::coop::budget(|| task.run()),
+                Some(task) => {
+                    crate::runtime::coop::budget(|| task.run());
+                }
                 // We have fully drained the queue of notified tasks, so the
                 // local future doesn't need to be notified again — it can wait
                 // until something else wakes a task in the local set.
@@ -323,6 +323,8 @@
 
 cfg_rt! {
     pub use crate::runtime::task::{JoinError, JoinHandle};
+    #[cfg(tokio_unstable)]
+    pub use crate::runtime::task::TrySpawnError;
 
     mod blocking;
     pub use blocking::spawn_blocking;
@@ -361,6 +363,12 @@ cfg_rt! {
 
     pub use crate::runtime::task::{Id, id, try_id};
 
+    cfg_unstable! {
+        mod cpu_limit;
+        pub use cpu_limit::CpuLimitExceeded;
+        pub(crate) use cpu_limit::WithCpuLimit;
+    }
+
     cfg_trace! {
         mod builder;
         pub use builder::Builder;
@@ -36,12 +36,17 @@ impl MetricAtomicU64 {
         pub(crate) fn add(&self, value: u64, ordering: Ordering) {
             self.value.fetch_add(value, ordering);
         }
+
+        pub(crate) fn fetch_max(&self, value: u64, ordering: Ordering) {
+            self.value.fetch_max(value, ordering);
+        }
     }
 
     cfg_no_64bit_metrics! {
         pub(crate) fn store(&self, _val: u64, _ordering: Ordering) { }
         // on platforms without 64-bit atomics, fetch-add returns unit
         pub(crate) fn add(&self, _value: u64, _ordering: Ordering) {  }
+        pub(crate) fn fetch_max(&self, _value: u64, _ordering: Ordering) {  }
         pub(crate) fn new(_value: u64) -> Self { Self { } }
     }
 }
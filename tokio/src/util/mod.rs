@@ -76,6 +76,11 @@ cfg_rt_multi_thread! {
     pub(crate) use try_lock::TryLock;
 }
 
+cfg_unstable_metrics! {
+    mod thread_cpu_time;
+    pub(crate) use thread_cpu_time::thread_cpu_time;
+}
+
 pub(crate) mod trace;
 
 pub(crate) mod error;
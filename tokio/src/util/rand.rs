@@ -52,6 +52,12 @@ impl RngSeed {
     fn from_pair(s: u32, r: u32) -> Self {
         Self { s, r }
     }
+
+    /// Packs this seed into a single `u64`, for reporting purposes.
+    #[cfg(tokio_unstable)]
+    pub(crate) fn as_u64(&self) -> u64 {
+        (u64::from(self.s) << 32) | u64::from(self.r)
+    }
 }
 
 impl FastRand {
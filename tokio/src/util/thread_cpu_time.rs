@@ -0,0 +1,30 @@
+//! Best-effort access to the calling thread's CPU time, as opposed to the
+//! wall-clock time it has been alive. Only implemented on Linux, where
+//! `clock_gettime(CLOCK_THREAD_CPUTIME_ID)` gives us this directly; every
+//! other platform reports "unavailable" rather than approximating it.
+
+use std::time::Duration;
+
+/// Returns the amount of CPU time the calling thread has consumed so far, or
+/// `None` if this platform doesn't expose that information.
+#[cfg(not(all(target_os = "linux", feature = "libc")))]
+pub(crate) fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+/// Returns the amount of CPU time the calling thread has consumed so far, or
+/// `None` if this platform doesn't expose that information.
+#[cfg(all(target_os = "linux", feature = "libc"))]
+pub(crate) fn thread_cpu_time() -> Option<Duration> {
+    // SAFETY: `timespec` is a plain data struct and `ts` is fully
+    // initialized by `clock_gettime` before we read from it below.
+    unsafe {
+        let mut ts: libc::timespec = std::mem::zeroed();
+
+        if libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) != 0 {
+            return None;
+        }
+
+        Some(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+    }
+}
@@ -0,0 +1,89 @@
+#![allow(unknown_lints, unexpected_cfgs)]
+#![cfg(all(tokio_unstable, feature = "full"))]
+
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::sync::Arc;
+use tokio::runtime::{self, Handle};
+use tokio::task::yield_now;
+
+#[test]
+fn runs_on_every_worker() {
+    const WORKERS: usize = 4;
+
+    let rt = runtime::Builder::new_multi_thread()
+        .worker_threads(WORKERS)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        // Keep every worker busy so they aren't all idling on the same
+        // thread by the time the barrier request comes in.
+        let tasks: Vec<_> = (0..WORKERS * 4)
+            .map(|_| {
+                tokio::spawn(async {
+                    for _ in 0..50 {
+                        yield_now().await;
+                    }
+                })
+            })
+            .collect();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let barrier_calls = calls.clone();
+
+        Handle::current()
+            .barrier(move || {
+                barrier_calls.fetch_add(1, Relaxed);
+            })
+            .await;
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(calls.load(Relaxed), WORKERS);
+    });
+}
+
+#[test]
+fn concurrent_calls_are_serialized() {
+    let rt = runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    rt.block_on(async {
+        let handle = Handle::current();
+        // Records, in observed order, which of the two `barrier` calls each
+        // per-worker callback invocation belongs to. If calls were allowed
+        // to run concurrently, worker callbacks from both calls could
+        // interleave; serialized, every `1` should be grouped together and
+        // every `2` should be grouped together.
+        let order: Arc<std::sync::Mutex<Vec<u8>>> = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let run = |handle: Handle, order: Arc<std::sync::Mutex<Vec<u8>>>, id: u8| async move {
+            handle
+                .barrier(move || {
+                    order.lock().unwrap().push(id);
+                    std::thread::yield_now();
+                })
+                .await;
+        };
+
+        tokio::join!(
+            run(handle.clone(), order.clone(), 1),
+            run(handle.clone(), order.clone(), 2),
+        );
+
+        let order = order.lock().unwrap();
+        assert_eq!(order.len(), 4);
+        // The two calls may complete in either order, but must not
+        // interleave: the first two entries should share one id, and the
+        // last two should share the other.
+        assert_eq!(order[0], order[1]);
+        assert_eq!(order[2], order[3]);
+        assert_ne!(order[0], order[2]);
+    });
+}
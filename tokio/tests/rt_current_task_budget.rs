@@ -0,0 +1,38 @@
+#![allow(unknown_lints, unexpected_cfgs)]
+#![warn(rust_2018_idioms)]
+#![cfg(all(feature = "full", tokio_unstable))]
+
+use tokio::runtime::{current_task_budget, Runtime};
+
+#[test]
+fn current_task_budget_is_none_outside_of_a_task() {
+    assert_eq!(None, current_task_budget());
+}
+
+#[test]
+fn current_task_budget_is_some_inside_block_on() {
+    // `block_on`'s own future is polled under a coop budget too, even
+    // though it isn't a spawned task.
+    let rt = Runtime::new().unwrap();
+    rt.block_on(async {
+        assert!(current_task_budget().is_some());
+    });
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn current_task_budget_is_some_inside_a_task() {
+    assert!(current_task_budget().is_some());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn current_task_budget_decreases_as_yield_points_are_consumed() {
+    let before = current_task_budget().unwrap();
+
+    for _ in 0..8 {
+        tokio::task::consume_budget().await;
+    }
+
+    let after = current_task_budget().unwrap();
+
+    assert!(after < before);
+}
@@ -86,6 +86,152 @@ mod unstable {
 
         assert_ne!(rt1.handle().id(), rt2.handle().id());
     }
+
+    #[test]
+    fn spawn_and_await_n_waits_for_every_task() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let rt = rt();
+        let handle = rt.handle();
+        let completed = Arc::new(AtomicUsize::new(0));
+
+        rt.block_on(async {
+            let tasks = (0..50).map(|_| {
+                let completed = completed.clone();
+                async move {
+                    completed.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+
+            handle.spawn_and_await_n(tasks).await;
+        });
+
+        assert_eq!(completed.load(Ordering::Relaxed), 50);
+    }
+
+    #[test]
+    fn spawn_and_await_n_with_no_tasks_completes_immediately() {
+        let rt = rt();
+        let handle = rt.handle();
+
+        rt.block_on(async {
+            handle
+                .spawn_and_await_n(std::iter::empty::<std::future::Ready<()>>())
+                .await;
+        });
+    }
+
+    #[test]
+    fn worker_rng_seeds_is_empty_for_current_thread() {
+        let rt = rt();
+
+        assert!(rt.handle().worker_rng_seeds().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rt-multi-thread")]
+    fn worker_rng_seeds_has_one_entry_per_worker() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(4)
+            .build()
+            .unwrap();
+
+        assert_eq!(rt.handle().worker_rng_seeds().len(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "rt-multi-thread")]
+    fn worker_rng_seeds_are_reproducible_from_the_same_rng_seed() {
+        use tokio::runtime::RngSeed;
+
+        let build = || {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(3)
+                .rng_seed(RngSeed::from_bytes(b"worker_rng_seeds test seed"))
+                .build()
+                .unwrap()
+        };
+
+        let rt1 = build();
+        let rt2 = build();
+
+        assert_eq!(
+            rt1.handle().worker_rng_seeds(),
+            rt2.handle().worker_rng_seeds()
+        );
+    }
+
+    #[test]
+    fn set_lifo_enabled_all_is_a_no_op_for_current_thread() {
+        let rt = rt();
+
+        // Just checking this doesn't panic; the current-thread scheduler has
+        // no LIFO slot to disable.
+        rt.handle().set_lifo_enabled_all(false);
+    }
+
+    #[test]
+    #[cfg(feature = "rt-multi-thread")]
+    fn set_lifo_enabled_all_can_be_toggled() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .build()
+            .unwrap();
+        let handle = rt.handle();
+
+        handle.set_lifo_enabled_all(false);
+        handle.set_lifo_enabled_all(true);
+    }
+
+    #[test]
+    fn yield_core_hint_is_a_no_op_outside_of_a_task() {
+        let rt = rt();
+
+        // Just checking this doesn't panic; there's no worker context to
+        // offer a backlog from.
+        rt.handle().yield_core_hint();
+    }
+
+    #[test]
+    fn yield_core_hint_is_a_no_op_for_current_thread() {
+        let rt = rt();
+
+        rt.block_on(async {
+            // The current-thread scheduler has no idle peers to offer a
+            // backlog to; just checking this doesn't panic.
+            tokio::runtime::Handle::current().yield_core_hint();
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "rt-multi-thread")]
+    fn yield_core_hint_moves_queued_backlog_to_the_injection_queue() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .build()
+            .unwrap();
+        let metrics = rt.metrics();
+
+        rt.block_on(async {
+            // Run on the sole worker, so the tasks spawned below land on its
+            // local queue and only the hint can move them to the injection
+            // queue.
+            tokio::spawn(async move {
+                let handle = tokio::runtime::Handle::current();
+
+                for _ in 0..8 {
+                    tokio::spawn(std::future::pending::<()>());
+                }
+
+                handle.yield_core_hint();
+
+                assert!(metrics.injection_queue_depth() > 0);
+            })
+            .await
+            .unwrap();
+        });
+    }
 }
 
 fn rt() -> Runtime {
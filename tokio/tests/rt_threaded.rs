@@ -65,6 +65,23 @@ fn many_oneshot_futures() {
     }
 }
 
+#[test]
+fn spawn_before_workers_are_running() {
+    // `Builder::build` returns as soon as worker threads have been requested,
+    // not once they've actually started running. A task spawned right after
+    // that call races the workers' startup, and must sit in the injection
+    // queue until one of them comes up and checks it, rather than getting
+    // lost because no worker was around yet to be woken.
+    let (tx, rx) = mpsc::channel();
+
+    let rt = rt();
+    rt.spawn(async move {
+        tx.send("done").unwrap();
+    });
+
+    assert_eq!(rx.recv().unwrap(), "done");
+}
+
 #[test]
 fn spawn_two() {
     let rt = rt();
@@ -816,6 +833,112 @@ mod unstable {
         bg_thread.join().unwrap();
     }
 
+    #[test]
+    fn offload_driver_shutdown_to_dedicated_thread() {
+        let rt = runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .offload_driver_shutdown_to_dedicated_thread()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            tokio::spawn(async {}).await.unwrap();
+        });
+
+        // Shutdown must complete promptly even though the driver is dropped
+        // on a separate thread.
+        rt.shutdown_timeout(std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn maintenance_interval() {
+        let rt = runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .maintenance_interval(std::time::Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        // The runtime should function normally with the option enabled, and
+        // shutdown should still complete promptly.
+        rt.block_on(async {
+            tokio::spawn(async {}).await.unwrap();
+        });
+
+        rt.shutdown_timeout(std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn shutdown_order_driver_first() {
+        use tokio::runtime::ShutdownOrder;
+
+        let rt = runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .shutdown_order(ShutdownOrder::DriverFirst)
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            // Leave a task pending on the I/O driver so shutdown has to tear
+            // down both a queued task and the driver.
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            tokio::spawn(async move {
+                let _ = listener.accept().await;
+            });
+        });
+
+        // Shutdown must still complete promptly with the reversed order.
+        rt.shutdown_timeout(std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn shutdown_order_driver_first_offloaded() {
+        use tokio::runtime::ShutdownOrder;
+
+        let rt = runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .shutdown_order(ShutdownOrder::DriverFirst)
+            .offload_driver_shutdown_to_dedicated_thread()
+            .build()
+            .unwrap();
+
+        rt.block_on(async {
+            tokio::spawn(async {}).await.unwrap();
+        });
+
+        // The two options combine: the driver is shut down first, and doing
+        // so is offloaded to a dedicated thread.
+        rt.shutdown_timeout(std::time::Duration::from_secs(5));
+    }
+
+    #[test]
+    fn worker_shutdown_observed_after() {
+        let rt = runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let metrics = rt.metrics();
+        assert_eq!(
+            metrics.worker_shutdown_observed_after(0),
+            std::time::Duration::ZERO
+        );
+
+        rt.block_on(async {
+            tokio::spawn(async {}).await.unwrap();
+        });
+
+        rt.shutdown_timeout(std::time::Duration::from_secs(5));
+
+        // Shutdown already ran to completion above, so the sole worker must
+        // have observed the close signal by now.
+        assert!(metrics.worker_shutdown_observed_after(0) > std::time::Duration::ZERO);
+    }
+
     #[test]
     fn runtime_id_is_same() {
         let rt = rt();
@@ -26,6 +26,31 @@ fn num_workers() {
     assert_eq!(2, rt.metrics().num_workers());
 }
 
+#[test]
+fn live_worker_thread_count() {
+    let rt = current_thread();
+    assert_eq!(1, rt.metrics().live_worker_thread_count());
+
+    let rt = threaded();
+    // Worker threads start up asynchronously, so wait for them to report in.
+    for _ in 0..100 {
+        if rt.metrics().live_worker_thread_count() == 2 {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    assert_eq!(2, rt.metrics().live_worker_thread_count());
+}
+
+#[test]
+fn total_pending_tasks() {
+    let rt = current_thread();
+    assert_eq!(0, rt.metrics().total_pending_tasks());
+
+    let rt = threaded();
+    assert_eq!(0, rt.metrics().total_pending_tasks());
+}
+
 #[test]
 fn num_blocking_threads() {
     let rt = current_thread();
@@ -294,6 +319,40 @@ fn worker_noop_count() {
     assert!(0 < metrics.worker_noop_count(1));
 }
 
+#[test]
+#[ignore] // depends on exact scheduler timing, like worker_steal_count below
+fn worker_notify_no_work_count() {
+    // This metric only applies to the multi-threaded runtime. There isn't a
+    // deterministic way to force a worker to wake up and find no work: it
+    // depends on another worker racing to steal the task first.
+    use std::sync::mpsc::channel;
+
+    let rt = threaded_no_lifo();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let (tx, rx) = channel();
+
+        tokio::spawn(async move {
+            tokio::spawn(async move {
+                tx.send(()).unwrap();
+            });
+
+            rx.recv().unwrap();
+        })
+        .await
+        .unwrap();
+    });
+
+    drop(rt);
+
+    let n: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_notify_no_work_count(i))
+        .sum();
+
+    assert!(n > 0);
+}
+
 #[test]
 #[ignore] // this test is flaky, see https://github.com/tokio-rs/tokio/issues/6470
 fn worker_steal_count() {
@@ -333,6 +392,40 @@ fn worker_steal_count() {
     assert_eq!(1, n);
 }
 
+#[test]
+#[ignore] // depends on exact scheduler timing, like worker_steal_count above
+fn worker_steal_global_fallback_count() {
+    // This metric only applies to the multi-threaded runtime. There isn't a
+    // deterministic way to force a worker to search for work, come up empty
+    // against every peer, and only then find something on the global queue:
+    // it depends on exactly when the task lands there relative to the
+    // worker's own empty-queue check.
+    use std::sync::mpsc::channel;
+
+    let rt = threaded_no_lifo();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        let (tx, rx) = channel();
+
+        // Spawned from outside any worker, so it is only reachable via the
+        // global queue, with no peer worker ever holding it.
+        tokio::spawn(async move {
+            tx.send(()).unwrap();
+        });
+
+        rx.recv().unwrap();
+    });
+
+    drop(rt);
+
+    let n: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_steal_global_fallback_count(i))
+        .sum();
+
+    assert!(n > 0);
+}
+
 #[test]
 fn worker_poll_count_and_time() {
     const N: u64 = 5;
@@ -545,6 +638,45 @@ fn worker_total_busy_duration() {
     }
 }
 
+// Only Linux exposes a per-thread CPU clock; everywhere else the metric is
+// always zero.
+#[test]
+#[cfg(target_os = "linux")]
+fn worker_total_cpu_time() {
+    // Larger than the other metrics tests' task counts: a per-thread CPU
+    // clock is coarse enough that a handful of near-instant yielding tasks
+    // can round down to zero, so give it enough work to clear that noise
+    // floor.
+    const N: usize = 2_000;
+
+    let zero = Duration::from_millis(0);
+
+    let rt = threaded();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        for _ in 0..N {
+            tokio::spawn(async {
+                tokio::task::yield_now().await;
+            })
+            .await
+            .unwrap();
+        }
+    });
+
+    drop(rt);
+
+    // Unlike `worker_total_busy_duration`, which is tracked in-process and
+    // always advances for a worker that ran a task, this reads a per-thread
+    // CPU clock whose resolution is coarse enough that a worker which only
+    // ran a couple of short, yielding tasks can legitimately report zero.
+    // Only assert that some worker did measurable work.
+    let total: Duration = (0..metrics.num_workers())
+        .map(|i| metrics.worker_total_cpu_time(i))
+        .sum();
+    assert!(zero < total);
+}
+
 #[test]
 fn worker_local_schedule_count() {
     let rt = current_thread();
@@ -622,6 +754,208 @@ fn worker_overflow_count() {
     assert_eq!(1, n);
 }
 
+#[test]
+fn overflow_shard_matrix() {
+    // Only applies to the threaded worker
+    let rt = threaded();
+    let metrics = rt.metrics();
+    rt.block_on(async {
+        // Move to the runtime
+        tokio::spawn(async {
+            let (tx1, rx1) = std::sync::mpsc::channel();
+            let (tx2, rx2) = std::sync::mpsc::channel();
+
+            // First, we need to block the other worker until all tasks have
+            // been spawned.
+            //
+            // We spawn from outside the runtime to ensure that the other worker
+            // will pick it up:
+            // <https://github.com/tokio-rs/tokio/issues/4730>
+            tokio::task::spawn_blocking(|| {
+                tokio::spawn(async move {
+                    tx1.send(()).unwrap();
+                    rx2.recv().unwrap();
+                });
+            });
+
+            rx1.recv().unwrap();
+
+            // Spawn many tasks
+            for _ in 0..300 {
+                tokio::spawn(async {});
+            }
+
+            tx2.send(()).unwrap();
+        })
+        .await
+        .unwrap();
+    });
+    drop(rt);
+
+    let matrix = metrics.overflow_shard_matrix();
+    assert_eq!(matrix.len(), metrics.num_workers());
+
+    // This runtime's injection queue isn't sharded, so every row has exactly
+    // one column, matching `worker_overflow_count`.
+    let n: u64 = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            assert_eq!(row.len(), 1);
+            assert_eq!(row[0], metrics.worker_overflow_count(i));
+            row[0]
+        })
+        .sum();
+
+    assert_eq!(1, n);
+}
+
+#[test]
+fn worker_local_and_global_queue_pull_count() {
+    let rt = current_thread();
+    let metrics = rt.metrics();
+    rt.block_on(async {
+        tokio::spawn(async {}).await.unwrap();
+    });
+    drop(rt);
+
+    // The task was scheduled and pulled from the worker's own local queue.
+    assert_eq!(1, metrics.worker_local_queue_pull_count(0));
+    assert_eq!(0, metrics.worker_global_queue_pull_count(0));
+
+    let rt = threaded();
+    let metrics = rt.metrics();
+    rt.block_on(async {
+        // Spawned from outside any worker, so it is only reachable via the
+        // global queue.
+        tokio::spawn(async {}).await.unwrap();
+    });
+    drop(rt);
+
+    let local: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_local_queue_pull_count(i))
+        .sum();
+    let global: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_global_queue_pull_count(i))
+        .sum();
+
+    assert_eq!(0, local);
+    assert_eq!(1, global);
+}
+
+#[test]
+fn worker_consecutive_idle() {
+    let rt = threaded();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        tokio::spawn(async {}).await.unwrap();
+
+        // Give the workers a chance to search for more work and come up
+        // empty a few times.
+        time::sleep(Duration::from_millis(50)).await;
+    });
+
+    let idle: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_consecutive_idle(i))
+        .sum();
+
+    assert!(idle > 0);
+
+    drop(rt);
+}
+
+#[test]
+fn worker_global_queue_intervals_threaded() {
+    let rt = threaded();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        tokio::spawn(async {}).await.unwrap();
+
+        // Give the workers a chance to park, which is when they publish
+        // their stats.
+        time::sleep(Duration::from_millis(50)).await;
+    });
+
+    let intervals = metrics.worker_global_queue_intervals();
+
+    assert_eq!(intervals.len(), metrics.num_workers());
+    for interval in intervals {
+        assert!((2..=127).contains(&interval));
+    }
+
+    drop(rt);
+}
+
+#[test]
+fn worker_global_queue_intervals_current_thread() {
+    let rt = current_thread();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        tokio::spawn(async {}).await.unwrap();
+    });
+
+    // The current-thread scheduler doesn't self-tune, so this is just the
+    // configured (or default) value, published for its single worker.
+    assert_eq!(metrics.worker_global_queue_intervals(), vec![31]);
+
+    drop(rt);
+}
+
+#[test]
+fn worker_label_default_threaded() {
+    let rt = threaded();
+    let metrics = rt.metrics();
+
+    for i in 0..metrics.num_workers() {
+        assert_eq!(metrics.worker_label(i), format!("worker-{i}"));
+    }
+
+    drop(rt);
+}
+
+#[test]
+fn worker_label_default_current_thread() {
+    let rt = current_thread();
+    let metrics = rt.metrics();
+
+    assert_eq!(metrics.worker_label(0), "worker-0");
+
+    drop(rt);
+}
+
+#[test]
+fn reset_metrics() {
+    let rt = threaded();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        tokio::spawn(async {}).await.unwrap();
+    });
+    // Per-worker poll counts are only flushed to `RuntimeMetrics` when a
+    // worker parks or the runtime shuts down, so drop the runtime first to
+    // force that flush before reading them.
+    drop(rt);
+
+    assert!(metrics.remote_schedule_count() > 0);
+    let n: u64 = (0..metrics.num_workers())
+        .map(|i| metrics.worker_poll_count(i))
+        .sum();
+    assert!(n > 0);
+
+    metrics.reset_metrics();
+
+    assert_eq!(metrics.remote_schedule_count(), 0);
+    for i in 0..metrics.num_workers() {
+        assert_eq!(metrics.worker_poll_count(i), 0);
+        assert_eq!(metrics.worker_park_count(i), 0);
+        assert_eq!(metrics.worker_steal_count(i), 0);
+        assert_eq!(metrics.worker_consecutive_idle(i), 0);
+    }
+}
+
 #[test]
 fn worker_local_queue_depth() {
     const N: usize = 100;
@@ -674,6 +1008,49 @@ fn worker_local_queue_depth() {
     });
 }
 
+#[test]
+fn worker_local_queue_depth_after_shutdown() {
+    // `worker_local_queue_depth` reads the victim queue's atomic head/tail
+    // difference directly, with no lock and no dependency on the worker
+    // thread still being alive, so it must keep answering (with `0`, since
+    // every queue is drained on shutdown) rather than panicking once the
+    // runtime backing it has shut down.
+    let rt = threaded();
+    let metrics = rt.metrics();
+
+    rt.block_on(async {
+        for _ in 0..8 {
+            tokio::spawn(async {});
+        }
+    });
+
+    rt.shutdown_background();
+
+    std::thread::spawn(move || {
+        for i in 0..metrics.num_workers() {
+            assert_eq!(0, metrics.worker_local_queue_depth(i));
+        }
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn worker_run_queue_remaining() {
+    // The current-thread scheduler's queue is unbounded, so it never runs
+    // out of room.
+    let rt = current_thread();
+    assert_eq!(usize::MAX, rt.metrics().worker_run_queue_remaining(0));
+
+    // An idle worker's queue is empty, so it starts out reporting its full
+    // capacity as remaining, for every worker.
+    let rt = threaded();
+    let metrics = rt.metrics();
+    for i in 0..metrics.num_workers() {
+        assert!(metrics.worker_run_queue_remaining(i) > 0);
+    }
+}
+
 #[test]
 fn budget_exhaustion_yield() {
     let rt = current_thread();
@@ -778,6 +1155,35 @@ fn io_driver_ready_count() {
     assert_eq!(metrics.io_driver_ready_count(), 1);
 }
 
+#[test]
+fn worker_status() {
+    use tokio::runtime::WorkerStatus;
+
+    // This metric only applies to the multi-threaded runtime; the current
+    // thread runtime always reports `Parked`.
+    let rt = current_thread();
+    let metrics = rt.metrics();
+    assert_eq!(metrics.worker_status(0), WorkerStatus::Parked);
+
+    rt.block_on(async {
+        assert_eq!(metrics.worker_status(0), WorkerStatus::Parked);
+    });
+
+    let rt = threaded();
+    let metrics = rt.metrics();
+    rt.block_on(async move {
+        tokio::spawn(async move {
+            let this_worker = (0..metrics.num_workers())
+                .find(|&w| metrics.worker_status(w) == WorkerStatus::Running)
+                .expect("some worker should be running the current task");
+
+            assert_eq!(metrics.worker_status(this_worker), WorkerStatus::Running);
+        })
+        .await
+        .unwrap();
+    });
+}
+
 fn current_thread() -> Runtime {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()